@@ -35,6 +35,97 @@ There are currently three members available:
   rest of the (larger) numbers.
 """
 
+ArithmeticFunction = bin.ArithmeticFunction
+"""
+Pseudo-Enum class to define a multiplicative arithmetic function.
+
+A pseudo-Enum class defined in Rust, this class is NOT an instance of the Python
+:class:`enum.Enum` class, even if it behaves mostly in the same way.
+
+Each of these functions is fully described by its values at prime powers, while its
+value at primes is a polynomial of the prime. This allows them to be summed in
+sub-linear time by :func:`summatory`.
+
+There are currently five members available:
+
+- :attr:`ArithmeticFunction.TOTIENT`: Euler's totient :math:`\\varphi(n)`.
+- :attr:`ArithmeticFunction.MOBIUS`: The Möbius function :math:`\\mu(n)`.
+- :attr:`ArithmeticFunction.LIOUVILLE`: The Liouville function :math:`\\lambda(n)`.
+- :attr:`ArithmeticFunction.DIVISOR_COUNT`: The number of divisors
+  :math:`\\sigma_0(n)`.
+- :attr:`ArithmeticFunction.DIVISOR_SUM`: The sum of divisors :math:`\\sigma_1(n)`.
+"""
+
+CunninghamKind = bin.CunninghamKind
+"""
+Pseudo-Enum class to define the kind of a Cunningham chain.
+
+A pseudo-Enum class defined in Rust, this class is NOT an instance of the Python
+:class:`enum.Enum` class, even if it behaves mostly in the same way.
+
+There are currently two members available:
+
+- :attr:`CunninghamKind.FIRST`: Each member is twice the previous plus one, i.e.
+  :math:`p, 2p + 1, 4p + 3, \\ldots`; the members other than the last are Sophie
+  Germain primes. *This is the default.*
+- :attr:`CunninghamKind.SECOND`: Each member is twice the previous minus one, i.e.
+  :math:`p, 2p - 1, 4p - 3, \\ldots`.
+"""
+
+TruncationSide = bin.TruncationSide
+"""
+Pseudo-Enum class to define the side from which the digits of a truncatable prime are
+removed.
+
+A pseudo-Enum class defined in Rust, this class is NOT an instance of the Python
+:class:`enum.Enum` class, even if it behaves mostly in the same way.
+
+There are currently two members available:
+
+- :attr:`TruncationSide.LEFT`: The leading digits are removed one by one, e.g.
+  ``3137, 137, 37, 7``; no digit may be ``0``. *This is the default.*
+- :attr:`TruncationSide.RIGHT`: The trailing digits are removed one by one, e.g.
+  ``7393, 739, 73, 7``.
+"""
+
+SpecialPrime = bin.SpecialPrime
+"""
+Pseudo-Enum class to define the kinds of primes :math:`p` given by a quotient modulo
+:math:`p^2`.
+
+A pseudo-Enum class defined in Rust, this class is NOT an instance of the Python
+:class:`enum.Enum` class, even if it behaves mostly in the same way.
+
+There are currently three members available:
+
+- :attr:`SpecialPrime.WIEFERICH`: The Wieferich primes, with
+  :math:`2^{p-1} \\equiv 1 \\pmod{p^2}`, by the Fermat quotient
+  :math:`(2^{p-1} - 1) / p`. Only ``1093`` and ``3511`` are known.
+- :attr:`SpecialPrime.WILSON`: The Wilson primes, with
+  :math:`(p-1)! \\equiv -1 \\pmod{p^2}`, by the Wilson quotient
+  :math:`((p-1)! + 1) / p`. Only ``5``, ``13`` and ``563`` are known.
+- :attr:`SpecialPrime.WALL_SUN_SUN`: The Wall-Sun-Sun primes, with
+  :math:`F_{p - (p/5)} \\equiv 0 \\pmod{p^2}`, by the Fibonacci quotient
+  :math:`F_{p - (p/5)} / p`, where :math:`(p/5)` is the Legendre symbol. None are known.
+"""
+
+SpiralKind = bin.SpiralKind
+"""
+Pseudo-Enum class to define the kinds of spirals to lay the numbers out on for
+:func:`prime_spiral`.
+
+A pseudo-Enum class defined in Rust, this class is NOT an instance of the Python
+:class:`enum.Enum` class, even if it behaves mostly in the same way.
+
+There are currently two members available:
+
+- :attr:`SpiralKind.ULAM`: The square spiral of Ulam, with one number per pixel, turning
+  anticlockwise from the right of the centre. *This is the default.*
+- :attr:`SpiralKind.SACKS`: The Archimedean spiral of Sacks, with each number :math:`n`
+  at the polar coordinates :math:`(\\sqrt{n}, 2 \\pi \\sqrt{n})` in pixels, so that the
+  perfect squares line up to the right of the centre.
+"""
+
 is_prime = decorators.TimedFunction(bin.is_prime)
 """
 Check if the given number is prime.
@@ -268,3 +359,3328 @@ int
 None
     If ``n`` is invalid (e.g. ``0``).
 """
+
+totient = decorators.TimedFunction(bin.totient)
+"""
+Calculate Euler's totient of ``num``.
+
+Euler's totient :math:`\\varphi(n)` counts the integers up to ``n`` that are coprime
+to ``n``. By convention, :math:`\\varphi(0) = 0`.
+
+.. note::
+    The GIL is released during calculation, so other Python threads can continue with
+    their work. This tends to have minimal impact on multi-threaded methods, since all
+    the threads are working already; but this could provide better utilisation for
+    single threaded methods.
+
+.. seealso::
+    While this function behaves like a function, it is implemented through
+    :class:`~rust_primes.decorators.timed.TimedFunction` and thus contains additional
+    functionalities with caching and
+    :meth:`~rust_primes.decorators.timed.TimedFunction.timed` executions.
+
+    See :class:`rust_primes.decorators.timed.TimedFunction` for more details.
+
+Parameters
+----------
+num : int
+    The number to be evaluated.
+
+method : SieveMethod
+    The method of sieving to be used for finding the prime factors.
+
+Returns
+-------
+int
+    The totient of ``num``.
+
+Examples
+--------
+    >>> from rust_primes import totient
+    >>> totient(36)
+    12
+"""
+
+totient_sieve = decorators.TimedFunction(bin.totient_sieve)
+"""
+Calculate Euler's totient for every number less than or equal to ``num``.
+
+The sieve starts with :math:`\\varphi(k) = k`, then for each prime :math:`p` found by
+the chosen :class:`SieveMethod`, every multiple of :math:`p` is multiplied by
+:math:`(p - 1) / p`.
+
+.. note::
+    The GIL is released during calculation, so other Python threads can continue with
+    their work.
+
+.. seealso::
+    While this function behaves like a function, it is implemented through
+    :class:`~rust_primes.decorators.timed.TimedFunction` and thus contains additional
+    functionalities with caching and
+    :meth:`~rust_primes.decorators.timed.TimedFunction.timed` executions.
+
+    See :class:`rust_primes.decorators.timed.TimedFunction` for more details.
+
+Parameters
+----------
+num : int
+    The upper bound to be calculated.
+
+method : SieveMethod
+    The method of sieving to be used for finding the primes.
+
+Returns
+-------
+numpy.ndarray
+    A 1-D array of ``numpy.uint64`` of length ``num + 1``, where the value at index
+    ``k`` is :math:`\\varphi(k)`.
+
+Examples
+--------
+    >>> from rust_primes import totient_sieve
+    >>> totient_sieve(10)
+    array([0, 1, 1, 2, 2, 4, 2, 6, 4, 6, 4], dtype=uint64)
+"""
+
+mobius_sieve = decorators.TimedFunction(bin.mobius_sieve)
+"""
+Calculate the Möbius function for every number less than or equal to ``num``.
+
+The sieve starts with :math:`\\mu(k) = 1`, then for each prime :math:`p` found by the
+chosen :class:`SieveMethod`, the sign of every multiple of :math:`p` is flipped, and
+every multiple of :math:`p^2` is set to ``0``. By convention, :math:`\\mu(0) = 0`.
+
+.. note::
+    The GIL is released during calculation, so other Python threads can continue with
+    their work.
+
+.. seealso::
+    While this function behaves like a function, it is implemented through
+    :class:`~rust_primes.decorators.timed.TimedFunction` and thus contains additional
+    functionalities with caching and
+    :meth:`~rust_primes.decorators.timed.TimedFunction.timed` executions.
+
+    See :class:`rust_primes.decorators.timed.TimedFunction` for more details.
+
+Parameters
+----------
+num : int
+    The upper bound to be calculated.
+
+method : SieveMethod
+    The method of sieving to be used for finding the primes.
+
+Returns
+-------
+numpy.ndarray
+    A 1-D array of ``numpy.int8`` of length ``num + 1``, where the value at index
+    ``k`` is :math:`\\mu(k)`.
+
+Examples
+--------
+    >>> from rust_primes import mobius_sieve
+    >>> mobius_sieve(10)
+    array([ 0,  1, -1, -1,  0, -1,  1, -1,  0,  0,  1], dtype=int8)
+"""
+
+mertens = decorators.TimedFunction(bin.mertens)
+"""
+Calculate the Mertens function of ``num``.
+
+The Mertens function :math:`M(x)` is the sum of :math:`\\mu(k)` for all :math:`k` up
+to and including ``x``.
+
+This is the plain recursion :math:`M(x) = 1 - \\sum_{2 \\le d \\le x} M(x / d)` over
+a sieved prefix, in around :math:`O(x^{2/3})`: all the values up to around
+:math:`x^{2/3}` are taken from :func:`mobius_sieve`, while the larger values are
+resolved through the recursion. Values of ``num`` up to around ``10**12`` complete
+within seconds.
+
+.. note::
+    The GIL is released during calculation, so other Python threads can continue with
+    their work.
+
+.. seealso::
+    While this function behaves like a function, it is implemented through
+    :class:`~rust_primes.decorators.timed.TimedFunction` and thus contains additional
+    functionalities with caching and
+    :meth:`~rust_primes.decorators.timed.TimedFunction.timed` executions.
+
+    See :class:`rust_primes.decorators.timed.TimedFunction` for more details.
+
+Parameters
+----------
+num : int
+    The upper bound of the sum.
+
+method : SieveMethod
+    The method of sieving to be used for finding the primes of the small values.
+
+Returns
+-------
+int
+    The value of :math:`M(` ``num`` :math:`)`.
+
+Examples
+--------
+    >>> from rust_primes import mertens
+    >>> mertens(10**9)
+    -222
+"""
+
+omega_sieve = decorators.TimedFunction(bin.omega_sieve)
+"""
+Count the distinct prime factors of every number from ``lbound`` up to and including
+``num``.
+
+Each prime up to :math:`\\sqrt{num}` found by the chosen :class:`SieveMethod` strides
+through its multiples in the window once; any cofactor left over afterwards is a single
+larger prime. By convention, :math:`\\omega(0) = \\omega(1) = 0`.
+
+.. note::
+    The GIL is released during calculation, so other Python threads can continue with
+    their work.
+
+.. seealso::
+    While this function behaves like a function, it is implemented through
+    :class:`~rust_primes.decorators.timed.TimedFunction` and thus contains additional
+    functionalities with caching and
+    :meth:`~rust_primes.decorators.timed.TimedFunction.timed` executions.
+
+    See :class:`rust_primes.decorators.timed.TimedFunction` for more details.
+
+Parameters
+----------
+num : int
+    The upper bound of the window.
+
+lbound : int
+    The lower bound of the window. Defaults to ``0``.
+
+method : SieveMethod
+    The method of sieving to be used for finding the primes.
+
+Returns
+-------
+numpy.ndarray
+    A 1-D array of ``numpy.uint8`` of length ``num - lbound + 1``, where the value at
+    index ``i`` is :math:`\\omega(lbound + i)`.
+
+Examples
+--------
+    >>> from rust_primes import omega_sieve
+    >>> omega_sieve(12)
+    array([0, 0, 1, 1, 1, 1, 2, 1, 1, 1, 2, 1, 2], dtype=uint8)
+    >>> omega_sieve(10**12 + 4, lbound=10**12)
+    array([2, 3, 3, 3, 5], dtype=uint8)
+"""
+
+big_omega_sieve = decorators.TimedFunction(bin.big_omega_sieve)
+"""
+Count the prime factors, with multiplicity, of every number from ``lbound`` up to and
+including ``num``.
+
+This is calculated in the same pass as :func:`omega_sieve`, where the multiples of each
+prime power are also strided through. By convention,
+:math:`\\Omega(0) = \\Omega(1) = 0`.
+
+.. note::
+    The GIL is released during calculation, so other Python threads can continue with
+    their work.
+
+.. seealso::
+    While this function behaves like a function, it is implemented through
+    :class:`~rust_primes.decorators.timed.TimedFunction` and thus contains additional
+    functionalities with caching and
+    :meth:`~rust_primes.decorators.timed.TimedFunction.timed` executions.
+
+    See :class:`rust_primes.decorators.timed.TimedFunction` for more details.
+
+Parameters
+----------
+num : int
+    The upper bound of the window.
+
+lbound : int
+    The lower bound of the window. Defaults to ``0``.
+
+method : SieveMethod
+    The method of sieving to be used for finding the primes.
+
+Returns
+-------
+numpy.ndarray
+    A 1-D array of ``numpy.uint8`` of length ``num - lbound + 1``, where the value at
+    index ``i`` is :math:`\\Omega(lbound + i)`.
+
+Examples
+--------
+    >>> from rust_primes import big_omega_sieve
+    >>> big_omega_sieve(12)
+    array([0, 0, 1, 1, 2, 1, 2, 1, 3, 2, 2, 1, 3], dtype=uint8)
+"""
+
+liouville_sieve = decorators.TimedFunction(bin.liouville_sieve)
+"""
+Calculate the Liouville function of every number from ``lbound`` up to and including
+``num``.
+
+The Liouville function is :math:`\\lambda(n) = (-1)^{\\Omega(n)}`, derived from the same
+pass as :func:`big_omega_sieve`. By convention, :math:`\\lambda(0) = 0`.
+
+.. note::
+    The GIL is released during calculation, so other Python threads can continue with
+    their work.
+
+.. seealso::
+    While this function behaves like a function, it is implemented through
+    :class:`~rust_primes.decorators.timed.TimedFunction` and thus contains additional
+    functionalities with caching and
+    :meth:`~rust_primes.decorators.timed.TimedFunction.timed` executions.
+
+    See :class:`rust_primes.decorators.timed.TimedFunction` for more details.
+
+Parameters
+----------
+num : int
+    The upper bound of the window.
+
+lbound : int
+    The lower bound of the window. Defaults to ``0``.
+
+method : SieveMethod
+    The method of sieving to be used for finding the primes.
+
+Returns
+-------
+numpy.ndarray
+    A 1-D array of ``numpy.int8`` of length ``num - lbound + 1``, where the value at
+    index ``i`` is :math:`\\lambda(lbound + i)`.
+
+Examples
+--------
+Summing the array gives the summatory Liouville function :math:`L(n)`, which Pólya
+conjectured to be non-positive for all :math:`n \\ge 2`:
+
+    >>> from rust_primes import liouville_sieve
+    >>> int(liouville_sieve(10**6).sum())
+    -530
+"""
+
+divisor_sigma_sieve = decorators.TimedFunction(bin.divisor_sigma_sieve)
+"""
+Calculate the divisor function :math:`\\sigma_k` for every number less than or equal to
+``num``.
+
+:math:`\\sigma_k(n)` is the sum of the ``k``-th powers of all the divisors of ``n``;
+``power=0`` counts the divisors, while ``power=1`` sums them.
+
+This is calculated by a linear sieve over multiplicative functions, which only needs
+the value of :math:`\\sigma_k` at prime powers.
+
+.. note::
+    The GIL is released during calculation, so other Python threads can continue with
+    their work.
+
+.. seealso::
+    While this function behaves like a function, it is implemented through
+    :class:`~rust_primes.decorators.timed.TimedFunction` and thus contains additional
+    functionalities with caching and
+    :meth:`~rust_primes.decorators.timed.TimedFunction.timed` executions.
+
+    See :class:`rust_primes.decorators.timed.TimedFunction` for more details.
+
+Parameters
+----------
+num : int
+    The upper bound to be calculated.
+
+power : int
+    The power ``k`` that each divisor is raised to. Defaults to ``1``.
+
+Returns
+-------
+numpy.ndarray
+    A 1-D array of ``numpy.uint64`` of length ``num + 1``, where the value at index
+    ``n`` is :math:`\\sigma_k(n)`. The value at index ``0`` is ``0``.
+
+Raises
+------
+OverflowError
+    If the values of :math:`\\sigma_k` could exceed 64-bit integers.
+
+Examples
+--------
+    >>> from rust_primes import divisor_sigma_sieve
+    >>> divisor_sigma_sieve(12, power=0)
+    array([0, 1, 2, 2, 3, 2, 4, 2, 4, 3, 4, 2, 6], dtype=uint64)
+    >>> divisor_sigma_sieve(12)
+    array([ 0,  1,  3,  4,  7,  6, 12,  8, 15, 13, 18, 12, 28], dtype=uint64)
+"""
+
+dirichlet_convolution = decorators.TimedFunction(bin.dirichlet_convolution)
+"""
+Calculate the Dirichlet convolution of two arithmetic functions, given by their values
+at each index.
+
+The Dirichlet convolution is
+
+.. math::
+    (f * g)(n) = \\sum_{d | n} f(d) \\, g(n / d)
+
+so that e.g. :math:`\\mu * 1` is ``1`` at ``1`` and ``0`` elsewhere, and
+:math:`\\varphi * 1` is the identity. Each :math:`f(d)` is scaled onto :math:`g` and
+added over steps of :math:`d`, which takes :math:`O(n \\log n)` operations.
+
+.. note::
+    The GIL is released during calculation, so other Python threads can continue with
+    their work.
+
+.. seealso::
+    While this function behaves like a function, it is implemented through
+    :class:`~rust_primes.decorators.timed.TimedFunction` and thus contains additional
+    functionalities with caching and
+    :meth:`~rust_primes.decorators.timed.TimedFunction.timed` executions.
+
+    See :class:`rust_primes.decorators.timed.TimedFunction` for more details.
+
+Parameters
+----------
+lhs : list[int] | numpy.ndarray
+    The values of :math:`f`, with :math:`f(n)` at index ``n``, such as the arrays of
+    :func:`mobius_sieve` or :func:`totient_sieve`. The value at index ``0`` is ignored.
+
+rhs : list[int] | numpy.ndarray
+    The values of :math:`g`, in the same layout as ``lhs``.
+
+Returns
+-------
+numpy.ndarray
+    A 1-D array of ``numpy.int64`` as long as the shorter of ``lhs`` and ``rhs``, where
+    the value at index ``n`` is :math:`(f * g)(n)`. The value at index ``0`` is ``0``.
+
+Raises
+------
+OverflowError
+    If the values of the convolution could exceed 64-bit integers.
+
+Examples
+--------
+    >>> from rust_primes import dirichlet_convolution, mobius_sieve
+    >>> dirichlet_convolution(mobius_sieve(10), [0] + [1] * 10)
+    array([0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0])
+"""
+
+summatory = decorators.TimedFunction(bin.summatory)
+"""
+Sum an :class:`ArithmeticFunction` over all numbers less than or equal to ``num``.
+
+This uses the min_25 sieve, which never holds an array of size ``num``. The sums of the
+function over primes are assembled from Lucy_Hedgehog tables of :math:`\\sum p^k` over
+all the distinct values of ``num // i``, then the composites are added back by
+recursing over their lowest prime factors. Only the primes up to :math:`\\sqrt{num}`
+need to be sieved.
+
+Sums up to around ``10**11`` complete within seconds.
+
+.. note::
+    The GIL is released during calculation, so other Python threads can continue with
+    their work.
+
+.. seealso::
+    While this function behaves like a function, it is implemented through
+    :class:`~rust_primes.decorators.timed.TimedFunction` and thus contains additional
+    functionalities with caching and
+    :meth:`~rust_primes.decorators.timed.TimedFunction.timed` executions.
+
+    See :class:`rust_primes.decorators.timed.TimedFunction` for more details.
+
+Parameters
+----------
+function : ArithmeticFunction
+    The function to be summed.
+
+num : int
+    The upper bound of the sum.
+
+method : SieveMethod
+    The method of sieving to be used for finding the primes up to
+    :math:`\\sqrt{num}`.
+
+Returns
+-------
+int
+    The sum of ``function`` over ``1`` to ``num`` inclusive.
+
+Raises
+------
+OverflowError
+    If the sum does not fit into a 128-bit integer.
+
+Examples
+--------
+    >>> from rust_primes import ArithmeticFunction, summatory
+    >>> summatory(ArithmeticFunction.TOTIENT, 10**11)
+    3039635509283386211140
+    >>> summatory(ArithmeticFunction.MOBIUS, 10**9)
+    -222
+"""
+
+prime_pi = decorators.TimedFunction(bin.prime_pi)
+"""
+Count the number of primes numbers less than or equal to ``num``, in sub-linear time.
+
+The result is the same as :func:`count_primes`, but instead of sieving every number up
+to ``num``, this uses the Lucy_Hedgehog method in :math:`O(num^{3/4})`, which only
+sieves the primes up to :math:`\\sqrt{num}`. This is the same table that drives
+:func:`sum_primes`.
+
+Counting up to ``10**12`` takes a few seconds; ``10**13`` takes under a minute.
+
+.. note::
+    The GIL is released during calculation, so other Python threads can continue with
+    their work.
+
+.. seealso::
+    While this function behaves like a function, it is implemented through
+    :class:`~rust_primes.decorators.timed.TimedFunction` and thus contains additional
+    functionalities with caching and
+    :meth:`~rust_primes.decorators.timed.TimedFunction.timed` executions.
+
+    See :class:`rust_primes.decorators.timed.TimedFunction` for more details.
+
+Parameters
+----------
+num : int
+    The upper bound to be checked.
+
+method : SieveMethod
+    The method of sieving to be used for finding the primes up to
+    :math:`\\sqrt{num}`.
+
+Returns
+-------
+int
+    Number of prime numbers up to and including ``num``.
+
+Examples
+--------
+    >>> from rust_primes import prime_pi
+    >>> prime_pi(10**12)
+    37607912018
+"""
+
+sum_primes = decorators.TimedFunction(bin.sum_primes)
+"""
+Sum the ``power``-th powers of all primes less than or equal to ``num``.
+
+This uses the Lucy_Hedgehog method in :math:`O(num^{3/4})`, sharing its table with
+:func:`prime_pi`. The sum is either exact, or reduced modulo ``modulus`` if given.
+
+.. note::
+    The GIL is released during calculation, so other Python threads can continue with
+    their work.
+
+.. seealso::
+    While this function behaves like a function, it is implemented through
+    :class:`~rust_primes.decorators.timed.TimedFunction` and thus contains additional
+    functionalities with caching and
+    :meth:`~rust_primes.decorators.timed.TimedFunction.timed` executions.
+
+    See :class:`rust_primes.decorators.timed.TimedFunction` for more details.
+
+Parameters
+----------
+num : int
+    The upper bound to be checked.
+
+power : int
+    The power that each prime is raised to, from ``0`` to ``3``. Defaults to ``1``.
+
+modulus : Optional[int]
+    If given, the sum is reduced modulo this value. Must be a positive 64-bit integer.
+
+method : SieveMethod
+    The method of sieving to be used for finding the primes up to
+    :math:`\\sqrt{num}`.
+
+Returns
+-------
+int
+    The sum of :math:`p^{power}` over all primes :math:`p \\le num`.
+
+Raises
+------
+ValueError
+    If ``power`` is above ``3``, or ``modulus`` is ``0``.
+
+OverflowError
+    If the exact sum does not fit into an unsigned 128-bit integer; specify a
+    ``modulus`` instead.
+
+Examples
+--------
+    >>> from rust_primes import sum_primes
+    >>> sum_primes(10**9)
+    24739512092254535
+    >>> sum_primes(10**13, power=2, modulus=10**9 + 7)
+    972144012
+"""
+
+von_mangoldt = decorators.TimedFunction(bin.von_mangoldt)
+"""
+Calculate the von Mangoldt function for every number less than or equal to ``num``.
+
+:math:`\\Lambda(n)` is :math:`\\ln p` if ``n`` is a power of a prime :math:`p`, and
+``0`` otherwise. The primes are found by the chosen :class:`SieveMethod`.
+
+.. note::
+    The GIL is released during calculation, so other Python threads can continue with
+    their work.
+
+.. seealso::
+    While this function behaves like a function, it is implemented through
+    :class:`~rust_primes.decorators.timed.TimedFunction` and thus contains additional
+    functionalities with caching and
+    :meth:`~rust_primes.decorators.timed.TimedFunction.timed` executions.
+
+    See :class:`rust_primes.decorators.timed.TimedFunction` for more details.
+
+Parameters
+----------
+num : int
+    The upper bound to be calculated.
+
+method : SieveMethod
+    The method of sieving to be used for finding the primes.
+
+Returns
+-------
+numpy.ndarray
+    A 1-D array of ``numpy.float64`` of length ``num + 1``, where the value at index
+    ``n`` is :math:`\\Lambda(n)`.
+"""
+
+chebyshev_theta = decorators.TimedFunction(bin.chebyshev_theta)
+"""
+Calculate the first Chebyshev function :math:`\\vartheta(x) = \\sum_{p \\le x} \\ln p`.
+
+The primes are found by the chosen :class:`SieveMethod`, and their logarithms are
+summed using Kahan's compensated summation, so the result stays accurate to the last
+few bits however large ``num`` is.
+
+.. note::
+    The GIL is released during calculation, so other Python threads can continue with
+    their work.
+
+.. seealso::
+    While this function behaves like a function, it is implemented through
+    :class:`~rust_primes.decorators.timed.TimedFunction` and thus contains additional
+    functionalities with caching and
+    :meth:`~rust_primes.decorators.timed.TimedFunction.timed` executions.
+
+    See :class:`rust_primes.decorators.timed.TimedFunction` for more details.
+
+Parameters
+----------
+num : int
+    The upper bound of the sum.
+
+method : SieveMethod
+    The method of sieving to be used for finding the primes.
+
+Returns
+-------
+float
+    The value of :math:`\\vartheta(` ``num`` :math:`)`.
+
+Examples
+--------
+    >>> from rust_primes import chebyshev_theta
+    >>> chebyshev_theta(10**6)
+    998484.1750256342
+"""
+
+chebyshev_psi = decorators.TimedFunction(bin.chebyshev_psi)
+"""
+Calculate the second Chebyshev function :math:`\\psi(x) = \\sum_{n \\le x} \\Lambda(n)`.
+
+Each prime :math:`p` found by the chosen :class:`SieveMethod` contributes
+:math:`\\ln p` once for each of its powers up to ``num``; these are summed using
+Kahan's compensated summation. The prime number theorem is equivalent to
+:math:`\\psi(x) \\sim x`.
+
+.. note::
+    The GIL is released during calculation, so other Python threads can continue with
+    their work.
+
+.. seealso::
+    While this function behaves like a function, it is implemented through
+    :class:`~rust_primes.decorators.timed.TimedFunction` and thus contains additional
+    functionalities with caching and
+    :meth:`~rust_primes.decorators.timed.TimedFunction.timed` executions.
+
+    See :class:`rust_primes.decorators.timed.TimedFunction` for more details.
+
+Parameters
+----------
+num : int
+    The upper bound of the sum.
+
+method : SieveMethod
+    The method of sieving to be used for finding the primes.
+
+Returns
+-------
+float
+    The value of :math:`\\psi(` ``num`` :math:`)`.
+"""
+
+count_primes_mod = decorators.TimedFunction(bin.count_primes_mod)
+"""
+Count the primes less than or equal to ``num`` in every residue class modulo
+``modulus``.
+
+All the residue classes are counted in a single pass over the prime mask found by the
+chosen :class:`SieveMethod`.
+
+.. note::
+    The GIL is released during calculation, so other Python threads can continue with
+    their work.
+
+.. seealso::
+    While this function behaves like a function, it is implemented through
+    :class:`~rust_primes.decorators.timed.TimedFunction` and thus contains additional
+    functionalities with caching and
+    :meth:`~rust_primes.decorators.timed.TimedFunction.timed` executions.
+
+    See :class:`rust_primes.decorators.timed.TimedFunction` for more details.
+
+Parameters
+----------
+num : int
+    The upper bound to be checked.
+
+modulus : int
+    The modulus of the residue classes, at most ``num + 1``.
+
+method : SieveMethod
+    The method of sieving to be used for finding the primes.
+
+Returns
+-------
+numpy.ndarray
+    A 1-D array of ``numpy.uint64`` of length ``modulus``, where the value at index
+    ``r`` is the number of primes :math:`p \\le num` with :math:`p \\equiv r`.
+
+Raises
+------
+ValueError
+    If ``modulus`` is ``0`` or exceeds ``num + 1``.
+
+Examples
+--------
+The prime race between :math:`4n+1` and :math:`4n+3`:
+
+    >>> from rust_primes import count_primes_mod
+    >>> count_primes_mod(10**6, 4)
+    array([    0, 39175,     1, 39322], dtype=uint64)
+"""
+
+list_primes_in_progression = decorators.TimedFunction(bin.list_primes_in_progression)
+"""
+List all primes congruent to ``residue`` modulo ``modulus``, between ``lbound`` and
+``ubound`` inclusive.
+
+Only the members of the arithmetic progression are sieved: for each prime :math:`p` up
+to :math:`\\sqrt{ubound}`, the members divisible by :math:`p` are found by solving
+a linear congruence, then struck out in steps of :math:`p`. This takes roughly
+``1 / modulus`` of the memory and time of :func:`list_primes`.
+
+.. note::
+    The GIL is released during calculation, so other Python threads can continue with
+    their work.
+
+.. seealso::
+    While this function behaves like a function, it is implemented through
+    :class:`~rust_primes.decorators.timed.TimedFunction` and thus contains additional
+    functionalities with caching and
+    :meth:`~rust_primes.decorators.timed.TimedFunction.timed` executions.
+
+    See :class:`rust_primes.decorators.timed.TimedFunction` for more details.
+
+Parameters
+----------
+residue : int
+    The residue class of the primes to list.
+
+modulus : int
+    The common difference of the progression.
+
+lbound : int
+    The lower bound of the range, inclusive.
+
+ubound : int
+    The upper bound of the range, inclusive.
+
+method : SieveMethod
+    The method of sieving to be used for finding the primes up to
+    :math:`\\sqrt{ubound}`.
+
+Returns
+-------
+List[int]
+    List of all primes :math:`p \\equiv residue \\pmod{modulus}` in the range, in
+    ascending order.
+
+Raises
+------
+ValueError
+    If ``modulus`` is ``0``.
+
+Examples
+--------
+    >>> from rust_primes import list_primes_in_progression
+    >>> list_primes_in_progression(1, 4, 0, 50)
+    [5, 13, 17, 29, 37, 41]
+"""
+
+prime_residue_transitions = decorators.TimedFunction(bin.prime_residue_transitions)
+"""
+Count the transitions between the residues of consecutive primes modulo ``modulus``.
+
+For every pair of consecutive primes :math:`p_n < p_{n+1} \\le num`, the entry at
+``[p_n % modulus, p_{n+1} % modulus]`` of the matrix is incremented. Lemke Oliver and
+Soundararajan observed that consecutive primes avoid repeating their last digit far
+more often than a random model would predict, which shows up here as an
+under-represented diagonal.
+
+The range is split into segments, which are sieved and counted in parallel; only the
+first and last prime of each segment are retained to stitch the transitions across
+segment boundaries. This never holds the full list of primes, so the memory used stays
+constant regardless of ``num``.
+
+.. note::
+    The GIL is released during calculation, so other Python threads can continue with
+    their work.
+
+.. seealso::
+    While this function behaves like a function, it is implemented through
+    :class:`~rust_primes.decorators.timed.TimedFunction` and thus contains additional
+    functionalities with caching and
+    :meth:`~rust_primes.decorators.timed.TimedFunction.timed` executions.
+
+    See :class:`rust_primes.decorators.timed.TimedFunction` for more details.
+
+Parameters
+----------
+num : int
+    The upper bound of the primes.
+
+modulus : int
+    The modulus of the residues.
+
+method : SieveMethod
+    The method of sieving to be used for finding the base primes up to
+    :math:`\\sqrt{num}`.
+
+Returns
+-------
+numpy.ndarray
+    A 2-D array of ``numpy.uint64`` of shape ``(modulus, modulus)``.
+
+Raises
+------
+ValueError
+    If ``modulus`` is ``0``.
+
+Examples
+--------
+    >>> from rust_primes import prime_residue_transitions
+    >>> prime_residue_transitions(100, 4)
+    array([[0, 0, 0, 0],
+           [0, 3, 0, 7],
+           [0, 0, 0, 1],
+           [0, 8, 0, 5]], dtype=uint64)
+"""
+
+prime_gaps = decorators.TimedFunction(bin.prime_gaps)
+"""
+Collect statistics of the gaps between consecutive primes in ``lbound..=ubound``.
+
+A gap is described by a ``(start, gap, merit)`` tuple, where ``start`` is the prime
+before the gap, ``gap`` is the distance to the next prime and ``merit`` is
+:math:`gap / \\ln(start)`, i.e. how many times larger the gap is than the average gap
+around ``start``.
+
+The range is split into segments, which are sieved and measured in parallel; the
+statistics of adjacent segments are then joined, counting the gap across each segment
+boundary. This never holds the full list of primes, so the memory used stays constant
+regardless of the size of the range.
+
+.. note::
+    The GIL is released during calculation, so other Python threads can continue with
+    their work.
+
+.. seealso::
+    While this function behaves like a function, it is implemented through
+    :class:`~rust_primes.decorators.timed.TimedFunction` and thus contains additional
+    functionalities with caching and
+    :meth:`~rust_primes.decorators.timed.TimedFunction.timed` executions.
+
+    See :class:`rust_primes.decorators.timed.TimedFunction` for more details.
+
+Parameters
+----------
+lbound : int
+    The lower bound of the primes, inclusive.
+
+ubound : int
+    The upper bound of the primes, inclusive.
+
+method : SieveMethod
+    The method of sieving to be used for finding the base primes up to
+    :math:`\\sqrt{ubound}`.
+
+Returns
+-------
+dict
+    A ``dict`` with the following keys:
+
+    - ``"first"``: the first prime in the range, or ``None`` if there are none.
+    - ``"last"``: the last prime in the range, or ``None`` if there are none.
+    - ``"histogram"``: a 1-D array of ``numpy.uint64``, where index ``g`` is the
+      number of gaps of size ``g``.
+    - ``"records"``: a ``list`` of the maximal gaps, i.e. every gap strictly larger
+      than all the gaps before it in the range.
+    - ``"first_occurrences"``: a ``dict`` mapping each gap size to its first gap in
+      the range.
+    - ``"max_merit"``: the gap with the highest merit, or ``None`` if there are
+      fewer than two primes.
+
+Examples
+--------
+    >>> from rust_primes import prime_gaps
+    >>> _gaps = prime_gaps(0, 100)
+    >>> _gaps["histogram"]
+    array([0, 1, 8, 0, 7, 0, 7, 0, 1], dtype=uint64)
+    >>> [(_start, _gap) for _start, _gap, _ in _gaps["records"]]
+    [(2, 1), (3, 2), (7, 4), (23, 6), (89, 8)]
+    >>> _gaps["max_merit"][:2]
+    (7, 4)
+"""
+
+find_constellations = decorators.TimedFunction(bin.find_constellations)
+"""
+Find the prime constellations matching ``pattern`` with all their members in
+``lbound..=ubound``.
+
+A constellation is a starting point :math:`n` for which :math:`n + d` is prime for
+every offset :math:`d` in ``pattern``; for example ``[0, 2]`` finds the twin primes,
+``[0, 4]`` the cousin primes, ``[0, 6]`` the sexy primes, ``[0, 2, 6]`` and
+``[0, 4, 6]`` the prime triplets and ``[0, 2, 6, 8]`` the prime quadruplets.
+
+The candidates are first restricted by a wheel of the primes up to ``13``, keeping
+only the residue classes where none of the members are divisible by any of these
+primes. Each class is then sieved by every other prime up to :math:`\\sqrt{ubound}`,
+where all the members of the pattern are sieved simultaneously. The classes are sieved
+in parallel.
+
+.. note::
+    The GIL is released during calculation, so other Python threads can continue with
+    their work.
+
+.. seealso::
+    While this function behaves like a function, it is implemented through
+    :class:`~rust_primes.decorators.timed.TimedFunction` and thus contains additional
+    functionalities with caching and
+    :meth:`~rust_primes.decorators.timed.TimedFunction.timed` executions.
+
+    See :class:`rust_primes.decorators.timed.TimedFunction` for more details.
+
+    Calls with a :class:`list` for ``pattern`` cannot be cached; pass a :class:`tuple`
+    instead to cache the results.
+
+Parameters
+----------
+pattern : list[int] | tuple[int, ...]
+    The offsets of the members, strictly increasing and starting from ``0``.
+
+lbound : int
+    The lower bound of the members, inclusive.
+
+ubound : int
+    The upper bound of the members, inclusive.
+
+count_only : bool
+    If ``True``, only count the constellations without holding them in memory.
+
+method : SieveMethod
+    The method of sieving to be used for finding the base primes up to
+    :math:`\\sqrt{ubound}`.
+
+Returns
+-------
+list[int] | int
+    The starting points of the constellations in ascending order, or their count if
+    ``count_only``.
+
+Raises
+------
+ValueError
+    If ``pattern`` is empty, does not start from ``0`` or is not strictly increasing.
+
+Examples
+--------
+    >>> from rust_primes import find_constellations
+    >>> find_constellations((0, 2), 0, 100)
+    [3, 5, 11, 17, 29, 41, 59, 71]
+    >>> find_constellations((0, 2, 6, 8), 0, 1000)
+    [5, 11, 101, 191, 821]
+    >>> find_constellations((0, 2), 0, 10**6, count_only=True)
+    8169
+"""
+
+is_admissible = decorators.TimedFunction(bin.is_admissible)
+"""
+Check if the offsets of ``pattern`` can all be prime infinitely often.
+
+A pattern is admissible if, for every prime :math:`p`, its offsets avoid at least one
+residue class modulo :math:`p`; otherwise one of the members is always divisible by
+:math:`p`. Only the primes up to the number of offsets need to be checked.
+
+An inadmissible pattern can still have a few small occurrences, where one of the
+members is the prime itself; for example ``(3, 5, 7)`` for ``[0, 2, 4]``.
+
+.. seealso::
+    While this function behaves like a function, it is implemented through
+    :class:`~rust_primes.decorators.timed.TimedFunction` and thus contains additional
+    functionalities with caching and
+    :meth:`~rust_primes.decorators.timed.TimedFunction.timed` executions.
+
+    See :class:`rust_primes.decorators.timed.TimedFunction` for more details.
+
+Parameters
+----------
+pattern : list[int] | tuple[int, ...]
+    The offsets of the members, strictly increasing and starting from ``0``.
+
+Returns
+-------
+bool
+
+Raises
+------
+ValueError
+    If ``pattern`` is empty, does not start from ``0`` or is not strictly increasing.
+
+Examples
+--------
+    >>> from rust_primes import is_admissible
+    >>> is_admissible((0, 2, 6, 8))
+    True
+    >>> is_admissible((0, 2, 4))
+    False
+"""
+
+hardy_littlewood_constant = decorators.TimedFunction(bin.hardy_littlewood_constant)
+"""
+Calculate the Hardy-Littlewood constant of ``pattern``.
+
+This is the singular series
+
+.. math::
+    \\mathfrak{S} = \\prod_{p} \\frac{1 - w(p) / p}{(1 - 1 / p)^k}
+
+over all primes :math:`p`, where :math:`k` is the number of offsets and :math:`w(p)`
+is the number of distinct residues of the offsets modulo :math:`p`. The factor for
+:math:`p = 2` is included, so for the twin primes this is :math:`2 C_2`, twice the twin
+prime constant. It is ``0`` if and only if the pattern is not admissible.
+
+The product is evaluated over the primes up to :math:`2^{22}`, with the rest of the
+product approximated by the prime number theorem.
+
+.. note::
+    The GIL is released during calculation, so other Python threads can continue with
+    their work.
+
+.. seealso::
+    While this function behaves like a function, it is implemented through
+    :class:`~rust_primes.decorators.timed.TimedFunction` and thus contains additional
+    functionalities with caching and
+    :meth:`~rust_primes.decorators.timed.TimedFunction.timed` executions.
+
+    See :class:`rust_primes.decorators.timed.TimedFunction` for more details.
+
+Parameters
+----------
+pattern : list[int] | tuple[int, ...]
+    The offsets of the members, strictly increasing and starting from ``0``.
+
+method : SieveMethod
+    The method of sieving to be used for finding the primes of the product.
+
+Returns
+-------
+float
+
+Raises
+------
+ValueError
+    If ``pattern`` is empty, does not start from ``0`` or is not strictly increasing.
+
+Examples
+--------
+    >>> from rust_primes import hardy_littlewood_constant
+    >>> round(hardy_littlewood_constant((0, 2)), 8)
+    1.32032363
+    >>> round(hardy_littlewood_constant((0, 2, 6, 8)), 8)
+    4.15118084
+"""
+
+hardy_littlewood_estimate = decorators.TimedFunction(bin.hardy_littlewood_estimate)
+"""
+Estimate the number of constellations matching ``pattern`` with all their members in
+``lbound..=ubound``, by the Hardy-Littlewood k-tuple conjecture.
+
+The estimate is
+
+.. math::
+    \\mathfrak{S} \\int_{\\max(lbound, 2)}^{ubound} \\frac{dt}{\\ln^k t}
+
+where :math:`\\mathfrak{S}` is the :func:`hardy_littlewood_constant`, to be compared
+against the counts of :func:`find_constellations`.
+
+.. note::
+    The GIL is released during calculation, so other Python threads can continue with
+    their work.
+
+.. seealso::
+    While this function behaves like a function, it is implemented through
+    :class:`~rust_primes.decorators.timed.TimedFunction` and thus contains additional
+    functionalities with caching and
+    :meth:`~rust_primes.decorators.timed.TimedFunction.timed` executions.
+
+    See :class:`rust_primes.decorators.timed.TimedFunction` for more details.
+
+Parameters
+----------
+pattern : list[int] | tuple[int, ...]
+    The offsets of the members, strictly increasing and starting from ``0``.
+
+lbound : int
+    The lower bound of the members, inclusive.
+
+ubound : int
+    The upper bound of the members, inclusive.
+
+method : SieveMethod
+    The method of sieving to be used for finding the primes of the constant.
+
+Returns
+-------
+float
+
+Raises
+------
+ValueError
+    If ``pattern`` is empty, does not start from ``0`` or is not strictly increasing.
+
+Examples
+--------
+    >>> from rust_primes import find_constellations, hardy_littlewood_estimate
+    >>> find_constellations((0, 2), 0, 10**6, count_only=True)
+    8169
+    >>> round(hardy_littlewood_estimate((0, 2), 0, 10**6))
+    8248
+"""
+
+cunningham_chains = decorators.TimedFunction(bin.cunningham_chains)
+"""
+Find the Cunningham chains of ``length`` members, with the first member in
+``lbound..=ubound``.
+
+A Cunningham chain of the first kind is a sequence of primes
+:math:`p, 2p + 1, 4p + 3, \\ldots`, where each member is twice the previous plus one;
+in the second kind, each member is twice the previous minus one instead. The
+:math:`j`-th member is therefore :math:`2^j p \\pm (2^j - 1)`.
+
+Every :math:`p` that starts ``length`` consecutive members is found, whether or not
+:math:`p` is itself a member of a chain, or its chain continues beyond ``length``
+members.
+
+All the members are sieved simultaneously: the candidates are first restricted by a
+wheel of the primes up to ``13``, then each prime :math:`q` divides the :math:`j`-th
+member exactly when :math:`p \\equiv -(\\pm(2^j - 1)) \\cdot 2^{-j} \\pmod{q}`, so a
+single modular inverse per member and prime is needed. The residue classes of the
+wheel are sieved in parallel.
+
+.. note::
+    The GIL is released during calculation, so other Python threads can continue with
+    their work.
+
+.. seealso::
+    While this function behaves like a function, it is implemented through
+    :class:`~rust_primes.decorators.timed.TimedFunction` and thus contains additional
+    functionalities with caching and
+    :meth:`~rust_primes.decorators.timed.TimedFunction.timed` executions.
+
+    See :class:`rust_primes.decorators.timed.TimedFunction` for more details.
+
+Parameters
+----------
+length : int
+    The number of members of the chains.
+
+lbound : int
+    The lower bound of the first members, inclusive.
+
+ubound : int
+    The upper bound of the first members, inclusive.
+
+kind : CunninghamKind
+    The kind of the chains. Defaults to :attr:`CunninghamKind.FIRST`.
+
+count_only : bool
+    If ``True``, only count the chains without holding them in memory.
+
+method : SieveMethod
+    The method of sieving to be used for finding the base primes up to the square root
+    of the last member.
+
+Returns
+-------
+list[int] | int
+    The first members of the chains in ascending order, or their count if
+    ``count_only``.
+
+Raises
+------
+ValueError
+    If ``length`` is ``0``.
+
+OverflowError
+    If the last member of the chains up to ``ubound`` does not fit into 64-bit integers.
+
+Examples
+--------
+    >>> from rust_primes import CunninghamKind, cunningham_chains
+    >>> cunningham_chains(5, 0, 60000)
+    [2, 89, 179, 53639, 53849]
+    >>> cunningham_chains(5, 0, 20000, kind=CunninghamKind.SECOND)
+    [1531, 6841, 15391, 16651]
+"""
+
+sophie_germain_primes = decorators.TimedFunction(bin.sophie_germain_primes)
+"""
+Find the Sophie Germain primes :math:`p` in ``lbound..=ubound``, i.e. the primes where
+:math:`2p + 1` is also prime.
+
+These are the Cunningham chains of the first kind with ``2`` members; see
+:func:`cunningham_chains`.
+
+.. note::
+    The GIL is released during calculation, so other Python threads can continue with
+    their work.
+
+.. seealso::
+    While this function behaves like a function, it is implemented through
+    :class:`~rust_primes.decorators.timed.TimedFunction` and thus contains additional
+    functionalities with caching and
+    :meth:`~rust_primes.decorators.timed.TimedFunction.timed` executions.
+
+    See :class:`rust_primes.decorators.timed.TimedFunction` for more details.
+
+Parameters
+----------
+lbound : int
+    The lower bound of the primes, inclusive.
+
+ubound : int
+    The upper bound of the primes, inclusive.
+
+count_only : bool
+    If ``True``, only count the primes without holding them in memory.
+
+method : SieveMethod
+    The method of sieving to be used for finding the base primes up to
+    :math:`\\sqrt{2 \\cdot ubound + 1}`.
+
+Returns
+-------
+list[int] | int
+    The primes in ascending order, or their count if ``count_only``.
+
+Raises
+------
+OverflowError
+    If :math:`2 \\cdot ubound + 1` does not fit into 64-bit integers.
+
+Examples
+--------
+    >>> from rust_primes import sophie_germain_primes
+    >>> sophie_germain_primes(0, 100)
+    [2, 3, 5, 11, 23, 29, 41, 53, 83, 89]
+    >>> sophie_germain_primes(0, 10**6, count_only=True)
+    7746
+"""
+
+safe_primes = decorators.TimedFunction(bin.safe_primes)
+"""
+Find the safe primes :math:`q` in ``lbound..=ubound``, i.e. the primes where
+:math:`(q - 1) / 2` is also prime.
+
+Safe primes are commonly used as the modulus of Diffie-Hellman key exchanges, where the
+multiplicative group has a large prime-order subgroup. They are found by sieving their
+Sophie Germain primes :math:`(q - 1) / 2`, so only half of the range is sieved; see
+:func:`sophie_germain_primes`.
+
+.. note::
+    The GIL is released during calculation, so other Python threads can continue with
+    their work.
+
+.. seealso::
+    While this function behaves like a function, it is implemented through
+    :class:`~rust_primes.decorators.timed.TimedFunction` and thus contains additional
+    functionalities with caching and
+    :meth:`~rust_primes.decorators.timed.TimedFunction.timed` executions.
+
+    See :class:`rust_primes.decorators.timed.TimedFunction` for more details.
+
+Parameters
+----------
+lbound : int
+    The lower bound of the primes, inclusive.
+
+ubound : int
+    The upper bound of the primes, inclusive.
+
+count_only : bool
+    If ``True``, only count the primes without holding them in memory.
+
+method : SieveMethod
+    The method of sieving to be used for finding the base primes up to
+    :math:`\\sqrt{ubound}`.
+
+Returns
+-------
+list[int] | int
+    The primes in ascending order, or their count if ``count_only``.
+
+Examples
+--------
+    >>> from rust_primes import safe_primes
+    >>> safe_primes(0, 100)
+    [5, 7, 11, 23, 47, 59, 83]
+    >>> safe_primes(0, 10**6, count_only=True)
+    4324
+"""
+
+goldbach_partitions = decorators.TimedFunction(bin.goldbach_partitions)
+"""
+List the ways to write ``num`` as the sum of two primes :math:`p + q` with
+:math:`p \\le q`.
+
+Goldbach's conjecture states that every even number greater than ``2`` has at least
+one such partition.
+
+.. note::
+    The GIL is released during calculation, so other Python threads can continue with
+    their work.
+
+.. seealso::
+    While this function behaves like a function, it is implemented through
+    :class:`~rust_primes.decorators.timed.TimedFunction` and thus contains additional
+    functionalities with caching and
+    :meth:`~rust_primes.decorators.timed.TimedFunction.timed` executions.
+
+    See :class:`rust_primes.decorators.timed.TimedFunction` for more details.
+
+    :func:`goldbach_counts` to count the partitions of every number up to ``num``.
+
+Parameters
+----------
+num : int
+    The number to partition.
+
+count_only : bool
+    If ``True``, only count the partitions without holding them in memory.
+
+method : SieveMethod
+    The method of sieving to be used for finding the primes up to ``num``.
+
+Returns
+-------
+list[tuple[int, int]] | int
+    The partitions as ``(p, q)`` in ascending order of ``p``, or their count if
+    ``count_only``.
+
+Examples
+--------
+    >>> from rust_primes import goldbach_partitions
+    >>> goldbach_partitions(100)
+    [(3, 97), (11, 89), (17, 83), (29, 71), (41, 59), (47, 53)]
+    >>> goldbach_partitions(10**6, count_only=True)
+    5402
+"""
+
+goldbach_counts = decorators.TimedFunction(bin.goldbach_counts)
+"""
+Count the ways to write every number up to ``num`` as the sum of two primes
+:math:`p + q` with :math:`p \\le q`.
+
+The sums of two odd primes are the self-convolution of the odd part of the prime mask,
+which is calculated in :math:`O(n \\log n)` by squaring its fast Fourier transform; the
+partitions involving ``2`` are then added directly.
+
+.. note::
+    The Fourier transform holds a complex number for every odd number up to ``num``,
+    padded to the next power of ``2``, i.e. up to ``16 * num`` bytes.
+
+.. note::
+    The GIL is released during calculation, so other Python threads can continue with
+    their work.
+
+.. seealso::
+    While this function behaves like a function, it is implemented through
+    :class:`~rust_primes.decorators.timed.TimedFunction` and thus contains additional
+    functionalities with caching and
+    :meth:`~rust_primes.decorators.timed.TimedFunction.timed` executions.
+
+    See :class:`rust_primes.decorators.timed.TimedFunction` for more details.
+
+Parameters
+----------
+num : int
+    The upper bound of the numbers.
+
+method : SieveMethod
+    The method of sieving to be used for finding the primes up to ``num``.
+
+Returns
+-------
+numpy.ndarray
+    A 1-D array of ``numpy.uint64`` of length ``num + 1``, where index ``n`` is the
+    number of partitions of ``n``.
+
+Examples
+--------
+    >>> from rust_primes import goldbach_counts
+    >>> goldbach_counts(20)
+    array([0, 0, 0, 0, 1, 1, 1, 1, 1, 1, 2, 0, 1, 1, 2, 1, 2, 0, 2, 1, 2],
+          dtype=uint64)
+"""
+
+verify_goldbach = decorators.TimedFunction(bin.verify_goldbach)
+"""
+Find the smallest prime :math:`p` for every even number :math:`n` in
+``lbound..=ubound``, for which :math:`n - p` is also prime.
+
+This verifies Goldbach's conjecture over the range: an entry of ``0`` means :math:`n`
+has no partition at all, which is only the case for ``0`` and ``2``, unless a
+counterexample is found.
+
+The range is split into segments, each of which sieves a window of the complements
+:math:`n - p` for the primes up to :math:`2^{16}`, in parallel. The smallest such prime
+is rarely larger than a few thousand; should none of these primes work, all the larger
+primes are searched too, so that every ``0`` is genuine. Only a single window per
+thread is held in memory, besides the returned array.
+
+.. note::
+    The GIL is released during calculation, so other Python threads can continue with
+    their work.
+
+.. seealso::
+    While this function behaves like a function, it is implemented through
+    :class:`~rust_primes.decorators.timed.TimedFunction` and thus contains additional
+    functionalities with caching and
+    :meth:`~rust_primes.decorators.timed.TimedFunction.timed` executions.
+
+    See :class:`rust_primes.decorators.timed.TimedFunction` for more details.
+
+Parameters
+----------
+ubound : int
+    The upper bound of the even numbers, inclusive.
+
+lbound : int
+    The lower bound of the even numbers, inclusive. Defaults to ``0``.
+
+method : SieveMethod
+    The method of sieving to be used for finding the base primes up to
+    :math:`\\sqrt{ubound}`.
+
+Returns
+-------
+numpy.ndarray
+    A 1-D array of ``numpy.uint64``, where index ``i`` is the smallest prime for the
+    ``i``-th even number of the range; with the default ``lbound``, this is
+    :math:`n = 2i`.
+
+Examples
+--------
+    >>> from rust_primes import verify_goldbach
+    >>> verify_goldbach(30)
+    array([0, 0, 2, 3, 3, 3, 5, 3, 3, 5, 3, 3, 5, 3, 5, 7], dtype=uint64)
+    >>> verify_goldbach(120, lbound=100)
+    array([3, 5, 3, 3, 5, 3, 3, 5, 3, 5, 7], dtype=uint64)
+"""
+
+lucas_lehmer = decorators.TimedFunction(bin.lucas_lehmer)
+"""
+Check if the Mersenne number :math:`M_p = 2^p - 1` is prime, where :math:`p` is
+``exponent``.
+
+:math:`M_p` can only be prime if :math:`p` is prime. For an odd prime :math:`p`, the
+Lucas-Lehmer test states that :math:`M_p` is prime if and only if
+:math:`s_{p - 2} \\equiv 0 \\pmod{M_p}`, where :math:`s_0 = 4` and
+:math:`s_{i + 1} = s_i^2 - 2`.
+
+Each square is reduced without any division: since :math:`2^p \\equiv 1 \\pmod{M_p}`,
+the bits above :math:`p` are folded back onto the lower bits by a shift and an
+addition. Before the test, the candidate factors :math:`2kp + 1` for small :math:`k`
+are tried, which rules out most composite :math:`M_p` at a fraction of the cost.
+
+.. note::
+    The GIL is released during calculation, so other Python threads can continue with
+    their work.
+
+.. seealso::
+    While this function behaves like a function, it is implemented through
+    :class:`~rust_primes.decorators.timed.TimedFunction` and thus contains additional
+    functionalities with caching and
+    :meth:`~rust_primes.decorators.timed.TimedFunction.timed` executions.
+
+    See :class:`rust_primes.decorators.timed.TimedFunction` for more details.
+
+Parameters
+----------
+exponent : int
+    The exponent :math:`p` of the Mersenne number.
+
+method : SieveMethod
+    The method of sieving to be used for checking if ``exponent`` is prime.
+
+Returns
+-------
+bool
+
+Examples
+--------
+    >>> from rust_primes import lucas_lehmer
+    >>> lucas_lehmer(127)
+    True
+    >>> lucas_lehmer(11)
+    False
+"""
+
+mersenne_primes = decorators.TimedFunction(bin.mersenne_primes)
+"""
+List the exponents :math:`p` up to and including ``max_exponent``, for which the
+Mersenne number :math:`2^p - 1` is prime.
+
+Only the prime exponents are tested by :func:`lucas_lehmer`, in parallel. The Mersenne
+primes themselves can be recovered as ``2**p - 1``.
+
+.. note::
+    The GIL is released during calculation, so other Python threads can continue with
+    their work.
+
+.. seealso::
+    While this function behaves like a function, it is implemented through
+    :class:`~rust_primes.decorators.timed.TimedFunction` and thus contains additional
+    functionalities with caching and
+    :meth:`~rust_primes.decorators.timed.TimedFunction.timed` executions.
+
+    See :class:`rust_primes.decorators.timed.TimedFunction` for more details.
+
+Parameters
+----------
+max_exponent : int
+    The upper bound of the exponents, inclusive.
+
+method : SieveMethod
+    The method of sieving to be used for finding the prime exponents.
+
+Returns
+-------
+list[int]
+    The exponents in ascending order.
+
+Examples
+--------
+    >>> from rust_primes import mersenne_primes
+    >>> mersenne_primes(1000)
+    [2, 3, 5, 7, 13, 17, 19, 31, 61, 89, 107, 127, 521, 607]
+"""
+
+perfect_numbers = decorators.TimedFunction(bin.perfect_numbers)
+"""
+List the even perfect numbers :math:`2^{p - 1} (2^p - 1)` for every Mersenne prime
+:math:`2^p - 1`, with :math:`p` up to and including ``max_exponent``.
+
+By the Euclid-Euler theorem, these are all the even perfect numbers; the exponents are
+found by :func:`mersenne_primes`.
+
+.. note::
+    The GIL is released during calculation, so other Python threads can continue with
+    their work.
+
+.. seealso::
+    While this function behaves like a function, it is implemented through
+    :class:`~rust_primes.decorators.timed.TimedFunction` and thus contains additional
+    functionalities with caching and
+    :meth:`~rust_primes.decorators.timed.TimedFunction.timed` executions.
+
+    See :class:`rust_primes.decorators.timed.TimedFunction` for more details.
+
+Parameters
+----------
+max_exponent : int
+    The upper bound of the exponents, inclusive.
+
+method : SieveMethod
+    The method of sieving to be used for finding the prime exponents.
+
+Returns
+-------
+list[int]
+    The perfect numbers in ascending order, as Python :class:`int`.
+
+Examples
+--------
+    >>> from rust_primes import perfect_numbers
+    >>> perfect_numbers(31)
+    [6, 28, 496, 8128, 33550336, 8589869056, 137438691328, 2305843008139952128]
+"""
+
+proth = decorators.TimedFunction(bin.proth)
+"""
+Check if the Proth number :math:`N = k \\cdot 2^n + 1` is prime, where :math:`n` is
+``exponent``, :math:`k` is odd, and :math:`k < 2^n`.
+
+By Proth's theorem, :math:`N` is prime if and only if
+:math:`a^{(N - 1) / 2} \\equiv -1 \\pmod{N}` for some :math:`a`. Taking :math:`a` as the
+smallest odd prime with the Jacobi symbol :math:`\\left(\\frac{a}{N}\\right) = -1`, i.e.
+a quadratic non-residue if :math:`N` is prime, makes the test deterministic: a single
+modular exponentiation decides it either way.
+
+.. note::
+    The GIL is released during calculation, so other Python threads can continue with
+    their work.
+
+.. seealso::
+    While this function behaves like a function, it is implemented through
+    :class:`~rust_primes.decorators.timed.TimedFunction` and thus contains additional
+    functionalities with caching and
+    :meth:`~rust_primes.decorators.timed.TimedFunction.timed` executions.
+
+    See :class:`rust_primes.decorators.timed.TimedFunction` for more details.
+
+Parameters
+----------
+k : int
+    The odd multiplier :math:`k`.
+
+exponent : int
+    The exponent :math:`n` of :math:`2`.
+
+Returns
+-------
+bool
+
+Raises
+------
+ValueError
+    If ``k`` is even, or not less than ``2**exponent``.
+
+Examples
+--------
+    >>> from rust_primes import proth
+    >>> proth(3, 2208)
+    True
+    >>> proth(3, 4)
+    False
+"""
+
+pepin = decorators.TimedFunction(bin.pepin)
+"""
+Check if the Fermat number :math:`F_m = 2^{2^m} + 1` is prime, where :math:`m` is
+``index``.
+
+For :math:`m \\geq 1`, Pépin's test states that :math:`F_m` is prime if and only if
+:math:`3^{(F_m - 1) / 2} \\equiv -1 \\pmod{F_m}`, which takes :math:`2^m - 1` squarings.
+Each square is reduced without any division: since :math:`2^{2^m} \\equiv -1
+\\pmod{F_m}`, the bits above :math:`2^m` are subtracted from the lower bits.
+
+Only :math:`F_0` to :math:`F_4` are known to be prime.
+
+.. note::
+    The GIL is released during calculation, so other Python threads can continue with
+    their work.
+
+.. seealso::
+    While this function behaves like a function, it is implemented through
+    :class:`~rust_primes.decorators.timed.TimedFunction` and thus contains additional
+    functionalities with caching and
+    :meth:`~rust_primes.decorators.timed.TimedFunction.timed` executions.
+
+    See :class:`rust_primes.decorators.timed.TimedFunction` for more details.
+
+Parameters
+----------
+index : int
+    The index :math:`m` of the Fermat number.
+
+Returns
+-------
+bool
+
+Raises
+------
+OverflowError
+    If ``index`` is ``32`` or more, for which :math:`F_m` has billions of digits.
+
+Examples
+--------
+    >>> from rust_primes import pepin
+    >>> [pepin(index) for index in range(8)]
+    [True, True, True, True, True, False, False, False]
+"""
+
+kbn_candidates = decorators.TimedFunction(bin.kbn_candidates, side_effects=("path",))
+"""
+Sieve the candidates :math:`k \\cdot b^n + c` for every :math:`k` in ``multipliers`` and
+every :math:`n` between ``min_exponent`` and ``max_exponent`` inclusive, removing those
+with a prime factor up to ``max_prime``, as srsieve does.
+
+This prepares a search for primes of such a form, e.g. Proth primes
+:math:`k \\cdot 2^n + 1` or Riesel primes :math:`k \\cdot 2^n - 1`: the remaining
+candidates are left for a primality test such as :func:`proth`, or for LLR and PFGW
+through the candidate file written to ``path``.
+
+A prime :math:`q` divides :math:`k \\cdot b^n + c` exactly when
+:math:`b^n \\equiv -c \\cdot k^{-1} \\pmod{q}`, so the first such :math:`n` is a
+discrete logarithm, found by baby-step giant-step with the baby steps shared by every
+:math:`k`; the others follow at every multiplicative order of :math:`b` modulo
+:math:`q`. The primes are taken from a segmented sieve, and sieved in parallel.
+
+.. note::
+    The GIL is released during calculation, so other Python threads can continue with
+    their work.
+
+.. seealso::
+    While this function behaves like a function, it is implemented through
+    :class:`~rust_primes.decorators.timed.TimedFunction` and thus contains additional
+    functionalities with caching and
+    :meth:`~rust_primes.decorators.timed.TimedFunction.timed` executions.
+
+    See :class:`rust_primes.decorators.timed.TimedFunction` for more details.
+
+Parameters
+----------
+multipliers : list[int]
+    The multipliers :math:`k`.
+
+base : int
+    The base :math:`b`.
+
+min_exponent : int
+    The lower bound of the exponents :math:`n`, inclusive.
+
+max_exponent : int
+    The upper bound of the exponents :math:`n`, inclusive.
+
+offset : int
+    The offset :math:`c`, which may be negative.
+
+max_prime : int
+    The upper bound of the sieving primes, inclusive. Defaults to ``1_000_000``.
+
+path : str | None
+    If given, the remaining candidates are also written to this file in the ABC format,
+    i.e. a header ``ABC $a*b^$b+c`` followed by a line ``k n`` for each candidate.
+
+count_only : bool
+    If ``True``, only count the remaining candidates.
+
+method : SieveMethod
+    The method of sieving to be used for finding the base primes of the segmented
+    sieve.
+
+Returns
+-------
+list[tuple[int, int]] | int
+    The remaining candidates as ``(k, n)`` in ascending order of ``k`` then ``n``, or
+    their count if ``count_only``.
+
+Raises
+------
+ValueError
+    If ``base`` is less than ``2``, ``min_exponent`` is ``0``, or ``multipliers``
+    contains ``0``.
+
+OSError
+    If ``path`` cannot be written to.
+
+Examples
+--------
+    >>> from rust_primes import kbn_candidates
+    >>> kbn_candidates([3], 2, 1, 12, -1, max_prime=1000)
+    [(3, 1), (3, 2), (3, 3), (3, 4), (3, 6), (3, 7), (3, 11)]
+"""
+
+palindromic_primes = decorators.TimedFunction(bin.palindromic_primes)
+"""
+List the primes between ``lbound`` and ``ubound`` inclusive, whose digits in ``base``
+read the same backwards.
+
+Instead of sieving the whole range, the palindromes are generated directly from their
+first half and tested by the deterministic Miller-Rabin test, in parallel. Every
+palindrome with an even number of digits is a multiple of :math:`b + 1`, so only
+:math:`b + 1` itself is tested among them.
+
+.. note::
+    The GIL is released during calculation, so other Python threads can continue with
+    their work.
+
+.. seealso::
+    While this function behaves like a function, it is implemented through
+    :class:`~rust_primes.decorators.timed.TimedFunction` and thus contains additional
+    functionalities with caching and
+    :meth:`~rust_primes.decorators.timed.TimedFunction.timed` executions.
+
+    See :class:`rust_primes.decorators.timed.TimedFunction` for more details.
+
+Parameters
+----------
+lbound : int
+    The lower bound of the primes, inclusive.
+
+ubound : int
+    The upper bound of the primes, inclusive.
+
+base : int
+    The base of the digits. Defaults to ``10``.
+
+Returns
+-------
+list[int]
+    The palindromic primes in ascending order.
+
+Raises
+------
+ValueError
+    If ``base`` is less than ``2``.
+
+Examples
+--------
+    >>> from rust_primes import palindromic_primes
+    >>> palindromic_primes(100, 400)
+    [101, 131, 151, 181, 191, 313, 353, 373, 383]
+    >>> palindromic_primes(0, 100, base=2)
+    [3, 5, 7, 17, 31, 73]
+"""
+
+emirps = decorators.TimedFunction(bin.emirps)
+"""
+List the emirps between ``lbound`` and ``ubound`` inclusive, i.e. the primes whose
+digits in ``base`` reversed are a different prime.
+
+The primes of the range are found by a segmented sieve, and their reversals looked up
+in a retained prime mask covering every number with as many digits as ``ubound``, up to
+:math:`2^{26}`; beyond it, the reversals are tested by the deterministic Miller-Rabin
+test. Palindromic primes are not emirps, as their reversals are themselves.
+
+.. note::
+    The GIL is released during calculation, so other Python threads can continue with
+    their work.
+
+.. seealso::
+    While this function behaves like a function, it is implemented through
+    :class:`~rust_primes.decorators.timed.TimedFunction` and thus contains additional
+    functionalities with caching and
+    :meth:`~rust_primes.decorators.timed.TimedFunction.timed` executions.
+
+    See :class:`rust_primes.decorators.timed.TimedFunction` for more details.
+
+Parameters
+----------
+lbound : int
+    The lower bound of the primes, inclusive.
+
+ubound : int
+    The upper bound of the primes, inclusive.
+
+base : int
+    The base of the digits. Defaults to ``10``.
+
+method : SieveMethod
+    The method of sieving to be used for finding the primes in the range and the
+    retained mask for looking up the rearranged digits.
+
+Returns
+-------
+list[int]
+    The emirps in ascending order.
+
+Raises
+------
+ValueError
+    If ``base`` is less than ``2``.
+
+Examples
+--------
+    >>> from rust_primes import emirps
+    >>> emirps(0, 200)
+    [13, 17, 31, 37, 71, 73, 79, 97, 107, 113, 149, 157, 167, 179, 199]
+"""
+
+circular_primes = decorators.TimedFunction(bin.circular_primes)
+"""
+List the circular primes between ``lbound`` and ``ubound`` inclusive, i.e. the primes
+for which every rotation of their digits in ``base`` is also prime.
+
+Every rotation is listed separately, e.g. ``197``, ``719`` and ``971``. Numbers with
+more than one digit are rejected outright if any of their digits shares a factor with
+``base``, as some rotation then ends with it; the other rotations are looked up as in
+:func:`emirps`.
+
+.. note::
+    The GIL is released during calculation, so other Python threads can continue with
+    their work.
+
+.. seealso::
+    While this function behaves like a function, it is implemented through
+    :class:`~rust_primes.decorators.timed.TimedFunction` and thus contains additional
+    functionalities with caching and
+    :meth:`~rust_primes.decorators.timed.TimedFunction.timed` executions.
+
+    See :class:`rust_primes.decorators.timed.TimedFunction` for more details.
+
+Parameters
+----------
+lbound : int
+    The lower bound of the primes, inclusive.
+
+ubound : int
+    The upper bound of the primes, inclusive.
+
+base : int
+    The base of the digits. Defaults to ``10``.
+
+method : SieveMethod
+    The method of sieving to be used for finding the primes in the range and the
+    retained mask for looking up the rearranged digits.
+
+Returns
+-------
+list[int]
+    The circular primes in ascending order.
+
+Raises
+------
+ValueError
+    If ``base`` is less than ``2``.
+
+Examples
+--------
+    >>> from rust_primes import circular_primes
+    >>> circular_primes(100, 1000)
+    [113, 131, 197, 199, 311, 337, 373, 719, 733, 919, 971, 991]
+"""
+
+permutable_primes = decorators.TimedFunction(bin.permutable_primes)
+"""
+List the permutable primes between ``lbound`` and ``ubound`` inclusive, i.e. the primes
+for which every permutation of their digits in ``base`` is also prime.
+
+The distinct permutations are enumerated in lexicographic order, stopping at the first
+one that is not prime; they are looked up as in :func:`emirps`. In base ``10``, the
+only permutable primes with more than three digits known are repunits.
+
+.. note::
+    The GIL is released during calculation, so other Python threads can continue with
+    their work.
+
+.. seealso::
+    While this function behaves like a function, it is implemented through
+    :class:`~rust_primes.decorators.timed.TimedFunction` and thus contains additional
+    functionalities with caching and
+    :meth:`~rust_primes.decorators.timed.TimedFunction.timed` executions.
+
+    See :class:`rust_primes.decorators.timed.TimedFunction` for more details.
+
+Parameters
+----------
+lbound : int
+    The lower bound of the primes, inclusive.
+
+ubound : int
+    The upper bound of the primes, inclusive.
+
+base : int
+    The base of the digits. Defaults to ``10``.
+
+method : SieveMethod
+    The method of sieving to be used for finding the primes in the range and the
+    retained mask for looking up the rearranged digits.
+
+Returns
+-------
+list[int]
+    The permutable primes in ascending order.
+
+Raises
+------
+ValueError
+    If ``base`` is less than ``2``.
+
+Examples
+--------
+    >>> from rust_primes import permutable_primes
+    >>> permutable_primes(100, 1000)
+    [113, 131, 199, 311, 337, 373, 733, 919, 991]
+"""
+
+truncatable_primes = decorators.TimedFunction(bin.truncatable_primes)
+"""
+List the truncatable primes between ``lbound`` and ``ubound`` inclusive, i.e. the
+primes which remain prime as their digits in ``base`` are removed one by one from
+``side``.
+
+There are only finitely many truncatable primes in any base, so rather than sieving,
+they are all grown from the single digit primes by adding one digit at a time, each
+level tested in parallel by the deterministic Miller-Rabin test, until they exceed
+``ubound``. In base ``10``, there are ``83`` right-truncatable primes, and ``4260``
+left-truncatable primes of which the largest ``18`` exceed 64-bit integers.
+
+.. note::
+    The GIL is released during calculation, so other Python threads can continue with
+    their work.
+
+.. seealso::
+    While this function behaves like a function, it is implemented through
+    :class:`~rust_primes.decorators.timed.TimedFunction` and thus contains additional
+    functionalities with caching and
+    :meth:`~rust_primes.decorators.timed.TimedFunction.timed` executions.
+
+    See :class:`rust_primes.decorators.timed.TimedFunction` for more details.
+
+Parameters
+----------
+lbound : int
+    The lower bound of the primes, inclusive.
+
+ubound : int
+    The upper bound of the primes, inclusive.
+
+side : TruncationSide
+    The side from which the digits are removed. Defaults to
+    :attr:`TruncationSide.LEFT`.
+
+base : int
+    The base of the digits. Defaults to ``10``.
+
+Returns
+-------
+list[int]
+    The truncatable primes in ascending order.
+
+Raises
+------
+ValueError
+    If ``base`` is less than ``2``.
+
+Examples
+--------
+    >>> from rust_primes import TruncationSide, truncatable_primes
+    >>> truncatable_primes(100, 200)
+    [113, 137, 167, 173, 197]
+    >>> truncatable_primes(100, 400, side=TruncationSide.RIGHT)
+    [233, 239, 293, 311, 313, 317, 373, 379]
+"""
+
+repunit_primes = decorators.TimedFunction(bin.repunit_primes)
+"""
+List the exponents :math:`n` up to and including ``max_exponent``, for which the repunit
+:math:`R_n = (b^n - 1) / (b - 1)` in ``base`` :math:`b` is prime, i.e. the number whose
+:math:`n` digits are all ``1``.
+
+:math:`R_n` can only be prime if :math:`n` is prime, as :math:`R_a` divides
+:math:`R_{ab}`; only the prime exponents are therefore tested, in parallel. Beyond
+64-bit integers, the repunits are tested by the Miller-Rabin test over the first ``12``
+primes, so these are probable primes. In base ``2``, these are the exponents of the
+Mersenne primes, for which :func:`mersenne_primes` is much faster.
+
+.. note::
+    The GIL is released during calculation, so other Python threads can continue with
+    their work.
+
+.. seealso::
+    While this function behaves like a function, it is implemented through
+    :class:`~rust_primes.decorators.timed.TimedFunction` and thus contains additional
+    functionalities with caching and
+    :meth:`~rust_primes.decorators.timed.TimedFunction.timed` executions.
+
+    See :class:`rust_primes.decorators.timed.TimedFunction` for more details.
+
+Parameters
+----------
+max_exponent : int
+    The upper bound of the exponents, inclusive.
+
+base : int
+    The base of the digits. Defaults to ``10``.
+
+method : SieveMethod
+    The method of sieving to be used for finding the prime exponents.
+
+Returns
+-------
+list[int]
+    The exponents in ascending order.
+
+Raises
+------
+ValueError
+    If ``base`` is less than ``2``.
+
+Examples
+--------
+    >>> from rust_primes import repunit_primes
+    >>> repunit_primes(400)
+    [2, 19, 23, 317]
+    >>> repunit_primes(100, base=3)
+    [3, 7, 13, 71]
+"""
+
+special_primes = decorators.TimedFunction(bin.special_primes)
+"""
+List the primes between ``lbound`` and ``ubound`` inclusive of the given ``kind``, i.e.
+whose quotient modulo :math:`p^2` described in :class:`SpecialPrime` is ``0``.
+
+The primes are found by a segmented sieve, and their quotients calculated in parallel
+over its segments in Montgomery arithmetic modulo :math:`p^2`. The Fermat and Fibonacci
+quotients take :math:`O(\\log p)` multiplications each, so Wieferich and Wall-Sun-Sun
+primes can be searched up to around :math:`10^9` within minutes. The Wilson quotient
+takes :math:`O(p)` multiplications for the factorial, which limits Wilson primes to
+around :math:`10^5` to :math:`10^6`.
+
+.. note::
+    The GIL is released during calculation, so other Python threads can continue with
+    their work.
+
+.. seealso::
+    While this function behaves like a function, it is implemented through
+    :class:`~rust_primes.decorators.timed.TimedFunction` and thus contains additional
+    functionalities with caching and
+    :meth:`~rust_primes.decorators.timed.TimedFunction.timed` executions.
+
+    See :class:`rust_primes.decorators.timed.TimedFunction` for more details.
+
+Parameters
+----------
+kind : SpecialPrime
+    The kind of primes to be listed.
+
+lbound : int
+    The lower bound of the primes, inclusive.
+
+ubound : int
+    The upper bound of the primes, inclusive.
+
+method : SieveMethod
+    The method of sieving to be used for finding the primes.
+
+Returns
+-------
+list[int]
+    The primes in ascending order.
+
+Raises
+------
+OverflowError
+    If ``ubound`` is not less than :math:`2^{63}`, as :math:`p^2` must fit into
+    128-bit integers.
+
+Examples
+--------
+    >>> from rust_primes import special_primes, SpecialPrime
+    >>> special_primes(SpecialPrime.WIEFERICH, 0, 10**6)
+    [1093, 3511]
+    >>> special_primes(SpecialPrime.WILSON, 0, 1000)
+    [5, 13, 563]
+"""
+
+special_prime_near_misses = decorators.TimedFunction(bin.special_prime_near_misses)
+"""
+List the primes between ``lbound`` and ``ubound`` inclusive, whose quotient of the given
+``kind`` is at most ``max_quotient`` in absolute value, together with that quotient.
+
+The quotient :math:`q` described in :class:`SpecialPrime` is taken modulo :math:`p` in
+:math:`(-p/2, p/2]`. As it is expected to behave like a random residue, the primes with a
+small :math:`|q|` are the near misses, and with a quotient of ``0``, the primes of the
+``kind`` themselves. See :func:`special_primes` for the algorithm and its limits.
+
+.. note::
+    The GIL is released during calculation, so other Python threads can continue with
+    their work.
+
+.. seealso::
+    While this function behaves like a function, it is implemented through
+    :class:`~rust_primes.decorators.timed.TimedFunction` and thus contains additional
+    functionalities with caching and
+    :meth:`~rust_primes.decorators.timed.TimedFunction.timed` executions.
+
+    See :class:`rust_primes.decorators.timed.TimedFunction` for more details.
+
+Parameters
+----------
+kind : SpecialPrime
+    The kind of quotient to be calculated.
+
+lbound : int
+    The lower bound of the primes, inclusive.
+
+ubound : int
+    The upper bound of the primes, inclusive.
+
+max_quotient : int
+    The largest absolute value of the quotients listed.
+
+method : SieveMethod
+    The method of sieving to be used for finding the primes.
+
+Returns
+-------
+list[tuple[int, int]]
+    The primes in ascending order, each with its signed quotient.
+
+Raises
+------
+OverflowError
+    If ``ubound`` is not less than :math:`2^{63}`, as :math:`p^2` must fit into
+    128-bit integers.
+
+Examples
+--------
+    >>> from rust_primes import special_prime_near_misses, SpecialPrime
+    >>> special_prime_near_misses(SpecialPrime.WIEFERICH, 0, 100, 1)
+    [(3, 1), (29, 1), (37, 1)]
+"""
+
+ramanujan_primes = decorators.TimedFunction(bin.ramanujan_primes)
+"""
+List the first ``n`` Ramanujan primes, where the :math:`k`-th Ramanujan prime
+:math:`R_k` is the smallest number such that :math:`\\pi(x) - \\pi(x/2) \\ge k` for all
+:math:`x \\ge R_k`.
+
+Bertrand's postulate is the case :math:`R_1 = 2`, with a prime in :math:`(x/2, x]` for
+all :math:`x \\ge 2`. As :math:`\\pi(x) - \\pi(x/2)` only rises at primes, :math:`R_k` is
+the largest prime :math:`p` with :math:`\\pi(p) - \\pi(p/2) \\le k`. Since
+:math:`R_k \\le p_{3k}`, the primes up to the upper bound of :math:`p_{3n}` are listed,
+and the count at every one of them found in a single sweep.
+
+.. note::
+    The GIL is released during calculation, so other Python threads can continue with
+    their work.
+
+.. seealso::
+    While this function behaves like a function, it is implemented through
+    :class:`~rust_primes.decorators.timed.TimedFunction` and thus contains additional
+    functionalities with caching and
+    :meth:`~rust_primes.decorators.timed.TimedFunction.timed` executions.
+
+    See :class:`rust_primes.decorators.timed.TimedFunction` for more details.
+
+Parameters
+----------
+n : int
+    The number of Ramanujan primes to be listed.
+
+method : SieveMethod
+    The method of sieving to be used for listing the primes.
+
+Returns
+-------
+list[int]
+    The Ramanujan primes in ascending order.
+
+Examples
+--------
+    >>> from rust_primes import ramanujan_primes
+    >>> ramanujan_primes(10)
+    [2, 11, 17, 29, 41, 47, 59, 67, 71, 97]
+"""
+
+labos_primes = decorators.TimedFunction(bin.labos_primes)
+"""
+List the first ``n`` Labos primes, where the :math:`k`-th Labos prime is the smallest
+number :math:`x` with :math:`\\pi(x) - \\pi(x/2) = k`.
+
+Unlike the Ramanujan primes, the count may fall below :math:`k` again after the
+:math:`k`-th Labos prime, which is therefore at most the :math:`k`-th Ramanujan prime.
+See :func:`ramanujan_primes` for the algorithm.
+
+.. note::
+    The GIL is released during calculation, so other Python threads can continue with
+    their work.
+
+.. seealso::
+    While this function behaves like a function, it is implemented through
+    :class:`~rust_primes.decorators.timed.TimedFunction` and thus contains additional
+    functionalities with caching and
+    :meth:`~rust_primes.decorators.timed.TimedFunction.timed` executions.
+
+    See :class:`rust_primes.decorators.timed.TimedFunction` for more details.
+
+Parameters
+----------
+n : int
+    The number of Labos primes to be listed.
+
+method : SieveMethod
+    The method of sieving to be used for listing the primes.
+
+Returns
+-------
+list[int]
+    The Labos primes in ascending order.
+
+Examples
+--------
+    >>> from rust_primes import labos_primes
+    >>> labos_primes(10)
+    [2, 3, 13, 19, 31, 43, 53, 61, 71, 73]
+"""
+
+primorial = decorators.TimedFunction(bin.primorial)
+"""
+Calculate the primorial :math:`n\\#`, the product of every prime up to and including
+``n``.
+
+The primes are multiplied by a product tree, i.e. the products of both halves are
+multiplied recursively in parallel. This keeps the operands balanced in size, which is
+much faster for large ``n`` than multiplying the primes one by one.
+
+.. note::
+    The GIL is released during calculation, so other Python threads can continue with
+    their work.
+
+.. seealso::
+    While this function behaves like a function, it is implemented through
+    :class:`~rust_primes.decorators.timed.TimedFunction` and thus contains additional
+    functionalities with caching and
+    :meth:`~rust_primes.decorators.timed.TimedFunction.timed` executions.
+
+    See :class:`rust_primes.decorators.timed.TimedFunction` for more details.
+
+Parameters
+----------
+n : int
+    The upper bound of the primes, inclusive.
+
+method : SieveMethod
+    The method of sieving to be used for listing the primes.
+
+Returns
+-------
+int
+    The primorial, ``1`` if ``n`` is less than ``2``.
+
+Examples
+--------
+    >>> from rust_primes import primorial
+    >>> primorial(30)
+    6469693230
+"""
+
+primorial_of_nth = decorators.TimedFunction(bin.primorial_of_nth)
+"""
+Calculate the primorial :math:`p_n\\#`, the product of the first ``n`` primes.
+
+See :func:`primorial` for the algorithm.
+
+.. note::
+    The GIL is released during calculation, so other Python threads can continue with
+    their work.
+
+.. seealso::
+    While this function behaves like a function, it is implemented through
+    :class:`~rust_primes.decorators.timed.TimedFunction` and thus contains additional
+    functionalities with caching and
+    :meth:`~rust_primes.decorators.timed.TimedFunction.timed` executions.
+
+    See :class:`rust_primes.decorators.timed.TimedFunction` for more details.
+
+Parameters
+----------
+n : int
+    The number of primes.
+
+method : SieveMethod
+    The method of sieving to be used for listing the primes.
+
+Returns
+-------
+int
+    The primorial, ``1`` if ``n`` is ``0``.
+
+Examples
+--------
+    >>> from rust_primes import primorial_of_nth
+    >>> primorial_of_nth(5)
+    2310
+"""
+
+bpsw = decorators.TimedFunction(bin.bpsw)
+"""
+Check if the given number is a probable prime, by the Baillie-PSW test.
+
+After trial division by the primes below ``100``, this is a strong probable prime test to
+base ``2``, followed by a strong Lucas probable prime test with the parameters of
+Selfridge's method A, i.e. :math:`P = 1` and :math:`Q = (1 - D) / 4` for the first
+:math:`D` in :math:`5, -7, 9, -11, \\dots` with the Jacobi symbol :math:`(D/n) = -1`. No
+composite number passing both is known, and there are none below :math:`2^{64}`, for
+which the test is replaced by the deterministic Miller-Rabin test.
+
+.. note::
+    The GIL is released during calculation, so other Python threads can continue with
+    their work.
+
+.. seealso::
+    While this function behaves like a function, it is implemented through
+    :class:`~rust_primes.decorators.timed.TimedFunction` and thus contains additional
+    functionalities with caching and
+    :meth:`~rust_primes.decorators.timed.TimedFunction.timed` executions.
+
+    See :class:`rust_primes.decorators.timed.TimedFunction` for more details.
+
+Parameters
+----------
+num : int
+    The number to be checked.
+
+Returns
+-------
+bool
+    Whether ``num`` is a probable prime.
+
+Examples
+--------
+    >>> from rust_primes import bpsw
+    >>> bpsw(2**127 - 1)
+    True
+    >>> bpsw(2**128 + 1)
+    False
+"""
+
+primorial_primes = decorators.TimedFunction(bin.primorial_primes)
+"""
+List the primes :math:`p` up to and including ``ubound``, for which
+:math:`p\\# + offset` is a probable prime by the Baillie-PSW test.
+
+The primorials are built up one prime at a time, and tested in parallel in batches; see
+:func:`bpsw`. With an ``offset`` of ``1`` and ``-1``, these are the primes of the
+primorial primes :math:`p\\# \\pm 1`.
+
+.. note::
+    The GIL is released during calculation, so other Python threads can continue with
+    their work.
+
+.. seealso::
+    While this function behaves like a function, it is implemented through
+    :class:`~rust_primes.decorators.timed.TimedFunction` and thus contains additional
+    functionalities with caching and
+    :meth:`~rust_primes.decorators.timed.TimedFunction.timed` executions.
+
+    See :class:`rust_primes.decorators.timed.TimedFunction` for more details.
+
+Parameters
+----------
+ubound : int
+    The upper bound of the primes, inclusive.
+
+offset : int
+    The offset added to the primorials. Defaults to ``1``.
+
+method : SieveMethod
+    The method of sieving to be used for listing the primes.
+
+Returns
+-------
+list[int]
+    The primes in ascending order.
+
+Examples
+--------
+    >>> from rust_primes import primorial_primes
+    >>> primorial_primes(1000)
+    [2, 3, 5, 7, 11, 31, 379]
+    >>> primorial_primes(1000, offset=-1)
+    [3, 5, 11, 13, 41, 89, 317, 337, 991]
+"""
+
+factorial_primes = decorators.TimedFunction(bin.factorial_primes)
+"""
+List the :math:`n` up to and including ``ubound``, for which :math:`n! + offset` is a
+probable prime by the Baillie-PSW test.
+
+The factorials are built up one factor at a time, and tested in parallel in batches;
+see :func:`bpsw`. With an ``offset`` of ``1`` and ``-1``, these give the factorial primes
+:math:`n! \\pm 1`.
+
+.. note::
+    The GIL is released during calculation, so other Python threads can continue with
+    their work.
+
+.. seealso::
+    While this function behaves like a function, it is implemented through
+    :class:`~rust_primes.decorators.timed.TimedFunction` and thus contains additional
+    functionalities with caching and
+    :meth:`~rust_primes.decorators.timed.TimedFunction.timed` executions.
+
+    See :class:`rust_primes.decorators.timed.TimedFunction` for more details.
+
+Parameters
+----------
+ubound : int
+    The upper bound of :math:`n`, inclusive.
+
+offset : int
+    The offset added to the factorials. Defaults to ``1``.
+
+Returns
+-------
+list[int]
+    The :math:`n` in ascending order.
+
+Examples
+--------
+    >>> from rust_primes import factorial_primes
+    >>> factorial_primes(100)
+    [0, 1, 2, 3, 11, 27, 37, 41, 73, 77]
+    >>> factorial_primes(100, offset=-1)
+    [3, 4, 6, 7, 12, 14, 30, 32, 33, 38, 94]
+"""
+
+list_almost_primes = decorators.TimedFunction(bin.list_almost_primes)
+"""
+List the :math:`k`-almost primes from ``lbound`` up to and including ``num``, i.e. the
+numbers with exactly ``k`` prime factors counted with multiplicity,
+:math:`\\Omega(n) = k`.
+
+:math:`\\Omega(n)` is sieved as in :func:`big_omega_sieve`, over windows of the range in
+parallel, so that the memory of the sieve stays bounded however large the range. The
+``1``-almost primes are the primes, and the ``2``-almost primes the semiprimes; the only
+``0``-almost prime is ``1``.
+
+.. note::
+    The GIL is released during calculation, so other Python threads can continue with
+    their work.
+
+.. seealso::
+    While this function behaves like a function, it is implemented through
+    :class:`~rust_primes.decorators.timed.TimedFunction` and thus contains additional
+    functionalities with caching and
+    :meth:`~rust_primes.decorators.timed.TimedFunction.timed` executions.
+
+    See :class:`rust_primes.decorators.timed.TimedFunction` for more details.
+
+Parameters
+----------
+k : int
+    The number of prime factors, counted with multiplicity.
+
+num : int
+    The upper bound of the numbers, inclusive.
+
+lbound : int
+    The lower bound of the numbers, inclusive. Defaults to ``0``.
+
+method : SieveMethod
+    The method of sieving to be used for the primes up to :math:`\\sqrt{num}`.
+
+Returns
+-------
+list[int]
+    The :math:`k`-almost primes in ascending order.
+
+Examples
+--------
+    >>> from rust_primes import list_almost_primes
+    >>> list_almost_primes(3, 50)
+    [8, 12, 18, 20, 27, 28, 30, 42, 44, 45, 50]
+"""
+
+count_almost_primes = decorators.TimedFunction(bin.count_almost_primes)
+"""
+Count the :math:`k`-almost primes up to and including ``num``, i.e. the numbers with
+exactly ``k`` prime factors counted with multiplicity, without sieving beyond
+:math:`\\sqrt{num}`.
+
+Writing such a number as :math:`p_1 p_2 \\cdots p_k` with
+:math:`p_1 \\le p_2 \\le \\dots \\le p_k`, every choice of the first :math:`k - 1`
+factors leaves the primes :math:`p_k` with :math:`p_{k-1} \\le p_k \\le
+num / (p_1 \\cdots p_{k-1})` for the last, so that for the semiprimes
+
+.. math::
+    \\pi_2(x) = \\sum_{p \\le \\sqrt{x}} \\left( \\pi(x / p) - \\pi(p) + 1 \\right)
+
+and larger ``k`` recurse over the smallest factor. All the prime counts are at quotients
+:math:`\\lfloor num / i \\rfloor`, which are found at once in
+:math:`O(num^{3/4})` as in :func:`prime_pi`; the rest of the recursion is small in
+comparison for small ``k``, and runs in parallel over the smallest factor.
+
+.. note::
+    The GIL is released during calculation, so other Python threads can continue with
+    their work.
+
+.. seealso::
+    While this function behaves like a function, it is implemented through
+    :class:`~rust_primes.decorators.timed.TimedFunction` and thus contains additional
+    functionalities with caching and
+    :meth:`~rust_primes.decorators.timed.TimedFunction.timed` executions.
+
+    See :class:`rust_primes.decorators.timed.TimedFunction` for more details.
+
+Parameters
+----------
+k : int
+    The number of prime factors, counted with multiplicity.
+
+num : int
+    The upper bound of the numbers, inclusive.
+
+method : SieveMethod
+    The method of sieving to be used for the primes up to :math:`\\sqrt{num}`.
+
+Returns
+-------
+int
+    The number of :math:`k`-almost primes.
+
+Examples
+--------
+    >>> from rust_primes import count_almost_primes
+    >>> count_almost_primes(2, 10**8)
+    17427258
+    >>> count_almost_primes(3, 1000)
+    247
+"""
+
+semiprimes_in = decorators.TimedFunction(bin.semiprimes_in)
+"""
+List the semiprimes between ``lbound`` and ``ubound`` inclusive, i.e. the products of
+two primes, not necessarily distinct.
+
+This is :func:`list_almost_primes` with ``k`` of ``2``. To count the semiprimes in the
+range instead, take the difference of :func:`count_almost_primes` at both ends.
+
+.. note::
+    The GIL is released during calculation, so other Python threads can continue with
+    their work.
+
+.. seealso::
+    While this function behaves like a function, it is implemented through
+    :class:`~rust_primes.decorators.timed.TimedFunction` and thus contains additional
+    functionalities with caching and
+    :meth:`~rust_primes.decorators.timed.TimedFunction.timed` executions.
+
+    See :class:`rust_primes.decorators.timed.TimedFunction` for more details.
+
+Parameters
+----------
+lbound : int
+    The lower bound of the numbers, inclusive.
+
+ubound : int
+    The upper bound of the numbers, inclusive.
+
+method : SieveMethod
+    The method of sieving to be used for the primes up to :math:`\\sqrt{ubound}`.
+
+Returns
+-------
+list[int]
+    The semiprimes in ascending order.
+
+Examples
+--------
+    >>> from rust_primes import semiprimes_in
+    >>> semiprimes_in(10, 40)
+    [10, 14, 15, 21, 22, 25, 26, 33, 34, 35, 38, 39]
+"""
+
+smooth_numbers = decorators.TimedFunction(bin.smooth_numbers)
+"""
+List the ``bound``-smooth numbers from ``lbound`` up to and including ``num``, i.e. the
+positive numbers without any prime factor above ``bound``.
+
+The range is sieved in windows in parallel, accumulating :math:`\\ln p` at the multiples
+of every power of every prime :math:`p \\le bound`. Each number then holds the logarithm
+of its ``bound``-smooth part; as any remaining part is at least ``2``, a number is smooth
+exactly when this is within :math:`\\ln 2` of its own logarithm, so the accumulation in
+single precision is exact.
+
+.. note::
+    The GIL is released during calculation, so other Python threads can continue with
+    their work.
+
+.. seealso::
+    While this function behaves like a function, it is implemented through
+    :class:`~rust_primes.decorators.timed.TimedFunction` and thus contains additional
+    functionalities with caching and
+    :meth:`~rust_primes.decorators.timed.TimedFunction.timed` executions.
+
+    See :class:`rust_primes.decorators.timed.TimedFunction` for more details.
+
+Parameters
+----------
+bound : int
+    The largest prime factor allowed, inclusive.
+
+num : int
+    The upper bound of the numbers, inclusive.
+
+lbound : int
+    The lower bound of the numbers, inclusive. Defaults to ``0``.
+
+method : SieveMethod
+    The method of sieving to be used for the primes up to ``bound``.
+
+Returns
+-------
+list[int]
+    The smooth numbers in ascending order, starting from ``1``.
+
+Examples
+--------
+    >>> from rust_primes import smooth_numbers
+    >>> smooth_numbers(3, 30)
+    [1, 2, 3, 4, 6, 8, 9, 12, 16, 18, 24, 27]
+"""
+
+rough_numbers = decorators.TimedFunction(bin.rough_numbers)
+"""
+List the ``bound``-rough numbers between ``lbound`` and ``ubound`` inclusive, i.e. the
+positive numbers without any prime factor up to ``bound``.
+
+Every multiple of every prime :math:`p \\le bound` is struck off windows of the range in
+parallel, as in a segmented sieve that stops at ``bound``. In the convention where
+:math:`k`-rough numbers have no prime factor below :math:`k`, these are the
+:math:`(bound + 1)`-rough numbers; they include ``1``, and every prime above ``bound``.
+
+.. note::
+    The GIL is released during calculation, so other Python threads can continue with
+    their work.
+
+.. seealso::
+    While this function behaves like a function, it is implemented through
+    :class:`~rust_primes.decorators.timed.TimedFunction` and thus contains additional
+    functionalities with caching and
+    :meth:`~rust_primes.decorators.timed.TimedFunction.timed` executions.
+
+    See :class:`rust_primes.decorators.timed.TimedFunction` for more details.
+
+Parameters
+----------
+bound : int
+    The largest prime factor excluded, inclusive.
+
+lbound : int
+    The lower bound of the numbers, inclusive.
+
+ubound : int
+    The upper bound of the numbers, inclusive.
+
+method : SieveMethod
+    The method of sieving to be used for the primes up to ``bound``.
+
+Returns
+-------
+list[int]
+    The rough numbers in ascending order.
+
+Examples
+--------
+    >>> from rust_primes import rough_numbers
+    >>> rough_numbers(5, 0, 50)
+    [1, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 49]
+"""
+
+count_smooth_numbers = decorators.TimedFunction(bin.count_smooth_numbers)
+"""
+Count the ``bound``-smooth numbers up to and including ``num`` exactly, i.e. de
+Bruijn's function :math:`\\Psi(x, y)` with :math:`x` of ``num`` and :math:`y` of
+``bound``.
+
+Counting each smooth number above ``1`` by its largest prime factor gives the Buchstab
+identity
+
+.. math::
+    \\Psi(x, y) = 1 + \\sum_{p \\le y} \\Psi(x / p, p)
+
+which is recursed into until :math:`p^2 \\ge x`. From there, every number up to
+:math:`x` has at most one prime factor :math:`q > p`, so those that are not smooth are
+counted directly as :math:`\\sum_{p < q \\le x} \\lfloor x / q \\rfloor` from the prime
+counts at :math:`\\lfloor x / k \\rfloor`. These come from the same table as
+:func:`prime_pi`, or from the primes up to :math:`y^2` if that is smaller. The largest
+prime factors are counted in parallel. See :func:`estimate_smooth_numbers` for an
+approximation.
+
+.. note::
+    The GIL is released during calculation, so other Python threads can continue with
+    their work.
+
+.. seealso::
+    While this function behaves like a function, it is implemented through
+    :class:`~rust_primes.decorators.timed.TimedFunction` and thus contains additional
+    functionalities with caching and
+    :meth:`~rust_primes.decorators.timed.TimedFunction.timed` executions.
+
+    See :class:`rust_primes.decorators.timed.TimedFunction` for more details.
+
+Parameters
+----------
+bound : int
+    The largest prime factor allowed, inclusive.
+
+num : int
+    The upper bound of the numbers, inclusive.
+
+method : SieveMethod
+    The method of sieving to be used for the primes.
+
+Returns
+-------
+int
+    The number of smooth numbers.
+
+Examples
+--------
+    >>> from rust_primes import count_smooth_numbers
+    >>> count_smooth_numbers(1000, 10**9)
+    59244184
+"""
+
+estimate_smooth_numbers = decorators.TimedFunction(bin.estimate_smooth_numbers)
+"""
+Estimate the number of ``bound``-smooth numbers up to and including ``num``, i.e. de
+Bruijn's function :math:`\\Psi(x, y)`, by the Dickman function :math:`\\rho`:
+
+.. math::
+    \\Psi(x, y) \\approx x \\left( \\rho(u) + (1 - \\gamma)
+    \\frac{\\rho(u - 1)}{\\ln x} \\right), \\quad u = \\frac{\\ln x}{\\ln y}
+
+which is de Bruijn's expansion up to its second term. By Hildebrand, the first term
+alone is asymptotic to :math:`\\Psi(x, y)` whenever
+:math:`y > \\exp((\\ln \\ln x)^{5/3 + \\varepsilon})`; for small ``bound`` and large
+``num``, the estimate falls well short. See :func:`count_smooth_numbers` for the exact
+count.
+
+.. note::
+    This function does NOT have a :attr:`method` parameter.
+
+.. seealso::
+    While this function behaves like a function, it is implemented through
+    :class:`~rust_primes.decorators.timed.TimedFunction` and thus contains additional
+    functionalities with caching and
+    :meth:`~rust_primes.decorators.timed.TimedFunction.timed` executions.
+
+    See :class:`rust_primes.decorators.timed.TimedFunction` for more details.
+
+Parameters
+----------
+bound : int
+    The largest prime factor allowed, inclusive.
+
+num : int
+    The upper bound of the numbers, inclusive.
+
+Returns
+-------
+float
+    The estimated number of smooth numbers.
+
+Examples
+--------
+    >>> from rust_primes import estimate_smooth_numbers
+    >>> round(estimate_smooth_numbers(10**6, 10**12) / 322895427961, 4)
+    0.9977
+"""
+
+dickman_rho = decorators.TimedFunction(bin.dickman_rho)
+"""
+Calculate the Dickman function :math:`\\rho(u)`, the limit of
+:math:`\\Psi(x, x^{1/u}) / x`, i.e. the density of the numbers whose prime factors are
+all at most their :math:`u`-th root.
+
+:math:`\\rho(u) = 1` up to ``1``, and :math:`1 - \\ln u` up to ``2``. Beyond that,
+:math:`u \\rho(u) = \\int_{u-1}^{u} \\rho(t) \\, dt` is integrated by the trapezoidal
+rule on a grid of ``4096`` points per unit, with :math:`\\ln \\rho` interpolated
+between them. As every term is positive, the relative error stays small however tiny
+:math:`\\rho(u)` becomes.
+
+.. note::
+    This function does NOT have a :attr:`method` parameter.
+
+.. seealso::
+    While this function behaves like a function, it is implemented through
+    :class:`~rust_primes.decorators.timed.TimedFunction` and thus contains additional
+    functionalities with caching and
+    :meth:`~rust_primes.decorators.timed.TimedFunction.timed` executions.
+
+    See :class:`rust_primes.decorators.timed.TimedFunction` for more details.
+
+Parameters
+----------
+u : float
+    The argument of the function.
+
+Returns
+-------
+float
+    The value of the function, ``0`` for negative ``u``.
+
+Examples
+--------
+    >>> from rust_primes import dickman_rho
+    >>> round(dickman_rho(3), 8)
+    0.04860839
+"""
+
+gaussian_primes = decorators.TimedFunction(bin.gaussian_primes)
+"""
+Sieve the Gaussian primes :math:`a + bi` with norm :math:`a^2 + b^2` up to and including
+``norm``, as a 2-D mask for plotting.
+
+Every Gaussian prime is a unit multiple of either a rational prime
+:math:`p \\equiv 3 \\pmod 4`, with norm :math:`p^2`, or of :math:`a + bi` or
+:math:`b + ai` where :math:`p = a^2 + b^2` is ``2`` or a prime
+:math:`p \\equiv 1 \\pmod 4`. The rational primes up to ``norm`` are therefore
+classified by their residue modulo ``4``, and decomposed in parallel as in
+:func:`two_squares`, before all their associates and conjugates are marked.
+
+.. note::
+    The GIL is released during calculation, so other Python threads can continue with
+    their work.
+
+.. seealso::
+    While this function behaves like a function, it is implemented through
+    :class:`~rust_primes.decorators.timed.TimedFunction` and thus contains additional
+    functionalities with caching and
+    :meth:`~rust_primes.decorators.timed.TimedFunction.timed` executions.
+
+    See :class:`rust_primes.decorators.timed.TimedFunction` for more details.
+
+Parameters
+----------
+norm : int
+    The upper bound of the norms, inclusive.
+
+method : SieveMethod
+    The method of sieving to be used for listing the rational primes.
+
+Returns
+-------
+numpy.ndarray
+    A 2-D array of ``numpy.bool_`` of shape ``(2r + 1, 2r + 1)`` with
+    :math:`r = \\lfloor \\sqrt{norm} \\rfloor`, where the entry at ``[r + a, r + b]`` is
+    whether :math:`a + bi` is a Gaussian prime. For plotting with the real part along
+    the horizontal axis, take its transpose.
+
+Examples
+--------
+    >>> from rust_primes import gaussian_primes
+    >>> mask = gaussian_primes(10)
+    >>> mask.shape
+    (7, 7)
+    >>> mask[3 + 1, 3 + 1], mask[3 + 3, 3], mask[3 + 2, 3]
+    (True, True, False)
+"""
+
+two_squares = decorators.TimedFunction(bin.two_squares)
+"""
+Decompose a prime into the sum of two squares :math:`a^2 + b^2` with :math:`a \\ge b`.
+
+By Fermat's theorem on sums of two squares, this is possible exactly for ``2`` and the
+primes :math:`p \\equiv 1 \\pmod 4`. A square root :math:`x` of :math:`-1` modulo
+:math:`p` is taken as a power of a quadratic non-residue; by the Hermite-Serret
+algorithm, the remainders of the Euclidean algorithm on :math:`p` and :math:`x` then
+pass below :math:`\\sqrt{p}` at :math:`a`, followed by :math:`b`.
+
+.. note::
+    This function does NOT have a :attr:`method` parameter.
+
+.. seealso::
+    While this function behaves like a function, it is implemented through
+    :class:`~rust_primes.decorators.timed.TimedFunction` and thus contains additional
+    functionalities with caching and
+    :meth:`~rust_primes.decorators.timed.TimedFunction.timed` executions.
+
+    See :class:`rust_primes.decorators.timed.TimedFunction` for more details.
+
+Parameters
+----------
+prime : int
+    The prime to be decomposed.
+
+Returns
+-------
+tuple[int, int]
+    The pair :math:`(a, b)`.
+
+Raises
+------
+ValueError
+    If ``prime`` is not ``2`` or a prime congruent to ``1`` modulo ``4``.
+
+Examples
+--------
+    >>> from rust_primes import two_squares
+    >>> two_squares(13)
+    (3, 2)
+    >>> two_squares(1000000009)
+    (31400, 3747)
+"""
+
+prime_spiral = decorators.TimedFunction(bin.prime_spiral, side_effects=("path",))
+"""
+Lay the numbers from ``start`` onwards out on a spiral in a square image of ``side``
+pixels, with the primes lit up as grey levels, e.g. for an Ulam spiral.
+
+The primes come from a segmented sieve over the numbers laid out, and the image can be
+written to a PGM or PNG file directly, without any dependency on the Python side.
+
+The image is ``0`` everywhere except for:
+
+- The primes, at ``255``; or if ``colour_gaps``, from ``64`` up to ``255`` in proportion
+  to the gap to the next prime, relative to the widest gap in the image.
+- The values :math:`a k^2 + b k + c` for :math:`k \\ge 0` of each of the
+  ``polynomials``: the primes among them are lit as usual while all other primes are
+  dimmed to half, and the composites among them are lit at ``48``.
+
+Where several numbers fall on the same pixel, as at the centre of a Sacks spiral, the
+brightest is kept.
+
+.. note::
+    The GIL is released during calculation, so other Python threads can continue with
+    their work.
+
+.. seealso::
+    While this function behaves like a function, it is implemented through
+    :class:`~rust_primes.decorators.timed.TimedFunction` and thus contains additional
+    functionalities with caching and
+    :meth:`~rust_primes.decorators.timed.TimedFunction.timed` executions.
+
+    See :class:`rust_primes.decorators.timed.TimedFunction` for more details.
+
+Parameters
+----------
+side : int
+    The width and height of the image in pixels.
+
+kind : SpiralKind
+    The kind of spiral. Defaults to :attr:`SpiralKind.ULAM`.
+
+start : int
+    The number at the centre of an Ulam spiral. Defaults to ``1``; ``41`` lines up the
+    primes of :math:`n^2 + n + 41` along a diagonal. As a Sacks spiral lays every number
+    out at its own radius, the numbers below ``start`` are left out of it instead.
+
+colour_gaps : bool
+    If ``True``, light each prime by the gap to the next prime.
+
+polynomials : list[tuple[int, int, int]]
+    The coefficients ``(a, b, c)`` of the quadratic polynomials to be highlighted.
+
+path : str | None
+    If given, the image is also written to this file, as a binary PGM if it ends with
+    ``.pgm`` or as a PNG if it ends with ``.png``.
+
+method : SieveMethod
+    The method of sieving to be used.
+
+Returns
+-------
+numpy.ndarray
+    A 2-D array of ``numpy.uint8`` of shape ``(side, side)``, with row ``0`` at the top.
+
+Raises
+------
+ValueError
+    If ``path`` ends with neither ``.pgm`` nor ``.png``.
+
+OSError
+    If ``path`` cannot be written to.
+
+Examples
+--------
+    >>> from rust_primes import prime_spiral
+    >>> prime_spiral(5).tolist()
+    [[255, 0, 0, 0, 255],
+     [0, 255, 0, 255, 0],
+     [255, 0, 0, 255, 255],
+     [0, 255, 0, 0, 0],
+     [0, 0, 255, 0, 0]]
+"""
+
+polynomial_primes = decorators.TimedFunction(bin.polynomial_primes)
+"""
+Find the integers ``n`` in ``lbound..=ubound``, for which the value of an integer
+polynomial :math:`f(n)` is prime.
+
+Rather than testing every value, the values are sieved: a prime :math:`p` divides
+:math:`f(n)` exactly when :math:`n` is congruent to a root of :math:`f` modulo
+:math:`p`, so every root of every sieving prime strikes off an arithmetic progression of
+``n``. The roots are found by the Cantor-Zassenhaus algorithm, and any value beyond the
+square of the largest sieving prime that survives the sieve is confirmed by a
+deterministic Miller-Rabin test.
+
+A value :math:`f(n)` counts as prime only if it is a positive prime, so
+:math:`-f` has no prime values wherever :math:`f` is positive.
+
+.. note::
+    The GIL is released during calculation, so other Python threads can continue with
+    their work.
+
+.. seealso::
+    While this function behaves like a function, it is implemented through
+    :class:`~rust_primes.decorators.timed.TimedFunction` and thus contains additional
+    functionalities with caching and
+    :meth:`~rust_primes.decorators.timed.TimedFunction.timed` executions.
+
+    See :class:`rust_primes.decorators.timed.TimedFunction` for more details.
+
+Parameters
+----------
+coefficients : list[int]
+    The coefficients of :math:`f`, from the highest power down to the constant, as in
+    ``numpy.polyval``; e.g. ``[1, 0, 1]`` for :math:`n^2 + 1`.
+
+lbound : int
+    The lower bound of ``n``, inclusive.
+
+ubound : int
+    The upper bound of ``n``, inclusive.
+
+count_only : bool
+    If ``True``, return the number of such ``n`` instead of listing them.
+
+method : SieveMethod
+    The method of sieving to be used for finding the sieving primes.
+
+Returns
+-------
+list[int] | int
+    The ``n`` in ascending order, or their number if ``count_only``.
+
+Raises
+------
+ValueError
+    If every coefficient is ``0``.
+
+OverflowError
+    If the values of the polynomial up to ``ubound`` do not fit into 64 bits.
+
+Examples
+--------
+    >>> from rust_primes import polynomial_primes
+    >>> polynomial_primes([1, 0, 1], 0, 20)
+    [1, 2, 4, 6, 10, 14, 16, 20]
+    >>> polynomial_primes([1, 1, 41], 0, 100, count_only=True)
+    87
+"""
+
+bateman_horn_constant = decorators.TimedFunction(bin.bateman_horn_constant)
+"""
+Calculate the Bateman-Horn constant of an irreducible integer polynomial :math:`f`.
+
+This is the singular series
+
+.. math::
+    C = \\prod_{p} \\frac{1 - w(p) / p}{1 - 1 / p}
+
+over all primes :math:`p`, where :math:`w(p)` is the number of roots of :math:`f` modulo
+:math:`p`. It is ``0`` if some prime divides every value of :math:`f`, as ``2`` does for
+:math:`n^2 + n + 2`.
+
+The product is evaluated over the primes up to :math:`2^{22}`. As :math:`w(p)` is only
+``1`` on average, the product converges slowly, and is accurate to a few digits; e.g. it
+gives ``1.37275`` for :math:`n^2 + 1`, whose constant is known to be ``1.37281``.
+
+.. note::
+    The GIL is released during calculation, so other Python threads can continue with
+    their work.
+
+.. seealso::
+    While this function behaves like a function, it is implemented through
+    :class:`~rust_primes.decorators.timed.TimedFunction` and thus contains additional
+    functionalities with caching and
+    :meth:`~rust_primes.decorators.timed.TimedFunction.timed` executions.
+
+    See :class:`rust_primes.decorators.timed.TimedFunction` for more details.
+
+Parameters
+----------
+coefficients : list[int]
+    The coefficients of :math:`f`, from the highest power down to the constant, as in
+    ``numpy.polyval``.
+
+method : SieveMethod
+    The method of sieving to be used for finding the primes of the product.
+
+Returns
+-------
+float
+
+Raises
+------
+ValueError
+    If :math:`f` is constant or its leading coefficient is not positive.
+
+Examples
+--------
+    >>> from rust_primes import bateman_horn_constant
+    >>> round(bateman_horn_constant([1, 0, 1]), 4)
+    1.3727
+    >>> round(bateman_horn_constant([1, 1, 41]), 4)
+    6.6396
+"""
+
+bateman_horn_estimate = decorators.TimedFunction(bin.bateman_horn_estimate)
+"""
+Estimate the number of integers ``n`` in ``lbound..=ubound``, for which :math:`f(n)` is
+prime, by the Bateman-Horn conjecture.
+
+The estimate is
+
+.. math::
+    C \\int_{lbound}^{ubound} \\frac{dt}{\\ln f(t)}
+
+where :math:`C` is the :func:`bateman_horn_constant`, to be compared against the counts
+of :func:`polynomial_primes`. The integrand is taken as ``0`` wherever
+:math:`f(t) < e`.
+
+.. note::
+    The GIL is released during calculation, so other Python threads can continue with
+    their work.
+
+.. seealso::
+    While this function behaves like a function, it is implemented through
+    :class:`~rust_primes.decorators.timed.TimedFunction` and thus contains additional
+    functionalities with caching and
+    :meth:`~rust_primes.decorators.timed.TimedFunction.timed` executions.
+
+    See :class:`rust_primes.decorators.timed.TimedFunction` for more details.
+
+Parameters
+----------
+coefficients : list[int]
+    The coefficients of :math:`f`, from the highest power down to the constant, as in
+    ``numpy.polyval``.
+
+lbound : int
+    The lower bound of ``n``, inclusive.
+
+ubound : int
+    The upper bound of ``n``, inclusive.
+
+method : SieveMethod
+    The method of sieving to be used for finding the primes of the constant.
+
+Returns
+-------
+float
+
+Raises
+------
+ValueError
+    If :math:`f` is constant or its leading coefficient is not positive.
+
+Examples
+--------
+    >>> from rust_primes import bateman_horn_estimate, polynomial_primes
+    >>> polynomial_primes([1, 0, 1], 0, 10**6, count_only=True)
+    54110
+    >>> round(bateman_horn_estimate([1, 0, 1], 0, 10**6))
+    53968
+"""
+
+polynomial_roots = decorators.TimedFunction(bin.polynomial_roots)
+"""
+Find the roots of an integer polynomial :math:`f` modulo every prime up to and including
+``bound``, e.g. to assess the polynomials of the number field sieve.
+
+The roots are found in parallel over the primes, by the Cantor-Zassenhaus algorithm, or
+by evaluating every residue for small primes. They are returned in compressed sparse row
+form, where the roots modulo ``primes[i]`` are ``roots[offsets[i]:offsets[i + 1]]``.
+Every residue is a root modulo a prime that divides every coefficient.
+
+From these, the Murphy alpha of :math:`f` is
+
+.. math::
+    \\alpha = \\sum_{p \\le bound} \\left(1 - \\frac{p \\, w(p)}{p + 1}\\right)
+    \\frac{\\ln p}{p - 1}
+
+where :math:`w(p)` is the number of roots modulo :math:`p`, with the projective root
+counted wherever :math:`p` divides the leading coefficient. The values of :math:`f` are
+about :math:`e^{\\alpha}` times as likely to be smooth as random integers of the same
+size, so that a negative alpha favours the polynomial.
+
+.. note::
+    The GIL is released during calculation, so other Python threads can continue with
+    their work.
+
+.. seealso::
+    While this function behaves like a function, it is implemented through
+    :class:`~rust_primes.decorators.timed.TimedFunction` and thus contains additional
+    functionalities with caching and
+    :meth:`~rust_primes.decorators.timed.TimedFunction.timed` executions.
+
+    See :class:`rust_primes.decorators.timed.TimedFunction` for more details.
+
+Parameters
+----------
+coefficients : list[int]
+    The coefficients of :math:`f`, from the highest power down to the constant, as in
+    ``numpy.polyval``.
+
+bound : int
+    The upper bound of the primes, inclusive.
+
+method : SieveMethod
+    The method of sieving to be used for finding the primes.
+
+Returns
+-------
+dict
+    A ``dict`` with the following keys:
+
+    - ``"primes"``: a 1-D array of ``numpy.uint64`` of the primes in ascending order.
+    - ``"offsets"``: a 1-D array of ``numpy.uint64`` with one more element than
+      ``"primes"``, starting from ``0``.
+    - ``"roots"``: a 1-D array of ``numpy.uint64`` of the distinct roots modulo each
+      prime in ascending order, one prime after another.
+    - ``"alpha"``: the Murphy alpha over these primes.
+
+Raises
+------
+ValueError
+    If every coefficient is ``0``.
+
+Examples
+--------
+    >>> from rust_primes import polynomial_roots
+    >>> _roots = polynomial_roots([1, 0, 1], 20)
+    >>> _roots["primes"]
+    array([ 2,  3,  5,  7, 11, 13, 17, 19], dtype=uint64)
+    >>> _roots["offsets"]
+    array([0, 1, 1, 3, 3, 3, 5, 7, 7], dtype=uint64)
+    >>> _roots["roots"]
+    array([ 1,  2,  3,  5,  8,  4, 13], dtype=uint64)
+    >>> round(_roots["alpha"], 4)
+    0.8992
+"""
@@ -120,14 +120,23 @@ class TimedFunction:
         ... _result.avg # doctest: +SKIP
         1.461696985643357e-06
 
+    Parameters
+    ----------
+    func : Callable
+        The function to be decorated.
 
+    side_effects : tuple[str, ...]
+        The names of the keyworded parameters, such as a ``path`` to write to, that
+        cause side effects when given. Calls given any of these are never cached.
     """
 
     _func: ClassVar[Callable]
+    _side_effects: tuple[str, ...] = ()
     _last_returned: Any = None
 
-    def __init__(self, func: Callable) -> None:
+    def __init__(self, func: Callable, *, side_effects: tuple[str, ...] = ()) -> None:
         self._func = func
+        self._side_effects = side_effects
 
     def uncached_call(self, *args: Any, **kwargs: Any) -> Any:
         """
@@ -165,8 +174,34 @@ class TimedFunction:
             The return from the underlying function.
     """
 
-    # Default to cached call.
-    __call__ = cached_call
+    def __call__(self, *args: Any, **kwargs: Any) -> Any:
+        """
+        Call the underlying function, cached if possible.
+
+        Calls with unhashable parameters, such as :class:`list`, cannot be cached by
+        :func:`functools.lru_cache`, so these fall back to :meth:`uncached_call`. So do
+        calls given any of the keyworded parameters in ``side_effects``, e.g. a ``path``
+        to write to, which has to be written again on every call.
+
+        Parameters
+        ----------
+        *args, **kwargs
+            Parameters to be passed to the underlying function.
+
+        Returns
+        -------
+        Any
+            The return from the underlying function.
+        """
+        if any(kwargs.get(_name) is not None for _name in self._side_effects):
+            return self.uncached_call(*args, **kwargs)
+
+        try:
+            hash((args, tuple(kwargs.items())))
+        except TypeError:
+            return self.uncached_call(*args, **kwargs)
+
+        return self.cached_call(*args, **kwargs)
 
     def timed(self, *args: Any, number: int = 1, **kwargs) -> TimedResult:
         """
@@ -46,7 +46,13 @@
 //! ```
 //!
 //! [Python docs]: ../../../index.html
+use ndarray::Array;
+use num_bigint::BigUint;
+use numpy::{IntoPyArray, PyArray1, PyArray2};
+use pyo3::exceptions::{PyOverflowError, PyValueError};
 use pyo3::prelude::*;
+use std::fs::File;
+use std::io::BufWriter;
 
 mod primes;
 mod py_compatibility;
@@ -58,7 +64,9 @@ pub use primes::constructs::*;
 pub mod prelude {
     pub use crate::primes::constructs::*;
     pub use crate::primes::*;
-    pub use crate::py_compatibility::enums::SieveMethod;
+    pub use crate::py_compatibility::enums::{
+        CunninghamKind, SieveMethod, SpecialPrime, SpiralKind, TruncationSide,
+    };
 }
 
 /// Rust library function.
@@ -148,6 +156,1507 @@ fn nth_prime(py: Python<'_>, n: u64, method: Option<&enums::SieveMethod>) -> PyR
     })
 }
 
+/// Rust library function.
+///
+/// Return Euler's totient of `num`.
+#[pyfunction(num, "*", method)]
+fn totient(py: Python<'_>, num: u64, method: Option<&enums::SieveMethod>) -> PyResult<u64> {
+    py.allow_threads(move || {
+        Ok(primes::totient(
+            *method.unwrap_or(&enums::SieveMethod::default()),
+            num,
+        ))
+    })
+}
+
+/// Rust library function.
+///
+/// Return an array of Euler's totient for every number up to and including `num`.
+/// Calls `prime_mask`, and divides out each prime from its multiples.
+#[pyfunction(num, "*", method)]
+fn totient_sieve<'py>(
+    py: Python<'py>,
+    num: u64,
+    method: Option<&enums::SieveMethod>,
+) -> PyResult<&'py PyArray1<u64>> {
+    let phi = py.allow_threads(move || {
+        primes::totient_sieve(*method.unwrap_or(&enums::SieveMethod::default()), num)
+    });
+
+    Ok(phi.into_pyarray(py))
+}
+
+/// Rust library function.
+///
+/// Return an array of the Möbius function for every number up to and including `num`.
+/// Calls `prime_mask`, then flips the sign of the multiples of each prime, and zeros
+/// the multiples of its square.
+#[pyfunction(num, "*", method)]
+fn mobius_sieve<'py>(
+    py: Python<'py>,
+    num: u64,
+    method: Option<&enums::SieveMethod>,
+) -> PyResult<&'py PyArray1<i8>> {
+    let mu = py.allow_threads(move || {
+        primes::mobius_sieve(*method.unwrap_or(&enums::SieveMethod::default()), num)
+    });
+
+    Ok(mu.into_pyarray(py))
+}
+
+/// Rust library function.
+///
+/// Return the Mertens function of `num`, i.e. the sum of the Möbius function up to and
+/// including `num`.
+#[pyfunction(num, "*", method)]
+fn mertens(py: Python<'_>, num: u64, method: Option<&enums::SieveMethod>) -> PyResult<i64> {
+    py.allow_threads(move || {
+        Ok(primes::mertens(
+            *method.unwrap_or(&enums::SieveMethod::default()),
+            num,
+        ))
+    })
+}
+
+/// Rust library function.
+///
+/// Return an array of the number of distinct prime factors of every number from
+/// `lbound` up to and including `num`.
+#[pyfunction(num, "*", lbound = "0", method)]
+fn omega_sieve<'py>(
+    py: Python<'py>,
+    num: u64,
+    lbound: u64,
+    method: Option<&enums::SieveMethod>,
+) -> PyResult<&'py PyArray1<u8>> {
+    let omegas = py.allow_threads(move || {
+        primes::prime_omegas(
+            *method.unwrap_or(&enums::SieveMethod::default()),
+            lbound,
+            num,
+        )
+    });
+
+    Ok(omegas.omega.into_pyarray(py))
+}
+
+/// Rust library function.
+///
+/// Return an array of the number of prime factors, counted with multiplicity, of every
+/// number from `lbound` up to and including `num`.
+#[pyfunction(num, "*", lbound = "0", method)]
+fn big_omega_sieve<'py>(
+    py: Python<'py>,
+    num: u64,
+    lbound: u64,
+    method: Option<&enums::SieveMethod>,
+) -> PyResult<&'py PyArray1<u8>> {
+    let omegas = py.allow_threads(move || {
+        primes::prime_omegas(
+            *method.unwrap_or(&enums::SieveMethod::default()),
+            lbound,
+            num,
+        )
+    });
+
+    Ok(omegas.big_omega.into_pyarray(py))
+}
+
+/// Rust library function.
+///
+/// Return an array of the Liouville function of every number from `lbound` up to and
+/// including `num`.
+#[pyfunction(num, "*", lbound = "0", method)]
+fn liouville_sieve<'py>(
+    py: Python<'py>,
+    num: u64,
+    lbound: u64,
+    method: Option<&enums::SieveMethod>,
+) -> PyResult<&'py PyArray1<i8>> {
+    let liouville = py.allow_threads(move || {
+        primes::prime_omegas(
+            *method.unwrap_or(&enums::SieveMethod::default()),
+            lbound,
+            num,
+        )
+        .liouville()
+    });
+
+    Ok(liouville.into_pyarray(py))
+}
+
+/// Rust library function.
+///
+/// Return an array of the sum of the `power`-th powers of the divisors of every number
+/// up to and including `num`. Uses the linear sieve of `MultiplicativeFn`.
+#[pyfunction(num, "*", power = "1")]
+fn divisor_sigma_sieve<'py>(py: Python<'py>, num: u64, power: u32) -> PyResult<&'py PyArray1<u64>> {
+    // sigma_k(n) is below 8 * n^k for all n within u64.
+    if num
+        .checked_pow(power)
+        .and_then(|value| value.checked_mul(8))
+        .is_none()
+    {
+        return Err(PyOverflowError::new_err(format!(
+            "divisor sums of power {} up to {} may not fit into 64-bit integers.",
+            power, num
+        )));
+    }
+
+    let sigma = py.allow_threads(move || primes::arithmetic::DivisorSigma(power).linear_sieve(num));
+
+    Ok(sigma.into_pyarray(py))
+}
+
+/// Rust library function.
+///
+/// Return the Dirichlet convolution of the arithmetic functions with the values `lhs`
+/// and `rhs` at each index, such as the arrays returned by the sieves of multiplicative
+/// functions. Calls `dirichlet_convolve`, over steps of each divisor.
+#[pyfunction(lhs, rhs)]
+fn dirichlet_convolution<'py>(
+    py: Python<'py>,
+    lhs: Vec<i64>,
+    rhs: Vec<i64>,
+) -> PyResult<&'py PyArray1<i64>> {
+    // Each value is a sum of `lhs[d] * rhs[n / d]` over distinct `d`, so it is bounded by
+    // the sum of `|lhs|` times the largest `|rhs|`.
+    let lhs_sum = lhs
+        .iter()
+        .try_fold(0_i64, |sum, value| sum.checked_add(value.checked_abs()?));
+    let rhs_max = rhs
+        .iter()
+        .try_fold(0_i64, |max, value| Some(max.max(value.checked_abs()?)));
+    if lhs_sum
+        .zip(rhs_max)
+        .and_then(|(lhs_sum, rhs_max)| lhs_sum.checked_mul(rhs_max))
+        .is_none()
+    {
+        return Err(PyOverflowError::new_err(
+            "the convolution may not fit into 64-bit integers.",
+        ));
+    }
+
+    let convolution =
+        py.allow_threads(move || dirichlet_convolve(&Array::from_vec(lhs), &Array::from_vec(rhs)));
+
+    Ok(convolution.into_pyarray(py))
+}
+
+/// Rust library function.
+///
+/// Return the sum of `function` over all numbers up to and including `num`.
+/// Calls `min_25_sum`, which only sieves primes up to `sqrt(num)`.
+#[pyfunction(function, num, "*", method)]
+fn summatory(
+    py: Python<'_>,
+    function: &enums::ArithmeticFunction,
+    num: u64,
+    method: Option<&enums::SieveMethod>,
+) -> PyResult<i128> {
+    py.allow_threads(move || {
+        function
+            .summatory(*method.unwrap_or(&enums::SieveMethod::default()), num)
+            .ok_or_else(|| {
+                PyOverflowError::new_err(format!(
+                    "the sum of {:?} up to {} does not fit into 128-bit integers.",
+                    function, num
+                ))
+            })
+    })
+}
+
+/// Rust library function.
+///
+/// Return the number of primes within `num`.
+/// Calls `prime_pi`, which only sieves primes up to `sqrt(num)`.
+#[pyfunction(num, "*", method)]
+fn prime_pi(py: Python<'_>, num: u64, method: Option<&enums::SieveMethod>) -> PyResult<u64> {
+    py.allow_threads(move || {
+        Ok(primes::summation::prime_pi(
+            *method.unwrap_or(&enums::SieveMethod::default()),
+            num,
+        ))
+    })
+}
+
+/// Rust library function.
+///
+/// Return the sum of the `power`-th powers of all primes within `num`, optionally
+/// modulo `modulus`. Calls `sum_primes`, which only sieves primes up to `sqrt(num)`.
+#[pyfunction(num, "*", power = "1", modulus = "None", method)]
+fn sum_primes(
+    py: Python<'_>,
+    num: u64,
+    power: u32,
+    modulus: Option<u64>,
+    method: Option<&enums::SieveMethod>,
+) -> PyResult<u128> {
+    if power > 3 {
+        return Err(PyValueError::new_err(format!(
+            "powers of up to 3 are supported, found {}.",
+            power
+        )));
+    }
+    if modulus == Some(0) {
+        return Err(PyValueError::new_err("modulus cannot be 0."));
+    }
+
+    py.allow_threads(move || {
+        primes::summation::sum_primes(
+            *method.unwrap_or(&enums::SieveMethod::default()),
+            num,
+            power,
+            modulus,
+        )
+        .ok_or_else(|| {
+            PyOverflowError::new_err(format!(
+                "the sum of primes to the power of {} up to {} does not fit into 128-bit \
+                 integers; specify a modulus instead.",
+                power, num
+            ))
+        })
+    })
+}
+
+/// Rust library function.
+///
+/// Return an array of the von Mangoldt function for every number up to and including
+/// `num`. Calls `prime_mask`, and sets `ln(p)` at every power of each prime.
+#[pyfunction(num, "*", method)]
+fn von_mangoldt<'py>(
+    py: Python<'py>,
+    num: u64,
+    method: Option<&enums::SieveMethod>,
+) -> PyResult<&'py PyArray1<f64>> {
+    let lambda = py.allow_threads(move || {
+        primes::arithmetic::von_mangoldt_sieve(
+            *method.unwrap_or(&enums::SieveMethod::default()),
+            num,
+        )
+    });
+
+    Ok(lambda.into_pyarray(py))
+}
+
+/// Rust library function.
+///
+/// Return the first Chebyshev function of `num`, the sum of `ln(p)` over all primes
+/// within `num`. Calls `prime_mask`, and sums the logarithms with Kahan summation.
+#[pyfunction(num, "*", method)]
+fn chebyshev_theta(py: Python<'_>, num: u64, method: Option<&enums::SieveMethod>) -> PyResult<f64> {
+    py.allow_threads(move || {
+        Ok(primes::arithmetic::chebyshev_theta(
+            *method.unwrap_or(&enums::SieveMethod::default()),
+            num,
+        ))
+    })
+}
+
+/// Rust library function.
+///
+/// Return the second Chebyshev function of `num`, the sum of the von Mangoldt function
+/// up to and including `num`. Calls `prime_mask`, and sums with Kahan summation.
+#[pyfunction(num, "*", method)]
+fn chebyshev_psi(py: Python<'_>, num: u64, method: Option<&enums::SieveMethod>) -> PyResult<f64> {
+    py.allow_threads(move || {
+        Ok(primes::arithmetic::chebyshev_psi(
+            *method.unwrap_or(&enums::SieveMethod::default()),
+            num,
+        ))
+    })
+}
+
+/// Rust library function.
+///
+/// Return the number of primes within `num` in every residue class modulo `modulus`.
+/// Calls `prime_mask`, and counts each prime into its residue class.
+#[pyfunction(num, modulus, "*", method)]
+fn count_primes_mod<'py>(
+    py: Python<'py>,
+    num: u64,
+    modulus: u64,
+    method: Option<&enums::SieveMethod>,
+) -> PyResult<&'py PyArray1<u64>> {
+    if modulus == 0 {
+        return Err(PyValueError::new_err("modulus cannot be 0."));
+    }
+    // Every residue class beyond `num` is empty, and would only take up memory.
+    if modulus > num.saturating_add(1) {
+        return Err(PyValueError::new_err(format!(
+            "modulus {} cannot exceed num + 1.",
+            modulus
+        )));
+    }
+
+    let counts = py.allow_threads(move || {
+        primes::analysis::count_primes_mod(
+            *method.unwrap_or(&enums::SieveMethod::default()),
+            num,
+            modulus,
+        )
+    });
+
+    Ok(counts.into_pyarray(py))
+}
+
+/// Rust library function.
+///
+/// List all the primes congruent to `residue` modulo `modulus` between `lbound` and
+/// `ubound` inclusive. Only the members of the progression are sieved.
+#[pyfunction(residue, modulus, lbound, ubound, "*", method)]
+fn list_primes_in_progression(
+    py: Python<'_>,
+    residue: u64,
+    modulus: u64,
+    lbound: u64,
+    ubound: u64,
+    method: Option<&enums::SieveMethod>,
+) -> PyResult<Vec<u64>> {
+    if modulus == 0 {
+        return Err(PyValueError::new_err("modulus cannot be 0."));
+    }
+
+    py.allow_threads(move || {
+        Ok(primes::analysis::list_primes_in_progression(
+            *method.unwrap_or(&enums::SieveMethod::default()),
+            residue,
+            modulus,
+            lbound,
+            ubound,
+        ))
+    })
+}
+
+/// Rust library function.
+///
+/// Return the matrix of transitions between the residues modulo `modulus` of
+/// consecutive primes within `num`. Uses a segmented sieve in parallel.
+#[pyfunction(num, modulus, "*", method)]
+fn prime_residue_transitions<'py>(
+    py: Python<'py>,
+    num: u64,
+    modulus: u64,
+    method: Option<&enums::SieveMethod>,
+) -> PyResult<&'py PyArray2<u64>> {
+    if modulus == 0 {
+        return Err(PyValueError::new_err("modulus cannot be 0."));
+    }
+
+    let transitions = py.allow_threads(move || {
+        primes::analysis::residue_transitions(
+            *method.unwrap_or(&enums::SieveMethod::default()),
+            num,
+            modulus,
+        )
+    });
+
+    Ok(transitions.into_pyarray(py))
+}
+
+/// Rust library function.
+///
+/// Return the statistics of the gaps between consecutive primes in
+/// `lbound..=ubound`: the histogram of gap sizes, the maximal gaps, the first
+/// occurrence of each gap size and the gap of highest merit. Uses a segmented
+/// sieve in parallel.
+#[pyfunction(lbound, ubound, "*", method)]
+fn prime_gaps(
+    py: Python<'_>,
+    lbound: u64,
+    ubound: u64,
+    method: Option<&enums::SieveMethod>,
+) -> PyResult<PyObject> {
+    let statistics = py.allow_threads(move || {
+        primes::analysis::prime_gaps(
+            *method.unwrap_or(&enums::SieveMethod::default()),
+            lbound,
+            ubound,
+        )
+    });
+
+    statistics.into_py_dict(py)
+}
+
+/// Check that `pattern` is a non-empty, strictly increasing list of offsets starting
+/// from `0`, as expected by the constellation functions.
+fn validate_pattern(pattern: &[u64]) -> PyResult<()> {
+    match pattern.first() {
+        None => Err(PyValueError::new_err("pattern cannot be empty.")),
+        Some(&first) if first != 0 => Err(PyValueError::new_err(format!(
+            "pattern must start from 0, found {}.",
+            first
+        ))),
+        _ if pattern.windows(2).any(|pair| pair[0] >= pair[1]) => Err(PyValueError::new_err(
+            "pattern must be strictly increasing.",
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// Rust library function.
+///
+/// Find all the occurrences of `pattern` with all their members between `lbound` and
+/// `ubound` inclusive, returning their first members, or only their count if
+/// `count_only`. Uses a wheel sieve over the starting points in parallel.
+#[pyfunction(pattern, lbound, ubound, "*", count_only = "false", method)]
+fn find_constellations(
+    py: Python<'_>,
+    pattern: Vec<u64>,
+    lbound: u64,
+    ubound: u64,
+    count_only: bool,
+    method: Option<&enums::SieveMethod>,
+) -> PyResult<PyObject> {
+    validate_pattern(&pattern)?;
+
+    let sieve = *method.unwrap_or(&enums::SieveMethod::default());
+
+    Ok(match count_only {
+        true => py
+            .allow_threads(move || {
+                primes::analysis::count_constellations(sieve, &pattern, lbound, ubound)
+            })
+            .into_py(py),
+        false => py
+            .allow_threads(move || {
+                primes::analysis::find_constellations(sieve, &pattern, lbound, ubound)
+            })
+            .into_py(py),
+    })
+}
+
+/// Rust library function.
+///
+/// Check if `pattern` is admissible, i.e. if its offsets avoid at least one residue
+/// class modulo every prime.
+#[pyfunction(pattern)]
+fn is_admissible(pattern: Vec<u64>) -> PyResult<bool> {
+    validate_pattern(&pattern)?;
+
+    Ok(primes::analysis::is_admissible(&pattern))
+}
+
+/// Rust library function.
+///
+/// Return the Hardy-Littlewood constant of `pattern`, including the factor for the
+/// prime `2`.
+#[pyfunction(pattern, "*", method)]
+fn hardy_littlewood_constant(
+    py: Python<'_>,
+    pattern: Vec<u64>,
+    method: Option<&enums::SieveMethod>,
+) -> PyResult<f64> {
+    validate_pattern(&pattern)?;
+
+    Ok(py.allow_threads(move || {
+        primes::analysis::hardy_littlewood_constant(
+            *method.unwrap_or(&enums::SieveMethod::default()),
+            &pattern,
+        )
+    }))
+}
+
+/// Rust library function.
+///
+/// Return the number of occurrences of `pattern` between `lbound` and `ubound`
+/// inclusive, as predicted by the Hardy-Littlewood k-tuple conjecture.
+#[pyfunction(pattern, lbound, ubound, "*", method)]
+fn hardy_littlewood_estimate(
+    py: Python<'_>,
+    pattern: Vec<u64>,
+    lbound: u64,
+    ubound: u64,
+    method: Option<&enums::SieveMethod>,
+) -> PyResult<f64> {
+    validate_pattern(&pattern)?;
+
+    Ok(py.allow_threads(move || {
+        primes::analysis::hardy_littlewood_estimate(
+            *method.unwrap_or(&enums::SieveMethod::default()),
+            &pattern,
+            lbound,
+            ubound,
+        )
+    }))
+}
+
+/// Rust library function.
+///
+/// Find the first members between `lbound` and `ubound` inclusive of the Cunningham
+/// chains of `kind` with `length` members, or only their count if `count_only`. Sieves
+/// all the members simultaneously over the range.
+#[pyfunction(length, lbound, ubound, "*", kind, count_only = "false", method)]
+fn cunningham_chains(
+    py: Python<'_>,
+    length: u32,
+    lbound: u64,
+    ubound: u64,
+    kind: Option<&enums::CunninghamKind>,
+    count_only: bool,
+    method: Option<&enums::SieveMethod>,
+) -> PyResult<PyObject> {
+    if length == 0 {
+        return Err(PyValueError::new_err("length cannot be 0."));
+    }
+
+    let sieve = *method.unwrap_or(&enums::SieveMethod::default());
+    let kind = *kind.unwrap_or(&enums::CunninghamKind::FIRST);
+
+    let chains = py
+        .allow_threads(move || {
+            primes::analysis::cunningham_chain_sieve(sieve, kind, length, lbound, ubound)
+        })
+        .ok_or_else(|| {
+            PyOverflowError::new_err(format!(
+                "the members of the chains of length {} up to {} do not fit into 64-bit \
+                 integers.",
+                length, ubound
+            ))
+        })?;
+
+    Ok(match count_only {
+        true => py.allow_threads(|| chains.count()).into_py(py),
+        false => py.allow_threads(|| chains.find()).into_py(py),
+    })
+}
+
+/// Rust library function.
+///
+/// Find the Sophie Germain primes `p` between `lbound` and `ubound` inclusive, i.e.
+/// where `2p + 1` is also prime, or only their count if `count_only`.
+#[pyfunction(lbound, ubound, "*", count_only = "false", method)]
+fn sophie_germain_primes(
+    py: Python<'_>,
+    lbound: u64,
+    ubound: u64,
+    count_only: bool,
+    method: Option<&enums::SieveMethod>,
+) -> PyResult<PyObject> {
+    cunningham_chains(
+        py,
+        2,
+        lbound,
+        ubound,
+        Some(&enums::CunninghamKind::FIRST),
+        count_only,
+        method,
+    )
+}
+
+/// Rust library function.
+///
+/// Find the safe primes `q` between `lbound` and `ubound` inclusive, i.e. where
+/// `(q - 1) / 2` is also prime, or only their count if `count_only`. Only sieves their
+/// Sophie Germain primes.
+#[pyfunction(lbound, ubound, "*", count_only = "false", method)]
+fn safe_primes(
+    py: Python<'_>,
+    lbound: u64,
+    ubound: u64,
+    count_only: bool,
+    method: Option<&enums::SieveMethod>,
+) -> PyResult<PyObject> {
+    let sieve = *method.unwrap_or(&enums::SieveMethod::default());
+
+    // The Sophie Germain primes are at most half of `ubound`, so never overflow.
+    let sophie_germain = py
+        .allow_threads(move || primes::analysis::safe_prime_sieve(sieve, lbound, ubound))
+        .unwrap();
+
+    Ok(match count_only {
+        true => py.allow_threads(|| sophie_germain.count()).into_py(py),
+        false => py
+            .allow_threads(|| {
+                sophie_germain
+                    .find()
+                    .into_iter()
+                    .map(|prime| 2 * prime + 1)
+                    .collect::<Vec<u64>>()
+            })
+            .into_py(py),
+    })
+}
+
+/// Rust library function.
+///
+/// List the ways to write `num` as the sum of two primes `p + q` with `p <= q`, or only
+/// their count if `count_only`.
+#[pyfunction(num, "*", count_only = "false", method)]
+fn goldbach_partitions(
+    py: Python<'_>,
+    num: u64,
+    count_only: bool,
+    method: Option<&enums::SieveMethod>,
+) -> PyResult<PyObject> {
+    let sieve = *method.unwrap_or(&enums::SieveMethod::default());
+
+    Ok(match count_only {
+        true => py
+            .allow_threads(move || primes::analysis::count_goldbach_partitions(sieve, num))
+            .into_py(py),
+        false => py
+            .allow_threads(move || primes::analysis::goldbach_partitions(sieve, num))
+            .into_py(py),
+    })
+}
+
+/// Rust library function.
+///
+/// Return an array of the number of ways to write every number up to and including
+/// `num` as the sum of two primes. Convolves the prime mask with itself by FFT.
+#[pyfunction(num, "*", method)]
+fn goldbach_counts<'py>(
+    py: Python<'py>,
+    num: u64,
+    method: Option<&enums::SieveMethod>,
+) -> PyResult<&'py PyArray1<u64>> {
+    let counts = py.allow_threads(move || {
+        primes::analysis::goldbach_counts(*method.unwrap_or(&enums::SieveMethod::default()), num)
+    });
+
+    Ok(counts.into_pyarray(py))
+}
+
+/// Rust library function.
+///
+/// Return an array of the smallest prime `p` for every even `n` from `lbound` up to and
+/// including `ubound`, for which `n - p` is also prime. Uses a segmented sieve in
+/// parallel.
+#[pyfunction(ubound, "*", lbound = "0", method)]
+fn verify_goldbach<'py>(
+    py: Python<'py>,
+    ubound: u64,
+    lbound: u64,
+    method: Option<&enums::SieveMethod>,
+) -> PyResult<&'py PyArray1<u64>> {
+    let minimal_primes = py.allow_threads(move || {
+        primes::analysis::verify_goldbach(
+            *method.unwrap_or(&enums::SieveMethod::default()),
+            lbound,
+            ubound,
+        )
+    });
+
+    Ok(minimal_primes.into_pyarray(py))
+}
+
+/// Rust library function.
+///
+/// Determines if the Mersenne number `2^exponent - 1` is prime, by the Lucas-Lehmer
+/// test with the special-form reduction modulo `2^exponent - 1`.
+#[pyfunction(exponent, "*", method)]
+fn lucas_lehmer(
+    py: Python<'_>,
+    exponent: u64,
+    method: Option<&enums::SieveMethod>,
+) -> PyResult<bool> {
+    py.allow_threads(move || {
+        Ok(primes::primality::lucas_lehmer(
+            *method.unwrap_or(&enums::SieveMethod::default()),
+            exponent,
+        ))
+    })
+}
+
+/// Rust library function.
+///
+/// List the exponents `p` up to and including `max_exponent`, for which `2^p - 1` is
+/// prime. Tests the prime exponents in parallel.
+#[pyfunction(max_exponent, "*", method)]
+fn mersenne_primes(
+    py: Python<'_>,
+    max_exponent: u64,
+    method: Option<&enums::SieveMethod>,
+) -> PyResult<Vec<u64>> {
+    py.allow_threads(move || {
+        Ok(primes::primality::mersenne_exponents(
+            *method.unwrap_or(&enums::SieveMethod::default()),
+            max_exponent,
+        ))
+    })
+}
+
+/// Rust library function.
+///
+/// List the even perfect numbers `2^(p - 1) * (2^p - 1)` for every Mersenne prime
+/// `2^p - 1` with `p` up to and including `max_exponent`.
+#[pyfunction(max_exponent, "*", method)]
+fn perfect_numbers(
+    py: Python<'_>,
+    max_exponent: u64,
+    method: Option<&enums::SieveMethod>,
+) -> PyResult<Vec<BigUint>> {
+    py.allow_threads(move || {
+        Ok(primes::primality::perfect_numbers(
+            *method.unwrap_or(&enums::SieveMethod::default()),
+            max_exponent,
+        ))
+    })
+}
+
+/// Rust library function.
+///
+/// Determines if the Proth number `k * 2^exponent + 1` is prime, by Proth's theorem with
+/// a quadratic non-residue as the witness.
+#[pyfunction(k, exponent)]
+fn proth(py: Python<'_>, k: u64, exponent: u64) -> PyResult<bool> {
+    py.allow_threads(move || primes::primality::proth(k, exponent))
+        .ok_or_else(|| {
+            PyValueError::new_err(format!(
+                "{} * 2^{} + 1 is not a Proth number, as k must be odd and less than 2^{}.",
+                k, exponent, exponent
+            ))
+        })
+}
+
+/// Rust library function.
+///
+/// Determines if the Fermat number `2^(2^index) + 1` is prime, by Pépin's test with the
+/// special-form reduction modulo `2^(2^index) + 1`.
+#[pyfunction(index)]
+fn pepin(py: Python<'_>, index: u32) -> PyResult<bool> {
+    if index >= 32 {
+        return Err(PyOverflowError::new_err(format!(
+            "the Fermat number of index {} has too many digits to test.",
+            index
+        )));
+    }
+
+    Ok(py.allow_threads(move || primes::primality::pepin(index)))
+}
+
+/// Rust library function.
+///
+/// Sieve the candidates `k * base^n + offset` for every `k` in `multipliers` and every
+/// `n` between `min_exponent` and `max_exponent` inclusive, by the primes up to
+/// `max_prime`. Returns the remaining `(k, n)`, or only their count if `count_only`,
+/// and writes them to `path` in the ABC format if given.
+#[pyfunction(
+    multipliers,
+    base,
+    min_exponent,
+    max_exponent,
+    offset,
+    "*",
+    max_prime = "1_000_000",
+    path = "None",
+    count_only = "false",
+    method
+)]
+#[allow(clippy::too_many_arguments)]
+fn kbn_candidates(
+    py: Python<'_>,
+    multipliers: Vec<u64>,
+    base: u64,
+    min_exponent: u64,
+    max_exponent: u64,
+    offset: i64,
+    max_prime: u64,
+    path: Option<String>,
+    count_only: bool,
+    method: Option<&enums::SieveMethod>,
+) -> PyResult<PyObject> {
+    if base < 2 {
+        return Err(PyValueError::new_err("base must be at least 2."));
+    }
+    if min_exponent == 0 {
+        return Err(PyValueError::new_err("min_exponent must be at least 1."));
+    }
+    if multipliers.contains(&0) {
+        return Err(PyValueError::new_err("multipliers cannot contain 0."));
+    }
+
+    let sieve = *method.unwrap_or(&enums::SieveMethod::default());
+
+    let grid = py.allow_threads(|| {
+        primes::primality::sieve_candidates(
+            sieve,
+            &multipliers,
+            base,
+            min_exponent,
+            max_exponent,
+            offset,
+            max_prime,
+        )
+    });
+
+    if let Some(path) = path {
+        py.allow_threads(|| grid.write_abc(BufWriter::new(File::create(path)?)))?;
+    }
+
+    Ok(match count_only {
+        true => grid.count().into_py(py),
+        false => grid.candidates().into_py(py),
+    })
+}
+
+/// Raise a ValueError if `base` cannot be used for digits.
+fn validate_base(base: u64) -> PyResult<()> {
+    match base < 2 {
+        true => Err(PyValueError::new_err("base must be at least 2.")),
+        false => Ok(()),
+    }
+}
+
+/// Rust library function.
+///
+/// List the primes between `lbound` and `ubound` inclusive, whose digits in `base` read
+/// the same backwards. Generates the palindromes from their first half.
+#[pyfunction(lbound, ubound, "*", base = "10")]
+fn palindromic_primes(py: Python<'_>, lbound: u64, ubound: u64, base: u64) -> PyResult<Vec<u64>> {
+    validate_base(base)?;
+
+    Ok(py.allow_threads(move || primes::analysis::palindromic_primes(lbound, ubound, base)))
+}
+
+/// Rust library function.
+///
+/// List the primes between `lbound` and `ubound` inclusive, whose digits in `base`
+/// reversed are a different prime.
+#[pyfunction(lbound, ubound, "*", base = "10", method)]
+fn emirps(
+    py: Python<'_>,
+    lbound: u64,
+    ubound: u64,
+    base: u64,
+    method: Option<&enums::SieveMethod>,
+) -> PyResult<Vec<u64>> {
+    validate_base(base)?;
+
+    Ok(py.allow_threads(move || {
+        primes::analysis::emirps(
+            *method.unwrap_or(&enums::SieveMethod::default()),
+            lbound,
+            ubound,
+            base,
+        )
+    }))
+}
+
+/// Rust library function.
+///
+/// List the primes between `lbound` and `ubound` inclusive, for which every rotation of
+/// their digits in `base` is also prime.
+#[pyfunction(lbound, ubound, "*", base = "10", method)]
+fn circular_primes(
+    py: Python<'_>,
+    lbound: u64,
+    ubound: u64,
+    base: u64,
+    method: Option<&enums::SieveMethod>,
+) -> PyResult<Vec<u64>> {
+    validate_base(base)?;
+
+    Ok(py.allow_threads(move || {
+        primes::analysis::circular_primes(
+            *method.unwrap_or(&enums::SieveMethod::default()),
+            lbound,
+            ubound,
+            base,
+        )
+    }))
+}
+
+/// Rust library function.
+///
+/// List the primes between `lbound` and `ubound` inclusive, for which every permutation
+/// of their digits in `base` is also prime.
+#[pyfunction(lbound, ubound, "*", base = "10", method)]
+fn permutable_primes(
+    py: Python<'_>,
+    lbound: u64,
+    ubound: u64,
+    base: u64,
+    method: Option<&enums::SieveMethod>,
+) -> PyResult<Vec<u64>> {
+    validate_base(base)?;
+
+    Ok(py.allow_threads(move || {
+        primes::analysis::permutable_primes(
+            *method.unwrap_or(&enums::SieveMethod::default()),
+            lbound,
+            ubound,
+            base,
+        )
+    }))
+}
+
+/// Rust library function.
+///
+/// List the primes between `lbound` and `ubound` inclusive, which remain prime as their
+/// digits in `base` are removed one by one from `side`.
+#[pyfunction(lbound, ubound, "*", side, base = "10")]
+fn truncatable_primes(
+    py: Python<'_>,
+    lbound: u64,
+    ubound: u64,
+    side: Option<&enums::TruncationSide>,
+    base: u64,
+) -> PyResult<Vec<u64>> {
+    validate_base(base)?;
+
+    let side = *side.unwrap_or(&enums::TruncationSide::LEFT);
+
+    Ok(py.allow_threads(move || primes::analysis::truncatable_primes(lbound, ubound, base, side)))
+}
+
+/// Rust library function.
+///
+/// List the exponents up to and including `max_exponent`, for which the repunit
+/// `(base^n - 1) / (base - 1)` is a probable prime.
+#[pyfunction(max_exponent, "*", base = "10", method)]
+fn repunit_primes(
+    py: Python<'_>,
+    max_exponent: u64,
+    base: u64,
+    method: Option<&enums::SieveMethod>,
+) -> PyResult<Vec<u64>> {
+    validate_base(base)?;
+
+    Ok(py.allow_threads(move || {
+        primes::analysis::repunit_primes(
+            *method.unwrap_or(&enums::SieveMethod::default()),
+            base,
+            max_exponent,
+        )
+    }))
+}
+
+/// Reject an upper bound whose primes do not fit the arithmetic modulo their squares.
+fn validate_square_bound(ubound: u64) -> PyResult<()> {
+    match ubound >= 1 << 63 {
+        true => Err(PyOverflowError::new_err(format!(
+            "ubound {} must be less than 2^63 for the arithmetic modulo p^2.",
+            ubound
+        ))),
+        false => Ok(()),
+    }
+}
+
+/// Rust library function.
+///
+/// List the primes between `lbound` and `ubound` inclusive of the `kind`, i.e. whose
+/// quotient modulo `p^2` is `0`.
+#[pyfunction(kind, lbound, ubound, "*", method)]
+fn special_primes(
+    py: Python<'_>,
+    kind: &enums::SpecialPrime,
+    lbound: u64,
+    ubound: u64,
+    method: Option<&enums::SieveMethod>,
+) -> PyResult<Vec<u64>> {
+    validate_square_bound(ubound)?;
+    let kind = *kind;
+
+    Ok(py.allow_threads(move || {
+        primes::analysis::special_prime_quotients(
+            *method.unwrap_or(&enums::SieveMethod::default()),
+            kind,
+            lbound,
+            ubound,
+            0,
+        )
+        .into_iter()
+        .map(|(prime, _)| prime)
+        .collect()
+    }))
+}
+
+/// Rust library function.
+///
+/// List the primes between `lbound` and `ubound` inclusive, whose signed quotient of the
+/// `kind` is at most `max_quotient` in absolute value, with those quotients.
+#[pyfunction(kind, lbound, ubound, max_quotient, "*", method)]
+fn special_prime_near_misses(
+    py: Python<'_>,
+    kind: &enums::SpecialPrime,
+    lbound: u64,
+    ubound: u64,
+    max_quotient: u64,
+    method: Option<&enums::SieveMethod>,
+) -> PyResult<Vec<(u64, i64)>> {
+    validate_square_bound(ubound)?;
+    let kind = *kind;
+
+    Ok(py.allow_threads(move || {
+        primes::analysis::special_prime_quotients(
+            *method.unwrap_or(&enums::SieveMethod::default()),
+            kind,
+            lbound,
+            ubound,
+            max_quotient,
+        )
+    }))
+}
+
+/// Rust library function.
+///
+/// List the first `n` Ramanujan primes, where the `k`th is the smallest `R(k)` such that
+/// `π(x) - π(x/2) >= k` for all `x >= R(k)`.
+#[pyfunction(n, "*", method)]
+fn ramanujan_primes(
+    py: Python<'_>,
+    n: u64,
+    method: Option<&enums::SieveMethod>,
+) -> PyResult<Vec<u64>> {
+    py.allow_threads(move || {
+        Ok(primes::analysis::ramanujan_primes(
+            *method.unwrap_or(&enums::SieveMethod::default()),
+            n,
+        ))
+    })
+}
+
+/// Rust library function.
+///
+/// List the first `n` Labos primes, where the `k`th is the smallest `x` with
+/// `π(x) - π(x/2) = k`.
+#[pyfunction(n, "*", method)]
+fn labos_primes(py: Python<'_>, n: u64, method: Option<&enums::SieveMethod>) -> PyResult<Vec<u64>> {
+    py.allow_threads(move || {
+        Ok(primes::analysis::labos_primes(
+            *method.unwrap_or(&enums::SieveMethod::default()),
+            n,
+        ))
+    })
+}
+
+/// Rust library function.
+///
+/// The primorial `n#`, the product of every prime up to and including `n`, by a product
+/// tree.
+#[pyfunction(n, "*", method)]
+fn primorial(py: Python<'_>, n: u64, method: Option<&enums::SieveMethod>) -> PyResult<BigUint> {
+    py.allow_threads(move || {
+        Ok(primes::formulas::primorial(
+            *method.unwrap_or(&enums::SieveMethod::default()),
+            n,
+        ))
+    })
+}
+
+/// Rust library function.
+///
+/// The product of the first `n` primes, by a product tree.
+#[pyfunction(n, "*", method)]
+fn primorial_of_nth(
+    py: Python<'_>,
+    n: u64,
+    method: Option<&enums::SieveMethod>,
+) -> PyResult<BigUint> {
+    py.allow_threads(move || {
+        Ok(primes::formulas::primorial_of_nth(
+            *method.unwrap_or(&enums::SieveMethod::default()),
+            n,
+        ))
+    })
+}
+
+/// Rust library function.
+///
+/// Determines if `num` is a probable prime by the Baillie-PSW test, which is exact for
+/// every `num` below `2^64`.
+#[pyfunction(num)]
+fn bpsw(py: Python<'_>, num: BigUint) -> PyResult<bool> {
+    py.allow_threads(move || Ok(primes::primality::bpsw(&num)))
+}
+
+/// Rust library function.
+///
+/// List the primes `p` up to and including `ubound`, for which `p# + offset` is a
+/// probable prime by the Baillie-PSW test.
+#[pyfunction(ubound, "*", offset = "1", method)]
+fn primorial_primes(
+    py: Python<'_>,
+    ubound: u64,
+    offset: i64,
+    method: Option<&enums::SieveMethod>,
+) -> PyResult<Vec<u64>> {
+    py.allow_threads(move || {
+        Ok(primes::primality::primorial_primes(
+            *method.unwrap_or(&enums::SieveMethod::default()),
+            ubound,
+            offset,
+        ))
+    })
+}
+
+/// Rust library function.
+///
+/// List the `n` up to and including `ubound`, for which `n! + offset` is a probable prime
+/// by the Baillie-PSW test.
+#[pyfunction(ubound, "*", offset = "1")]
+fn factorial_primes(py: Python<'_>, ubound: u64, offset: i64) -> PyResult<Vec<u64>> {
+    py.allow_threads(move || Ok(primes::primality::factorial_primes(ubound, offset)))
+}
+
+/// Rust library function.
+///
+/// List the numbers from `lbound` up to and including `num` with exactly `k` prime
+/// factors, counted with multiplicity.
+#[pyfunction(k, num, "*", lbound = "0", method)]
+fn list_almost_primes(
+    py: Python<'_>,
+    k: u32,
+    num: u64,
+    lbound: u64,
+    method: Option<&enums::SieveMethod>,
+) -> PyResult<Vec<u64>> {
+    py.allow_threads(move || {
+        Ok(primes::arithmetic::list_almost_primes(
+            *method.unwrap_or(&enums::SieveMethod::default()),
+            k,
+            lbound,
+            num,
+        ))
+    })
+}
+
+/// Rust library function.
+///
+/// Count the numbers up to and including `num` with exactly `k` prime factors, counted
+/// with multiplicity, from the prime counts of a `LucyTable`.
+#[pyfunction(k, num, "*", method)]
+fn count_almost_primes(
+    py: Python<'_>,
+    k: u32,
+    num: u64,
+    method: Option<&enums::SieveMethod>,
+) -> PyResult<u64> {
+    py.allow_threads(move || {
+        Ok(primes::arithmetic::count_almost_primes(
+            *method.unwrap_or(&enums::SieveMethod::default()),
+            k,
+            num,
+        ))
+    })
+}
+
+/// Rust library function.
+///
+/// List the semiprimes between `lbound` and `ubound` inclusive.
+#[pyfunction(lbound, ubound, "*", method)]
+fn semiprimes_in(
+    py: Python<'_>,
+    lbound: u64,
+    ubound: u64,
+    method: Option<&enums::SieveMethod>,
+) -> PyResult<Vec<u64>> {
+    py.allow_threads(move || {
+        Ok(primes::arithmetic::list_almost_primes(
+            *method.unwrap_or(&enums::SieveMethod::default()),
+            2,
+            lbound,
+            ubound,
+        ))
+    })
+}
+
+/// Rust library function.
+///
+/// List the numbers from `lbound` up to and including `num` without any prime factor
+/// above `bound`, by sieving with logarithm accumulation.
+#[pyfunction(bound, num, "*", lbound = "0", method)]
+fn smooth_numbers(
+    py: Python<'_>,
+    bound: u64,
+    num: u64,
+    lbound: u64,
+    method: Option<&enums::SieveMethod>,
+) -> PyResult<Vec<u64>> {
+    py.allow_threads(move || {
+        Ok(primes::arithmetic::smooth_numbers(
+            *method.unwrap_or(&enums::SieveMethod::default()),
+            bound,
+            lbound,
+            num,
+        ))
+    })
+}
+
+/// Rust library function.
+///
+/// List the numbers between `lbound` and `ubound` inclusive without any prime factor up
+/// to `bound`.
+#[pyfunction(bound, lbound, ubound, "*", method)]
+fn rough_numbers(
+    py: Python<'_>,
+    bound: u64,
+    lbound: u64,
+    ubound: u64,
+    method: Option<&enums::SieveMethod>,
+) -> PyResult<Vec<u64>> {
+    py.allow_threads(move || {
+        Ok(primes::arithmetic::rough_numbers(
+            *method.unwrap_or(&enums::SieveMethod::default()),
+            bound,
+            lbound,
+            ubound,
+        ))
+    })
+}
+
+/// Rust library function.
+///
+/// Count the numbers up to and including `num` without any prime factor above `bound`,
+/// i.e. `Ψ(num, bound)`, by the Buchstab identity.
+#[pyfunction(bound, num, "*", method)]
+fn count_smooth_numbers(
+    py: Python<'_>,
+    bound: u64,
+    num: u64,
+    method: Option<&enums::SieveMethod>,
+) -> PyResult<u64> {
+    py.allow_threads(move || {
+        Ok(primes::arithmetic::count_smooth_numbers(
+            *method.unwrap_or(&enums::SieveMethod::default()),
+            bound,
+            num,
+        ))
+    })
+}
+
+/// Rust library function.
+///
+/// Estimate `Ψ(num, bound)` by the Dickman function, with de Bruijn's second term.
+#[pyfunction(bound, num)]
+fn estimate_smooth_numbers(bound: u64, num: u64) -> PyResult<f64> {
+    Ok(primes::arithmetic::estimate_smooth_numbers(bound, num))
+}
+
+/// Rust library function.
+///
+/// The Dickman function `ρ(u)`.
+#[pyfunction(u)]
+fn dickman_rho(u: f64) -> PyResult<f64> {
+    Ok(primes::arithmetic::dickman_rho(u))
+}
+
+/// Rust library function.
+///
+/// Return the 2-D mask of the Gaussian primes `a + bi` with norm up to and including
+/// `norm`, where `[r + a, r + b]` is `a + bi` for `r = isqrt(norm)`.
+#[pyfunction(norm, "*", method)]
+fn gaussian_primes<'py>(
+    py: Python<'py>,
+    norm: u64,
+    method: Option<&enums::SieveMethod>,
+) -> PyResult<&'py PyArray2<bool>> {
+    let mask = py.allow_threads(move || {
+        primes::analysis::gaussian_primes(*method.unwrap_or(&enums::SieveMethod::default()), norm)
+    });
+
+    Ok(mask.into_pyarray(py))
+}
+
+/// Rust library function.
+///
+/// Decompose `prime` into `a^2 + b^2` with `a >= b`, for `2` or a prime `1 mod 4`.
+#[pyfunction(prime)]
+fn two_squares(prime: u64) -> PyResult<(u64, u64)> {
+    match primes::primality::miller_rabin(prime) {
+        true => primes::analysis::two_squares(prime),
+        false => None,
+    }
+    .ok_or_else(|| {
+        PyValueError::new_err(format!(
+            "{} is not 2 or a prime congruent to 1 mod 4.",
+            prime
+        ))
+    })
+}
+
+/// Rust library function.
+///
+/// Lay the numbers from `start` onwards out on a spiral of the `kind` in a square image of
+/// `side` pixels, with the primes lit up as grey levels, and write it to `path` as a PGM or
+/// PNG if given.
+#[pyfunction(
+    side,
+    "*",
+    kind,
+    start = "1",
+    colour_gaps = "false",
+    polynomials = "Vec::new()",
+    path = "None",
+    method
+)]
+#[allow(clippy::too_many_arguments)]
+fn prime_spiral<'py>(
+    py: Python<'py>,
+    side: usize,
+    kind: Option<&enums::SpiralKind>,
+    start: u64,
+    colour_gaps: bool,
+    polynomials: Vec<(i64, i64, i64)>,
+    path: Option<String>,
+    method: Option<&enums::SieveMethod>,
+) -> PyResult<&'py PyArray2<u8>> {
+    if let Some(path) = &path {
+        if !(path.ends_with(".pgm") || path.ends_with(".png")) {
+            return Err(PyValueError::new_err(format!(
+                "path {} must end with either .pgm or .png.",
+                path
+            )));
+        }
+    }
+    let kind = *kind.unwrap_or(&enums::SpiralKind::default());
+
+    let image = py.allow_threads(|| {
+        primes::analysis::prime_spiral(
+            *method.unwrap_or(&enums::SieveMethod::default()),
+            kind,
+            side,
+            start,
+            colour_gaps,
+            &polynomials,
+        )
+    });
+
+    if let Some(path) = path {
+        py.allow_threads(|| {
+            let writer = BufWriter::new(File::create(&path)?);
+
+            match path.ends_with(".png") {
+                true => primes::analysis::write_png(&image, writer),
+                false => primes::analysis::write_pgm(&image, writer),
+            }
+        })?;
+    }
+
+    Ok(image.into_pyarray(py))
+}
+
+/// Build the polynomial from the `coefficients` from the highest power down, rejecting
+/// the zero polynomial.
+fn validate_polynomial(coefficients: &[i64]) -> PyResult<primes::formulas::Polynomial> {
+    primes::formulas::Polynomial::new(coefficients)
+        .ok_or_else(|| PyValueError::new_err("coefficients cannot all be 0."))
+}
+
+/// Build the polynomial from the `coefficients` from the highest power down, rejecting
+/// those the Bateman-Horn conjecture does not apply to.
+fn validate_bateman_horn(coefficients: &[i64]) -> PyResult<primes::formulas::Polynomial> {
+    let polynomial = validate_polynomial(coefficients)?;
+
+    match polynomial.degree() == 0 || polynomial.leading_coefficient() < 0 {
+        true => Err(PyValueError::new_err(
+            "the polynomial must be non-constant with a positive leading coefficient.",
+        )),
+        false => Ok(polynomial),
+    }
+}
+
+/// Rust library function.
+///
+/// Find every `n` between `lbound` and `ubound` inclusive for which the polynomial with
+/// `coefficients` from the highest power down is prime at `n`, or only their count if
+/// `count_only`. Sieves the values along the roots of the polynomial modulo each prime.
+#[pyfunction(coefficients, lbound, ubound, "*", count_only = "false", method)]
+fn polynomial_primes(
+    py: Python<'_>,
+    coefficients: Vec<i64>,
+    lbound: u64,
+    ubound: u64,
+    count_only: bool,
+    method: Option<&enums::SieveMethod>,
+) -> PyResult<PyObject> {
+    let polynomial = validate_polynomial(&coefficients)?;
+    let sieve = *method.unwrap_or(&enums::SieveMethod::default());
+    let overflow = || {
+        PyOverflowError::new_err(format!(
+            "the values of the polynomial up to ubound {} must fit into 64 bits.",
+            ubound
+        ))
+    };
+
+    Ok(match count_only {
+        true => py
+            .allow_threads(|| {
+                primes::analysis::count_polynomial_primes(sieve, &polynomial, lbound, ubound)
+            })
+            .ok_or_else(overflow)?
+            .into_py(py),
+        false => py
+            .allow_threads(|| {
+                primes::analysis::polynomial_primes(sieve, &polynomial, lbound, ubound)
+            })
+            .ok_or_else(overflow)?
+            .into_py(py),
+    })
+}
+
+/// Rust library function.
+///
+/// Return the Bateman-Horn constant of the polynomial with `coefficients` from the
+/// highest power down.
+#[pyfunction(coefficients, "*", method)]
+fn bateman_horn_constant(
+    py: Python<'_>,
+    coefficients: Vec<i64>,
+    method: Option<&enums::SieveMethod>,
+) -> PyResult<f64> {
+    let polynomial = validate_bateman_horn(&coefficients)?;
+
+    Ok(py.allow_threads(move || {
+        primes::analysis::bateman_horn_constant(
+            *method.unwrap_or(&enums::SieveMethod::default()),
+            &polynomial,
+        )
+    }))
+}
+
+/// Rust library function.
+///
+/// Return the number of `n` between `lbound` and `ubound` inclusive for which the
+/// polynomial with `coefficients` from the highest power down is prime at `n`, as
+/// predicted by the Bateman-Horn conjecture.
+#[pyfunction(coefficients, lbound, ubound, "*", method)]
+fn bateman_horn_estimate(
+    py: Python<'_>,
+    coefficients: Vec<i64>,
+    lbound: u64,
+    ubound: u64,
+    method: Option<&enums::SieveMethod>,
+) -> PyResult<f64> {
+    let polynomial = validate_bateman_horn(&coefficients)?;
+
+    Ok(py.allow_threads(move || {
+        primes::analysis::bateman_horn_estimate(
+            *method.unwrap_or(&enums::SieveMethod::default()),
+            &polynomial,
+            lbound,
+            ubound,
+        )
+    }))
+}
+
+/// Rust library function.
+///
+/// Find the roots of the polynomial with `coefficients` from the highest power down
+/// modulo every prime up to and including `bound`, in compressed sparse row form, with
+/// the Murphy alpha over these primes.
+#[pyfunction(coefficients, bound, "*", method)]
+fn polynomial_roots(
+    py: Python<'_>,
+    coefficients: Vec<i64>,
+    bound: u64,
+    method: Option<&enums::SieveMethod>,
+) -> PyResult<PyObject> {
+    let polynomial = validate_polynomial(&coefficients)?;
+
+    let roots = py.allow_threads(move || {
+        primes::analysis::polynomial_roots(
+            *method.unwrap_or(&enums::SieveMethod::default()),
+            &polynomial,
+            bound,
+        )
+    });
+
+    roots.into_py_dict(py)
+}
+
 /// A Python module implemented in Rust.
 #[pymodule]
 fn lib_rust_primes(_py: Python, m: &PyModule) -> PyResult<()> {
@@ -157,8 +1666,78 @@ fn lib_rust_primes(_py: Python, m: &PyModule) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(upper_bound_of_nth_prime, m)?)?;
     m.add_function(wrap_pyfunction!(list_n_primes, m)?)?;
     m.add_function(wrap_pyfunction!(nth_prime, m)?)?;
+    m.add_function(wrap_pyfunction!(totient, m)?)?;
+    m.add_function(wrap_pyfunction!(totient_sieve, m)?)?;
+    m.add_function(wrap_pyfunction!(mobius_sieve, m)?)?;
+    m.add_function(wrap_pyfunction!(mertens, m)?)?;
+    m.add_function(wrap_pyfunction!(omega_sieve, m)?)?;
+    m.add_function(wrap_pyfunction!(big_omega_sieve, m)?)?;
+    m.add_function(wrap_pyfunction!(liouville_sieve, m)?)?;
+    m.add_function(wrap_pyfunction!(divisor_sigma_sieve, m)?)?;
+    m.add_function(wrap_pyfunction!(dirichlet_convolution, m)?)?;
+    m.add_function(wrap_pyfunction!(summatory, m)?)?;
+    m.add_function(wrap_pyfunction!(prime_pi, m)?)?;
+    m.add_function(wrap_pyfunction!(sum_primes, m)?)?;
+    m.add_function(wrap_pyfunction!(von_mangoldt, m)?)?;
+    m.add_function(wrap_pyfunction!(chebyshev_theta, m)?)?;
+    m.add_function(wrap_pyfunction!(chebyshev_psi, m)?)?;
+    m.add_function(wrap_pyfunction!(count_primes_mod, m)?)?;
+    m.add_function(wrap_pyfunction!(list_primes_in_progression, m)?)?;
+    m.add_function(wrap_pyfunction!(prime_residue_transitions, m)?)?;
+    m.add_function(wrap_pyfunction!(prime_gaps, m)?)?;
+    m.add_function(wrap_pyfunction!(find_constellations, m)?)?;
+    m.add_function(wrap_pyfunction!(is_admissible, m)?)?;
+    m.add_function(wrap_pyfunction!(hardy_littlewood_constant, m)?)?;
+    m.add_function(wrap_pyfunction!(hardy_littlewood_estimate, m)?)?;
+    m.add_function(wrap_pyfunction!(cunningham_chains, m)?)?;
+    m.add_function(wrap_pyfunction!(sophie_germain_primes, m)?)?;
+    m.add_function(wrap_pyfunction!(safe_primes, m)?)?;
+    m.add_function(wrap_pyfunction!(goldbach_partitions, m)?)?;
+    m.add_function(wrap_pyfunction!(goldbach_counts, m)?)?;
+    m.add_function(wrap_pyfunction!(verify_goldbach, m)?)?;
+    m.add_function(wrap_pyfunction!(lucas_lehmer, m)?)?;
+    m.add_function(wrap_pyfunction!(mersenne_primes, m)?)?;
+    m.add_function(wrap_pyfunction!(perfect_numbers, m)?)?;
+    m.add_function(wrap_pyfunction!(proth, m)?)?;
+    m.add_function(wrap_pyfunction!(pepin, m)?)?;
+    m.add_function(wrap_pyfunction!(kbn_candidates, m)?)?;
+    m.add_function(wrap_pyfunction!(palindromic_primes, m)?)?;
+    m.add_function(wrap_pyfunction!(emirps, m)?)?;
+    m.add_function(wrap_pyfunction!(circular_primes, m)?)?;
+    m.add_function(wrap_pyfunction!(permutable_primes, m)?)?;
+    m.add_function(wrap_pyfunction!(truncatable_primes, m)?)?;
+    m.add_function(wrap_pyfunction!(repunit_primes, m)?)?;
+    m.add_function(wrap_pyfunction!(special_primes, m)?)?;
+    m.add_function(wrap_pyfunction!(special_prime_near_misses, m)?)?;
+    m.add_function(wrap_pyfunction!(ramanujan_primes, m)?)?;
+    m.add_function(wrap_pyfunction!(labos_primes, m)?)?;
+    m.add_function(wrap_pyfunction!(primorial, m)?)?;
+    m.add_function(wrap_pyfunction!(primorial_of_nth, m)?)?;
+    m.add_function(wrap_pyfunction!(bpsw, m)?)?;
+    m.add_function(wrap_pyfunction!(primorial_primes, m)?)?;
+    m.add_function(wrap_pyfunction!(factorial_primes, m)?)?;
+    m.add_function(wrap_pyfunction!(list_almost_primes, m)?)?;
+    m.add_function(wrap_pyfunction!(count_almost_primes, m)?)?;
+    m.add_function(wrap_pyfunction!(semiprimes_in, m)?)?;
+    m.add_function(wrap_pyfunction!(smooth_numbers, m)?)?;
+    m.add_function(wrap_pyfunction!(rough_numbers, m)?)?;
+    m.add_function(wrap_pyfunction!(count_smooth_numbers, m)?)?;
+    m.add_function(wrap_pyfunction!(estimate_smooth_numbers, m)?)?;
+    m.add_function(wrap_pyfunction!(dickman_rho, m)?)?;
+    m.add_function(wrap_pyfunction!(gaussian_primes, m)?)?;
+    m.add_function(wrap_pyfunction!(two_squares, m)?)?;
+    m.add_function(wrap_pyfunction!(prime_spiral, m)?)?;
+    m.add_function(wrap_pyfunction!(polynomial_primes, m)?)?;
+    m.add_function(wrap_pyfunction!(bateman_horn_constant, m)?)?;
+    m.add_function(wrap_pyfunction!(bateman_horn_estimate, m)?)?;
+    m.add_function(wrap_pyfunction!(polynomial_roots, m)?)?;
 
     m.add_class::<py_compatibility::enums::SieveMethod>()?;
+    m.add_class::<py_compatibility::enums::ArithmeticFunction>()?;
+    m.add_class::<py_compatibility::enums::CunninghamKind>()?;
+    m.add_class::<py_compatibility::enums::TruncationSide>()?;
+    m.add_class::<py_compatibility::enums::SpecialPrime>()?;
+    m.add_class::<py_compatibility::enums::SpiralKind>()?;
 
     Ok(())
 }
@@ -0,0 +1,51 @@
+use crate::primes::{LinearForm, LinearFormSieve};
+use crate::py_compatibility::enums;
+
+/// The [`LinearFormSieve`] over the first members `p` in `lbound..=ubound` of the
+/// Cunningham chains of `kind` with `length` members.
+///
+/// Every `p` starting `length` consecutive members is included, whether or not it is
+/// the first member of its chain, or its chain continues beyond `length`.
+///
+/// Returns [`None`] if the last member overflows [`u64`].
+pub fn cunningham_chain_sieve(
+    sieve: enums::SieveMethod,
+    kind: enums::CunninghamKind,
+    length: u32,
+    lbound: u64,
+    ubound: u64,
+) -> Option<LinearFormSieve> {
+    let forms = (0..length)
+        .map(|index| kind.member(index))
+        .collect::<Option<Vec<LinearForm>>>()?;
+
+    LinearFormSieve::new(sieve, forms, lbound, ubound)
+}
+
+/// The [`LinearFormSieve`] over the Sophie Germain primes `p` in `lbound..=ubound`, i.e.
+/// where `2p + 1` is also prime.
+///
+/// Returns [`None`] if `2p + 1` overflows [`u64`].
+pub fn sophie_germain_sieve(
+    sieve: enums::SieveMethod,
+    lbound: u64,
+    ubound: u64,
+) -> Option<LinearFormSieve> {
+    cunningham_chain_sieve(sieve, enums::CunninghamKind::FIRST, 2, lbound, ubound)
+}
+
+/// The [`LinearFormSieve`] over the Sophie Germain primes `p` of the safe primes
+/// `2p + 1` in `lbound..=ubound`, so that only half of the range is sieved.
+///
+/// Each value `p` found corresponds to the safe prime `2p + 1`.
+pub fn safe_prime_sieve(
+    sieve: enums::SieveMethod,
+    lbound: u64,
+    ubound: u64,
+) -> Option<LinearFormSieve> {
+    sophie_germain_sieve(
+        sieve,
+        lbound.saturating_sub(1).div_ceil(2),
+        ubound.saturating_sub(1) / 2,
+    )
+}
@@ -0,0 +1,149 @@
+use std::cmp;
+
+use crate::primes::constructs::*;
+use crate::primes::formulas::simpson;
+use crate::primes::{LinearForm, LinearFormSieve};
+use crate::py_compatibility::enums;
+
+/// The primes up to which the product of [`hardy_littlewood_constant`] is evaluated
+/// explicitly, before the tail is approximated.
+const SINGULAR_SERIES_LIMIT: u64 = 2_u64.pow(22);
+
+/// The number of distinct residues of `pattern` modulo `prime`.
+fn residue_count(pattern: &[u64], prime: u64) -> u64 {
+    let mut residues = vec![false; prime as usize];
+    pattern
+        .iter()
+        .for_each(|offset| residues[(offset % prime) as usize] = true);
+
+    residues.into_iter().filter(|&covered| covered).count() as u64
+}
+
+/// Whether the offsets of `pattern` can all be prime infinitely often, i.e. whether
+/// they avoid at least one residue class modulo every prime.
+///
+/// Only the primes up to the number of offsets can be covered completely, so only these
+/// need to be checked. An inadmissible pattern can still have a few small occurrences
+/// where one of the members is the prime that excludes it, e.g. `(3, 5, 7)` for
+/// `[0, 2, 4]`.
+pub fn is_admissible(pattern: &[u64]) -> bool {
+    let size = pattern.len() as u64;
+
+    (2..=size)
+        .filter(|&candidate| (2..candidate).all(|divisor| !candidate.is_multiple_of(divisor)))
+        .all(|prime| residue_count(pattern, prime) < prime)
+}
+
+/// The Hardy–Littlewood constant of `pattern`, i.e. the singular series
+///
+/// ```text
+/// S = product over all primes p of (1 - w(p) / p) / (1 - 1 / p)^k
+/// ```
+///
+/// where `k` is the number of offsets and `w(p)` is the number of distinct residues of
+/// the offsets modulo `p`. This includes the factor for `p = 2`, so for twin primes it
+/// is `2 * C2 = 1.3203236...`. It is `0` if and only if the pattern is not
+/// [admissible](is_admissible).
+///
+/// The product is evaluated explicitly over the primes up to [`SINGULAR_SERIES_LIMIT`];
+/// beyond that, each factor is `1 - k(k - 1) / (2p^2) + O(p^-3)`, so the tail is
+/// approximated by `exp(-k(k - 1) / (2 P ln P))` from the prime number theorem.
+pub fn hardy_littlewood_constant(sieve: enums::SieveMethod, pattern: &[u64]) -> f64 {
+    if !is_admissible(pattern) {
+        return 0.0;
+    }
+
+    let size = pattern.len() as f64;
+    let span = pattern.iter().max().copied().unwrap_or(0);
+
+    let mut log_product = KahanSum::new();
+    log_product.extend(
+        sieve
+            .list_primes(SINGULAR_SERIES_LIMIT, None)
+            .into_iter()
+            .map(|prime| {
+                let covered = match prime > span {
+                    true => pattern.len() as u64,
+                    false => residue_count(pattern, prime),
+                };
+                let prime = prime as f64;
+
+                (-(covered as f64) / prime).ln_1p() - size * (-1.0 / prime).ln_1p()
+            }),
+    );
+
+    let limit = SINGULAR_SERIES_LIMIT as f64;
+    log_product.add(-size * (size - 1.0) / (2.0 * limit * limit.ln()));
+
+    log_product.total().exp()
+}
+
+/// The number of occurrences of `pattern` with all its members in `lbound..=ubound`, as
+/// predicted by the Hardy–Littlewood k-tuple conjecture:
+///
+/// ```text
+/// S * integral of dt / ln(t)^k over max(lbound, 2)..ubound
+/// ```
+///
+/// where `S` is the [`hardy_littlewood_constant`]. The integral is evaluated by Simpson's
+/// rule after substituting `t = e^u`, which turns the integrand into the smooth
+/// `e^u / u^k`.
+pub fn hardy_littlewood_estimate(
+    sieve: enums::SieveMethod,
+    pattern: &[u64],
+    lbound: u64,
+    ubound: u64,
+) -> f64 {
+    let lower = (cmp::max(lbound, 2) as f64).ln();
+    let upper = (ubound as f64).ln();
+    if lower >= upper {
+        return 0.0;
+    }
+
+    let size = pattern.len() as i32;
+
+    hardy_littlewood_constant(sieve, pattern) * simpson(|u| u.exp() / u.powi(size), lower, upper)
+}
+
+/// The [`LinearFormSieve`] over the starting points `n` of `pattern`, with all the
+/// members within `lbound..=ubound`, or [`None`] if there are no such starting points.
+fn constellation_sieve(
+    sieve: enums::SieveMethod,
+    pattern: &[u64],
+    lbound: u64,
+    ubound: u64,
+) -> Option<LinearFormSieve> {
+    let span = pattern.iter().max().copied().unwrap_or(0);
+    let forms = pattern
+        .iter()
+        .map(|&offset| LinearForm::new(1, offset as i64))
+        .collect();
+
+    LinearFormSieve::new(sieve, forms, lbound, ubound.checked_sub(span)?)
+}
+
+/// List the starting points `n` in ascending order, for which `n + d` is prime for
+/// every offset `d` of `pattern`, with all the members within `lbound..=ubound`.
+pub fn find_constellations(
+    sieve: enums::SieveMethod,
+    pattern: &[u64],
+    lbound: u64,
+    ubound: u64,
+) -> Vec<u64> {
+    constellation_sieve(sieve, pattern, lbound, ubound)
+        .map_or_else(Vec::new, |constellations| constellations.find())
+}
+
+/// Count the starting points `n`, for which `n + d` is prime for every offset `d` of
+/// `pattern`, with all the members within `lbound..=ubound`.
+///
+/// Unlike [`find_constellations`], the starting points are never held in memory.
+pub fn count_constellations(
+    sieve: enums::SieveMethod,
+    pattern: &[u64],
+    lbound: u64,
+    ubound: u64,
+) -> u64 {
+    constellation_sieve(sieve, pattern, lbound, ubound)
+        .map_or(0, |constellations| constellations.count())
+}
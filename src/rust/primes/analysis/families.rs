@@ -0,0 +1,275 @@
+use num_bigint::BigUint;
+use num_traits::One;
+use rayon::prelude::*;
+use std::cmp;
+
+use crate::primes::constructs::*;
+use crate::primes::formulas::gcd;
+use crate::primes::primality::{miller_rabin, probable_prime};
+use crate::primes::{RetainedSieve, SegmentedSieve};
+use crate::py_compatibility::enums;
+
+/// The largest prime mask retained for looking up the reversals, rotations and
+/// permutations of primes; numbers beyond it are tested by Miller-Rabin.
+const RETAINED_LIMIT: u64 = 2_u64.pow(26);
+
+/// The digits of `num` in `base`, least significant first.
+fn digits(mut num: u64, base: u64) -> Vec<u64> {
+    let mut digits = vec![num % base];
+    while num >= base {
+        num /= base;
+        digits.push(num % base);
+    }
+
+    digits
+}
+
+/// The number with `digits` in `base`, least significant first, or [`None`] if it
+/// overflows [`u64`].
+fn from_digits<'a>(digits: impl DoubleEndedIterator<Item = &'a u64>, base: u64) -> Option<u64> {
+    digits.rev().try_fold(0_u64, |num, &digit| {
+        num.checked_mul(base)?.checked_add(digit)
+    })
+}
+
+/// The largest number with as many digits in `base` as `num`.
+fn digit_ceiling(num: u64, base: u64) -> u64 {
+    base.checked_pow(digits(num, base).len() as u32)
+        .map_or(u64::MAX, |power| power - 1)
+}
+
+/// A [`RetainedSieve`] for looking up numbers with as many digits as `ubound`, up to
+/// [`RETAINED_LIMIT`].
+fn retained_sieve(sieve: enums::SieveMethod, ubound: u64, base: u64) -> RetainedSieve {
+    RetainedSieve::new(sieve, cmp::min(digit_ceiling(ubound, base), RETAINED_LIMIT))
+}
+
+/// List the primes in `lbound..=ubound` satisfying `predicate`, in ascending order,
+/// filtering the segments of a [`SegmentedSieve`] in parallel.
+fn filter_primes(
+    sieve: enums::SieveMethod,
+    lbound: u64,
+    ubound: u64,
+    predicate: impl Fn(u64) -> bool + Sync,
+) -> Vec<u64> {
+    SegmentedSieve::new(sieve, ubound)
+        .par_segments(lbound, ubound)
+        .flat_map_iter(|(start, mask)| {
+            mask.iter()
+                .enumerate()
+                .filter(|(_, is_prime)| is_prime.is_true())
+                .map(|(index, _)| start + index as u64)
+                .filter(|&prime| predicate(prime))
+                .collect::<Vec<u64>>()
+        })
+        .collect()
+}
+
+/// List the primes in `lbound..=ubound` in ascending order, whose digits in `base` read
+/// the same backwards.
+///
+/// The palindromes are generated directly from their first half and tested by
+/// Miller-Rabin, as they are far too sparse for a sieve. Those with an even number of
+/// digits are all multiples of `base + 1`, so only `base + 1` itself is tested among
+/// them. The first halves run between the leading digits of `lbound` and `ubound`, so
+/// the work follows the range rather than `base`.
+pub fn palindromic_primes(lbound: u64, ubound: u64, base: u64) -> Vec<u64> {
+    if lbound > ubound {
+        return Vec::new();
+    }
+
+    let (min_length, max_length) = (digits(lbound, base).len(), digits(ubound, base).len());
+
+    let is_member =
+        |palindrome: u64| (lbound..=ubound).contains(&palindrome) && miller_rabin(palindrome);
+
+    (min_length..=max_length)
+        .flat_map(|length| match length % 2 {
+            0 => match length == 2 && is_member(base + 1) {
+                true => vec![base + 1],
+                false => Vec::new(),
+            },
+            _ => {
+                let half = length.div_ceil(2) as u32;
+                let scale = base.pow(half - 1);
+                let (first, last) = (
+                    (lbound / scale).max(scale),
+                    (ubound / scale).min(base * scale - 1),
+                );
+
+                (first..=last)
+                    .into_par_iter()
+                    .filter_map(|prefix| {
+                        let mirrored = digits(prefix / base, base);
+                        let value = (prefix as u128) * (base as u128).pow(half - 1)
+                            + from_digits(mirrored.iter().rev(), base)? as u128;
+
+                        u64::try_from(value)
+                            .ok()
+                            .filter(|&palindrome| is_member(palindrome))
+                    })
+                    .collect()
+            }
+        })
+        .collect()
+}
+
+/// List the primes in `lbound..=ubound` in ascending order, whose digits in `base`
+/// reversed are a different prime.
+///
+/// Primes whose reversal does not fit into [`u64`] are left out.
+pub fn emirps(sieve: enums::SieveMethod, lbound: u64, ubound: u64, base: u64) -> Vec<u64> {
+    let retained = retained_sieve(sieve, ubound, base);
+
+    filter_primes(sieve, lbound, ubound, |prime| {
+        from_digits(digits(prime, base).iter().rev(), base)
+            .is_some_and(|reversal| reversal != prime && retained.is_prime(reversal))
+    })
+}
+
+/// Whether every digit of a number with more than one digit is coprime to `base`; if
+/// not, some rearrangement of its digits ends with a digit sharing a factor with `base`,
+/// and is therefore not prime.
+fn has_coprime_digits(digits: &[u64], base: u64) -> bool {
+    digits.len() == 1 || digits.iter().all(|&digit| gcd(digit, base) == 1)
+}
+
+/// List the primes in `lbound..=ubound` in ascending order, for which every rotation of
+/// their digits in `base` is also prime.
+///
+/// Every rotation of a circular prime is listed, e.g. `197`, `719` and `971`.
+pub fn circular_primes(sieve: enums::SieveMethod, lbound: u64, ubound: u64, base: u64) -> Vec<u64> {
+    let retained = retained_sieve(sieve, ubound, base);
+
+    filter_primes(sieve, lbound, ubound, |prime| {
+        let mut digits = digits(prime, base);
+        if !has_coprime_digits(&digits, base) {
+            return false;
+        }
+
+        (1..digits.len()).all(|_| {
+            digits.rotate_left(1);
+            from_digits(digits.iter(), base).is_some_and(|rotation| retained.is_prime(rotation))
+        })
+    })
+}
+
+/// Rearrange `digits` into the next permutation in lexicographic order, returning
+/// `false` once they are back in ascending order.
+fn next_permutation(digits: &mut [u64]) -> bool {
+    let Some(pivot) = (1..digits.len())
+        .rev()
+        .find(|&index| digits[index - 1] < digits[index])
+    else {
+        digits.reverse();
+        return false;
+    };
+
+    let successor = (pivot..digits.len())
+        .rev()
+        .find(|&index| digits[index] > digits[pivot - 1])
+        .unwrap_or(pivot);
+    digits.swap(pivot - 1, successor);
+    digits[pivot..].reverse();
+
+    true
+}
+
+/// List the primes in `lbound..=ubound` in ascending order, for which every permutation
+/// of their digits in `base` is also prime.
+///
+/// The distinct permutations are enumerated in lexicographic order, stopping at the
+/// first one that is not prime.
+pub fn permutable_primes(
+    sieve: enums::SieveMethod,
+    lbound: u64,
+    ubound: u64,
+    base: u64,
+) -> Vec<u64> {
+    let retained = retained_sieve(sieve, ubound, base);
+
+    filter_primes(sieve, lbound, ubound, |prime| {
+        let mut digits = digits(prime, base);
+        if !has_coprime_digits(&digits, base) {
+            return false;
+        }
+
+        digits.sort_unstable();
+        loop {
+            if !from_digits(digits.iter(), base).is_some_and(|value| retained.is_prime(value)) {
+                return false;
+            }
+            if !next_permutation(&mut digits) {
+                return true;
+            }
+        }
+    })
+}
+
+/// List the primes in `lbound..=ubound` in ascending order, which remain prime as their
+/// digits in `base` are removed one by one from `side`.
+///
+/// There are only finitely many in any base, so they are all grown from the single
+/// digit primes, one level of digits at a time in parallel, until they exceed `ubound`.
+/// Left-truncatable primes may not contain the digit `0`.
+pub fn truncatable_primes(
+    lbound: u64,
+    ubound: u64,
+    base: u64,
+    side: enums::TruncationSide,
+) -> Vec<u64> {
+    let mut level: Vec<u64> = (2..cmp::min(base, ubound.saturating_add(1)))
+        .filter(|&digit| miller_rabin(digit))
+        .collect();
+    let mut primes = Vec::new();
+
+    for length in 1_u32.. {
+        if level.is_empty() {
+            break;
+        }
+
+        let next = level
+            .par_iter()
+            .flat_map_iter(|&prime| {
+                (0..base).filter_map(move |digit| match side {
+                    enums::TruncationSide::LEFT if digit > 0 => digit
+                        .checked_mul(base.checked_pow(length)?)?
+                        .checked_add(prime),
+                    enums::TruncationSide::LEFT => None,
+                    enums::TruncationSide::RIGHT => prime.checked_mul(base)?.checked_add(digit),
+                })
+            })
+            .filter(|&candidate| candidate <= ubound && miller_rabin(candidate))
+            .collect();
+
+        primes.append(&mut level);
+        level = next;
+    }
+
+    primes.retain(|&prime| prime >= lbound);
+    primes.par_sort_unstable();
+
+    primes
+}
+
+/// The repunit `(base^exponent - 1) / (base - 1)`, whose `exponent` digits in `base`
+/// are all `1`.
+pub fn repunit(base: u64, exponent: u64) -> BigUint {
+    let base = BigUint::from(base);
+
+    (base.pow(exponent as u32) - BigUint::one()) / (base - BigUint::one())
+}
+
+/// List the exponents up to and including `max_exponent` in ascending order, for which
+/// the [`repunit`] in `base` is prime.
+///
+/// A repunit with a composite exponent `ab` is divisible by the repunit with exponent
+/// `a`, so only the prime exponents are tested, in parallel. Beyond [`u64`], these are
+/// probable primes by Miller-Rabin.
+pub fn repunit_primes(sieve: enums::SieveMethod, base: u64, max_exponent: u64) -> Vec<u64> {
+    sieve
+        .list_primes(max_exponent, None)
+        .into_par_iter()
+        .filter(|&exponent| probable_prime(&repunit(base, exponent)))
+        .collect()
+}
@@ -0,0 +1,154 @@
+use rayon::prelude::*;
+use std::collections::BTreeMap;
+
+use crate::primes::constructs::*;
+use crate::primes::SegmentedSieve;
+use crate::py_compatibility::enums;
+
+/// A gap between two consecutive primes.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PrimeGap {
+    /// The prime at the start of the gap.
+    pub start: u64,
+
+    /// The difference between `start` and the next prime.
+    pub gap: u64,
+}
+impl PrimeGap {
+    /// The merit of the gap, `gap / ln(start)`, i.e. how many times larger it is than
+    /// the average gap around `start`.
+    pub fn merit(&self) -> f64 {
+        self.gap as f64 / (self.start as f64).ln()
+    }
+}
+
+/// Statistics of the gaps between consecutive primes over a range.
+#[derive(Debug, Default)]
+pub struct PrimeGapStatistics {
+    /// The first prime in the range.
+    pub first: Option<u64>,
+
+    /// The last prime in the range.
+    pub last: Option<u64>,
+
+    /// The number of gaps of each size, where index `g` counts the gaps of size `g`.
+    pub histogram: Vec<u64>,
+
+    /// The maximal gaps, i.e. every gap that is strictly larger than all the gaps
+    /// before it in the range, in ascending order.
+    pub records: Vec<PrimeGap>,
+
+    /// The first occurrence of each gap size in the range, keyed by size.
+    pub first_occurrences: BTreeMap<u64, PrimeGap>,
+
+    /// The gap with the highest merit in the range.
+    pub max_merit: Option<PrimeGap>,
+}
+impl PrimeGapStatistics {
+    /// Append the next prime to the range.
+    fn push(&mut self, prime: u64) {
+        if let Some(last) = self.last {
+            self.record(PrimeGap {
+                start: last,
+                gap: prime - last,
+            });
+        }
+
+        self.first.get_or_insert(prime);
+        self.last = Some(prime);
+    }
+
+    fn max_gap(&self) -> u64 {
+        self.records.last().map_or(0, |record| record.gap)
+    }
+
+    /// Count a gap that occurs after all the gaps already counted.
+    fn record(&mut self, gap: PrimeGap) {
+        if self.histogram.len() <= gap.gap as usize {
+            self.histogram.resize(gap.gap as usize + 1, 0);
+        }
+        self.histogram[gap.gap as usize] += 1;
+
+        if gap.gap > self.max_gap() {
+            self.records.push(gap);
+        }
+
+        self.first_occurrences.entry(gap.gap).or_insert(gap);
+
+        if self
+            .max_merit
+            .is_none_or(|max_merit| gap.merit() > max_merit.merit())
+        {
+            self.max_merit = Some(gap);
+        }
+    }
+
+    /// Join the statistics of two ranges, where `self` comes immediately before
+    /// `other`.
+    fn concat(mut self, other: Self) -> Self {
+        if let (Some(last), Some(first)) = (self.last, other.first) {
+            self.record(PrimeGap {
+                start: last,
+                gap: first - last,
+            });
+        }
+
+        if self.histogram.len() < other.histogram.len() {
+            self.histogram.resize(other.histogram.len(), 0);
+        }
+        self.histogram
+            .iter_mut()
+            .zip(other.histogram.iter())
+            .for_each(|(count, other_count)| *count += other_count);
+
+        // Only the gaps larger than everything in `self` remain records.
+        let max_gap = self.max_gap();
+        self.records.extend(
+            other
+                .records
+                .into_iter()
+                .filter(|record| record.gap > max_gap),
+        );
+
+        for (size, gap) in other.first_occurrences {
+            self.first_occurrences.entry(size).or_insert(gap);
+        }
+
+        if let Some(other_max_merit) = other.max_merit {
+            if self
+                .max_merit
+                .is_none_or(|max_merit| other_max_merit.merit() > max_merit.merit())
+            {
+                self.max_merit = Some(other_max_merit);
+            }
+        }
+
+        self.first = self.first.or(other.first);
+        self.last = other.last.or(self.last);
+
+        self
+    }
+}
+
+/// Calculate the [`PrimeGapStatistics`] of all the primes in `lbound..=ubound`.
+///
+/// The range is split by a [`SegmentedSieve`], where the statistics of each segment
+/// are calculated in parallel, then joined in order; the gaps spanning the segment
+/// boundaries are counted as the segments are joined.
+pub fn prime_gaps(sieve: enums::SieveMethod, lbound: u64, ubound: u64) -> PrimeGapStatistics {
+    let segmented = SegmentedSieve::new(sieve, ubound);
+
+    segmented
+        .par_segments(lbound, ubound)
+        .map(|(segment_lbound, mask)| {
+            let mut statistics = PrimeGapStatistics::default();
+
+            mask.iter()
+                .enumerate()
+                .filter(|(_, is_prime)| is_prime.is_true())
+                .for_each(|(offset, _)| statistics.push(segment_lbound + offset as u64));
+
+            statistics
+        })
+        .reduce(PrimeGapStatistics::default, PrimeGapStatistics::concat)
+}
@@ -0,0 +1,71 @@
+use ndarray::Array2;
+use rayon::prelude::*;
+
+use crate::primes::constructs::*;
+use crate::primes::formulas::{isqrt, jacobi, pow_mod};
+use crate::py_compatibility::enums;
+
+/// The decomposition `prime = a^2 + b^2` with `a >= b`, of `2` or a prime `1 mod 4`, by
+/// the Hermite-Serret algorithm; or [`None`] for any other `prime`.
+///
+/// With `x^2 = -1 mod prime`, taken as a power of a quadratic non-residue, the Euclidean
+/// algorithm on `prime` and `x` passes below `sqrt(prime)` at `a`, followed by `b`.
+pub fn two_squares(prime: u64) -> Option<(u64, u64)> {
+    if prime == 2 {
+        return Some((1, 1));
+    }
+    if prime % 4 != 1 {
+        return None;
+    }
+
+    let non_residue = (2..prime).find(|&value| jacobi(value, prime) == -1)?;
+    let limit = isqrt(prime);
+
+    let (mut dividend, mut divisor) = (prime, pow_mod(non_residue, (prime - 1) / 4, prime));
+    while divisor > limit {
+        (dividend, divisor) = (divisor, dividend % divisor);
+    }
+    let (a, b) = (divisor, dividend % divisor.max(1));
+
+    (a as u128 * a as u128 + b as u128 * b as u128 == prime as u128).then_some((a, b))
+}
+
+/// A mask of the Gaussian primes `a + bi` with norm `a^2 + b^2` up to and including
+/// `norm`, where `[r + a, r + b]` is `a + bi` for `r = isqrt(norm)`.
+///
+/// Every Gaussian prime is an associate of one of
+/// - `p` for a rational prime `p = 3 mod 4`, with norm `p^2`;
+/// - `a + bi` or `b + ai` for `p = a^2 + b^2`, where `p` is `2` or a prime `1 mod 4`.
+///
+/// The rational primes up to `norm` are therefore classified by their residue modulo
+/// `4`, and decomposed by [`two_squares`] in parallel, before every associate and
+/// conjugate is marked.
+pub fn gaussian_primes(sieve: enums::SieveMethod, norm: u64) -> Array2<bool> {
+    let radius = isqrt(norm);
+    let side = 2 * radius as usize + 1;
+    let mut mask = Array2::from_elem((side, side), false);
+
+    let points: Vec<(u64, u64)> = sieve
+        .list_primes(norm, None)
+        .into_par_iter()
+        .filter_map(|prime| match prime % 4 {
+            3 => (prime <= radius).then_some((prime, 0)),
+            _ => two_squares(prime),
+        })
+        .collect();
+
+    let radius = radius as usize;
+    for (a, b) in points {
+        let (a, b) = (a as usize, b as usize);
+
+        for (real, imaginary) in [(a, b), (b, a)] {
+            for real in [radius + real, radius - real] {
+                for imaginary in [radius + imaginary, radius - imaginary] {
+                    mask[[real, imaginary]] = true;
+                }
+            }
+        }
+    }
+
+    mask
+}
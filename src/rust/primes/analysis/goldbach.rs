@@ -0,0 +1,162 @@
+use rayon::prelude::*;
+use rustfft::{num_complex::Complex, FftPlanner};
+use std::cmp;
+
+use crate::primes::constructs::*;
+use crate::primes::methods::DEFAULT_SEGMENT_SIZE;
+use crate::primes::SegmentedSieve;
+use crate::py_compatibility::enums;
+
+/// The primes up to this are tried first by [`verify_goldbach`]; the minimal prime of
+/// every even number verified so far is far below this.
+const SMALL_PRIME_BOUND: u64 = 2_u64.pow(16);
+
+/// The number of even numbers in each segment of [`verify_goldbach`].
+const VERIFY_SEGMENT_SIZE: u64 = 2_u64.pow(20);
+
+/// List the ways to write `num` as the sum of two primes `p + q`, as `(p, q)` with
+/// `p <= q` in ascending order of `p`.
+pub fn goldbach_partitions(sieve: enums::SieveMethod, num: u64) -> Vec<(u64, u64)> {
+    let mask = sieve.sieve(num);
+
+    (2..=num / 2)
+        .filter(|&prime| mask[prime as usize] && mask[(num - prime) as usize])
+        .map(|prime| (prime, num - prime))
+        .collect()
+}
+
+/// Count the ways to write `num` as the sum of two primes `p + q` with `p <= q`.
+pub fn count_goldbach_partitions(sieve: enums::SieveMethod, num: u64) -> u64 {
+    let mask = sieve.sieve(num);
+
+    (2..=num / 2)
+        .filter(|&prime| mask[prime as usize] && mask[(num - prime) as usize])
+        .count() as u64
+}
+
+/// Count the ways to write every number up to `num` as the sum of two primes `p + q`
+/// with `p <= q`, where index `n` of the returned [`Vec`] is the count for `n`.
+///
+/// Writing each odd number `2a + 1` as `a`, the sums of two odd primes are the
+/// self-convolution of the odd part of the prime mask, which is calculated in
+/// `O(n log n)` by squaring its Fourier transform. This counts each `p + q` as well as
+/// `q + p`, so it is halved after adding back the `p + p` counted only once. The
+/// partitions involving `2` are then added directly.
+///
+/// The Fourier transform holds a [`Complex<f64>`] for every odd number up to `num`,
+/// padded to the next power of `2`.
+pub fn goldbach_counts(sieve: enums::SieveMethod, num: u64) -> Vec<u64> {
+    let mask = sieve.sieve(num);
+    let mut counts = vec![0_u64; num as usize + 1];
+
+    // Index `a` of the odd mask is `2a + 1`.
+    let odd_count = num.div_ceil(2) as usize;
+    if odd_count > 0 {
+        let size = (2 * odd_count - 1).next_power_of_two();
+
+        let mut buffer: Vec<Complex<f64>> = (0..size)
+            .map(|index| match index < odd_count && mask[2 * index + 1] {
+                true => Complex::new(1.0, 0.0),
+                false => Complex::new(0.0, 0.0),
+            })
+            .collect();
+
+        let mut planner = FftPlanner::new();
+        planner.plan_fft_forward(size).process(&mut buffer);
+        buffer.iter_mut().for_each(|value| *value = *value * *value);
+        planner.plan_fft_inverse(size).process(&mut buffer);
+
+        // Index `m` of the convolution is `(2a + 1) + (2b + 1)` where `a + b = m`.
+        buffer
+            .iter()
+            .enumerate()
+            .map(|(index, value)| (2 * index + 2, (value.re / size as f64).round() as u64))
+            .take_while(|&(sum, _)| sum <= num as usize)
+            .for_each(|(sum, ordered)| {
+                let square = (sum / 2) % 2 == 1 && mask[sum / 2];
+                counts[sum] = (ordered + square as u64) / 2;
+            });
+    }
+
+    // The only partition of an odd number is `2 + (n - 2)`, and `4` is `2 + 2`.
+    if num >= 4 {
+        counts[4] += 1;
+    }
+    (5..=num as usize)
+        .step_by(2)
+        .for_each(|odd| counts[odd] = mask[odd - 2] as u64);
+
+    counts
+}
+
+/// Search for the smallest prime `p` after `after`, for which `num - p` is also prime,
+/// by sieving both `p` and `num - p` in segments.
+///
+/// Returns `0` if there is no such prime.
+fn minimal_partition(segmented: &SegmentedSieve, num: u64, after: u64) -> u64 {
+    let mut lbound = after + 1;
+
+    while lbound <= num / 2 {
+        let ubound = cmp::min(lbound + DEFAULT_SEGMENT_SIZE - 1, num / 2);
+
+        let primes = segmented.sieve_segment(lbound, ubound);
+        let complements = segmented.sieve_segment(num - ubound, num - lbound);
+
+        if let Some(prime) = (lbound..=ubound).find(|&prime| {
+            primes[(prime - lbound) as usize] && complements[(ubound - prime) as usize]
+        }) {
+            return prime;
+        }
+
+        lbound = ubound + 1;
+    }
+
+    0
+}
+
+/// Find the smallest prime `p` for every even number `n` in `lbound..=ubound`, for which
+/// `n - p` is also prime, where index `i` of the returned [`Vec`] is for the `i`-th even
+/// number of the range.
+///
+/// An entry of `0` means `n` has no such partition, i.e. `n` is `0` or `2`, or it is a
+/// counterexample to Goldbach's conjecture.
+///
+/// The range is split into segments, each of which sieves a window of the complements
+/// `n - p` for the primes up to [`SMALL_PRIME_BOUND`], in parallel. Should none of these
+/// primes work, all the larger primes are searched too, so that every `0` is genuine.
+pub fn verify_goldbach(sieve: enums::SieveMethod, lbound: u64, ubound: u64) -> Vec<u64> {
+    let first = lbound.div_ceil(2) * 2;
+    if first > ubound {
+        return Vec::new();
+    }
+
+    let segmented = SegmentedSieve::new(sieve, ubound);
+    let small_primes = sieve.list_primes(SMALL_PRIME_BOUND, None);
+
+    let segment_count = ((ubound - first) / 2 / VERIFY_SEGMENT_SIZE + 1) as usize;
+
+    (0..segment_count)
+        .into_par_iter()
+        .flat_map_iter(|index| {
+            let start = first + 2 * VERIFY_SEGMENT_SIZE * index as u64;
+            let end = cmp::min(start + 2 * (VERIFY_SEGMENT_SIZE - 1), ubound);
+
+            let window_lbound = start.saturating_sub(SMALL_PRIME_BOUND);
+            let window = segmented.sieve_segment(window_lbound, end);
+
+            (start..=end)
+                .step_by(2)
+                .map(|even| {
+                    small_primes
+                        .iter()
+                        .take_while(|&&prime| prime <= even / 2)
+                        .find(|&&prime| window[(even - prime - window_lbound) as usize])
+                        .map_or_else(
+                            || minimal_partition(&segmented, even, SMALL_PRIME_BOUND),
+                            |&prime| prime,
+                        )
+                })
+                .collect::<Vec<u64>>()
+        })
+        .collect()
+}
@@ -0,0 +1,43 @@
+//! Statistics on the distribution of primes.
+
+pub mod chains;
+pub mod constellations;
+pub mod families;
+pub mod gaps;
+pub mod gaussian;
+pub mod goldbach;
+pub mod polynomials;
+pub mod progressions;
+pub mod quotients;
+pub mod ramanujan;
+pub mod spirals;
+pub mod transitions;
+
+pub use chains::{cunningham_chain_sieve, safe_prime_sieve, sophie_germain_sieve};
+pub use constellations::{
+    count_constellations, find_constellations, hardy_littlewood_constant,
+    hardy_littlewood_estimate, is_admissible,
+};
+pub use families::{
+    circular_primes, emirps, palindromic_primes, permutable_primes, repunit, repunit_primes,
+    truncatable_primes,
+};
+pub use gaps::{prime_gaps, PrimeGap, PrimeGapStatistics};
+pub use gaussian::{gaussian_primes, two_squares};
+pub use goldbach::{
+    count_goldbach_partitions, goldbach_counts, goldbach_partitions, verify_goldbach,
+};
+pub use polynomials::{
+    bateman_horn_constant, bateman_horn_estimate, count_polynomial_primes, polynomial_primes,
+    polynomial_roots, PolynomialRoots,
+};
+pub use progressions::{count_primes_mod, list_primes_in_progression};
+pub use quotients::{
+    fermat_quotient, fibonacci_quotient, special_prime_quotients, wilson_quotient,
+};
+pub use ramanujan::{labos_primes, ramanujan_primes};
+pub use spirals::{
+    prime_spiral, quadratic_values, sacks_length, sacks_position, ulam_length, ulam_position,
+    write_pgm, write_png,
+};
+pub use transitions::residue_transitions;
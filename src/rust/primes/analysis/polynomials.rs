@@ -0,0 +1,182 @@
+use rayon::prelude::*;
+
+use crate::primes::constructs::*;
+use crate::primes::formulas::{simpson, Polynomial};
+use crate::primes::PolynomialSieve;
+use crate::py_compatibility::enums;
+
+/// The primes up to which the product of [`bateman_horn_constant`] is evaluated.
+const SINGULAR_SERIES_LIMIT: u64 = 2_u64.pow(22);
+
+/// The distinct roots of a polynomial modulo every prime up to a bound, in compressed
+/// sparse row form.
+#[derive(Debug, Default)]
+pub struct PolynomialRoots {
+    /// The primes in ascending order.
+    pub primes: Vec<u64>,
+
+    /// The roots modulo `primes[i]` are `roots[offsets[i]..offsets[i + 1]]`, so there is
+    /// one more offset than there are primes, starting from `0`.
+    pub offsets: Vec<u64>,
+
+    /// The roots modulo each prime in ascending order, one prime after another.
+    pub roots: Vec<u64>,
+
+    /// The leading coefficient of the polynomial, which decides the projective roots.
+    leading_coefficient: i64,
+}
+impl PolynomialRoots {
+    /// The roots modulo `primes[index]`.
+    pub fn roots_of(&self, index: usize) -> &[u64] {
+        &self.roots[self.offsets[index] as usize..self.offsets[index + 1] as usize]
+    }
+
+    /// The Murphy alpha of the polynomial over these primes, i.e.
+    ///
+    /// ```text
+    /// alpha = sum over primes p of (1 - p w(p) / (p + 1)) ln(p) / (p - 1)
+    /// ```
+    ///
+    /// where `w(p)` is the number of roots modulo `p`, with the projective root counted
+    /// wherever `p` divides the leading coefficient. Its exponential is the factor by
+    /// which the values of the polynomial are more likely to be smooth than random
+    /// integers of the same size, so that a negative alpha favours the polynomial.
+    pub fn murphy_alpha(&self) -> f64 {
+        let mut alpha = KahanSum::new();
+        alpha.extend(self.primes.iter().enumerate().map(|(index, &prime)| {
+            let projective = self
+                .leading_coefficient
+                .unsigned_abs()
+                .is_multiple_of(prime);
+            let covered = (self.roots_of(index).len() + projective as usize) as f64;
+            let prime = prime as f64;
+
+            (1. - prime * covered / (prime + 1.)) * prime.ln() / (prime - 1.)
+        }));
+
+        alpha.total()
+    }
+}
+
+/// Find the roots of `polynomial` modulo every prime up to and including `bound`, by
+/// [`Polynomial::roots_mod`] in parallel over the primes.
+///
+/// Every residue is a root modulo a prime dividing every coefficient.
+pub fn polynomial_roots(
+    sieve: enums::SieveMethod,
+    polynomial: &Polynomial,
+    bound: u64,
+) -> PolynomialRoots {
+    let primes = sieve.list_primes(bound, None);
+    let roots: Vec<Vec<u64>> = primes
+        .par_iter()
+        .map(|&prime| polynomial.roots_mod(prime))
+        .collect();
+
+    let offsets = std::iter::once(0)
+        .chain(roots.iter().scan(0, |offset, roots| {
+            *offset += roots.len() as u64;
+            Some(*offset)
+        }))
+        .collect();
+
+    PolynomialRoots {
+        primes,
+        offsets,
+        roots: roots.concat(),
+        leading_coefficient: polynomial.leading_coefficient(),
+    }
+}
+
+/// List the `n` in `lbound..=ubound` in ascending order, for which `f(n)` is prime, by
+/// the [`PolynomialSieve`].
+///
+/// Returns [`None`] if `|f(n)|` overflows [`u64`] within the range.
+pub fn polynomial_primes(
+    sieve: enums::SieveMethod,
+    polynomial: &Polynomial,
+    lbound: u64,
+    ubound: u64,
+) -> Option<Vec<u64>> {
+    PolynomialSieve::new(sieve, polynomial.clone(), lbound, ubound).map(|values| values.find())
+}
+
+/// Count the `n` in `lbound..=ubound`, for which `f(n)` is prime, by the
+/// [`PolynomialSieve`].
+///
+/// Returns [`None`] if `|f(n)|` overflows [`u64`] within the range.
+pub fn count_polynomial_primes(
+    sieve: enums::SieveMethod,
+    polynomial: &Polynomial,
+    lbound: u64,
+    ubound: u64,
+) -> Option<u64> {
+    PolynomialSieve::new(sieve, polynomial.clone(), lbound, ubound).map(|values| values.count())
+}
+
+/// The Bateman–Horn constant of an irreducible `polynomial`, i.e. the singular series
+///
+/// ```text
+/// C = product over all primes p of (1 - w(p) / p) / (1 - 1 / p)
+/// ```
+///
+/// where `w(p)` is the number of roots of the polynomial modulo `p`, evaluated up to
+/// [`SINGULAR_SERIES_LIMIT`]. It is `0` if some prime divides every value, e.g. `2` for
+/// `n^2 + n + 2`.
+///
+/// On average `w(p)` is `1` for an irreducible polynomial, so the product only converges
+/// conditionally, and to a few digits at this limit.
+pub fn bateman_horn_constant(sieve: enums::SieveMethod, polynomial: &Polynomial) -> f64 {
+    let terms: Option<Vec<f64>> = sieve
+        .list_primes(SINGULAR_SERIES_LIMIT, None)
+        .into_par_iter()
+        .map(|prime| {
+            let covered = polynomial.roots_mod(prime).len() as u64;
+            let prime = prime as f64;
+
+            (covered < prime as u64)
+                .then(|| (-(covered as f64) / prime).ln_1p() - (-1.0 / prime).ln_1p())
+        })
+        .collect();
+
+    terms.map_or(0.0, |terms| {
+        let mut log_product = KahanSum::new();
+        log_product.extend(terms);
+
+        log_product.total().exp()
+    })
+}
+
+/// The number of `n` in `lbound..=ubound` for which `f(n)` is prime, as predicted by the
+/// Bateman–Horn conjecture:
+///
+/// ```text
+/// C * integral of dt / ln(f(t)) over lbound..ubound
+/// ```
+///
+/// where `C` is the [`bateman_horn_constant`], for an irreducible `polynomial` with a
+/// positive leading coefficient. The integrand is taken as `0` wherever `f(t) < e`, and
+/// the integral is evaluated by Simpson's rule after substituting `t = e^u - 1`.
+pub fn bateman_horn_estimate(
+    sieve: enums::SieveMethod,
+    polynomial: &Polynomial,
+    lbound: u64,
+    ubound: u64,
+) -> f64 {
+    let lower = (lbound as f64).ln_1p();
+    let upper = (ubound as f64).ln_1p();
+    if lower >= upper {
+        return 0.0;
+    }
+
+    let integrand = |u: f64| {
+        let value = polynomial.evaluate_float(u.exp_m1());
+
+        match value > std::f64::consts::E {
+            true => u.exp() / value.ln(),
+            false => 0.0,
+        }
+    };
+
+    bateman_horn_constant(sieve, polynomial) * simpson(integrand, lower, upper)
+}
@@ -0,0 +1,85 @@
+use ndarray::{s, Array};
+
+use crate::primes::constructs::*;
+use crate::primes::formulas::{isqrt, mod_inverse};
+use crate::py_compatibility::enums;
+
+/// Count the primes up to and including `ubound` in every residue class modulo
+/// `modulus`, in a single pass over the prime mask from `sieve`.
+///
+/// The count of primes `p` with `p % modulus == r` is at index `r` of the returned
+/// [`Vec`].
+pub fn count_primes_mod(sieve: enums::SieveMethod, ubound: u64, modulus: u64) -> Vec<u64> {
+    let mut counts = vec![0_u64; modulus as usize];
+
+    sieve
+        .sieve(ubound)
+        .iter()
+        .enumerate()
+        .filter(|(_, is_prime)| is_prime.is_true())
+        .for_each(|(prime, _)| counts[prime % modulus as usize] += 1);
+
+    counts
+}
+
+/// List all the primes `p` in `lbound..=ubound` where `p % modulus == residue % modulus`.
+///
+/// Only the progression itself is sieved: index `k` of the mask corresponds to
+/// `first + k * modulus`, and each base prime `p` up to `sqrt(ubound)` strikes out the
+/// `k` solving `first + k * modulus == 0 (mod p)` with a `slice_mut` over steps of `p`.
+pub fn list_primes_in_progression(
+    sieve: enums::SieveMethod,
+    residue: u64,
+    modulus: u64,
+    lbound: u64,
+    ubound: u64,
+) -> Vec<u64> {
+    let residue = residue % modulus;
+
+    let offset = match lbound % modulus {
+        remainder if remainder <= residue => residue - remainder,
+        remainder => modulus - (remainder - residue),
+    };
+
+    // The first member of the progression at or above `lbound`, if there is one within
+    // `u64`.
+    let first = match lbound.checked_add(offset) {
+        Some(first) if first <= ubound => first,
+        _ => return Vec::new(),
+    };
+
+    let term = |index: usize| first + index as u64 * modulus;
+
+    let mut mask: NonAtomicSieve =
+        Array::from_elem(((ubound - first) / modulus + 1) as usize, true);
+
+    for prime in sieve.list_primes(isqrt(ubound), None) {
+        let (start, step) = match mod_inverse(modulus, prime) {
+            // first + k * modulus == 0 (mod prime)
+            Some(inverse) => {
+                let offset = (prime - first % prime) % prime;
+                let start = offset as u128 * inverse as u128 % prime as u128;
+
+                (start as usize, prime as usize)
+            }
+            // prime | modulus; either every member is divisible by prime, or none is.
+            None if first.is_multiple_of(prime) => (0, 1),
+            None => continue,
+        };
+
+        if start < mask.len() {
+            mask.slice_mut(s![start..; step]).fill(false);
+        }
+
+        // The prime itself may be a member of the progression.
+        if prime >= first && (prime - first).is_multiple_of(modulus) && prime <= ubound {
+            mask[((prime - first) / modulus) as usize] = true;
+        }
+    }
+
+    mask.iter()
+        .enumerate()
+        .filter(|(index, is_prime)| is_prime.is_true() && term(*index) > 1)
+        .map(|(index, _)| term(index))
+        .collect()
+}
@@ -0,0 +1,127 @@
+use rayon::prelude::*;
+
+use crate::primes::constructs::*;
+use crate::primes::formulas::{jacobi, Montgomery};
+use crate::primes::SegmentedSieve;
+use crate::py_compatibility::enums;
+
+/// The Montgomery arithmetic modulo `prime^2`, or [`None`] if `prime` is `2` or not
+/// below `2^63`.
+fn square_modulus(prime: u64) -> Option<Montgomery> {
+    Montgomery::new(prime as u128 * prime as u128)
+}
+
+/// The Fermat quotient `(base^(p - 1) - 1) / p mod p` of the prime `p`, which is `0`
+/// exactly when `p` is a Wieferich prime to `base`; or [`None`] if `p` divides `base`.
+pub fn fermat_quotient(base: u64, prime: u64) -> Option<u64> {
+    if base.is_multiple_of(prime) {
+        return None;
+    }
+    // `2` does not fit the Montgomery arithmetic, and `base` is odd.
+    if prime == 2 {
+        return Some((base % 4 == 3) as u64);
+    }
+
+    let arithmetic = square_modulus(prime)?;
+    let power = arithmetic.pow(arithmetic.to_form(base as u128), prime as u128 - 1);
+
+    Some(((arithmetic.from_form(power) + arithmetic.modulus() - 1) / prime as u128) as u64 % prime)
+}
+
+/// The Wilson quotient `((p - 1)! + 1) / p mod p` of the prime `p`, which is `0` exactly
+/// when `p` is a Wilson prime.
+///
+/// The factorial takes a multiplication modulo `p^2` for every pair of factors, so this
+/// is `O(p)`. Each product is left out of the Montgomery form, i.e. divided by `R`, which
+/// is made up for by a single power of `R` at the end.
+pub fn wilson_quotient(prime: u64) -> Option<u64> {
+    // `1! + 1 = 2`
+    if prime == 2 {
+        return Some(1);
+    }
+
+    let arithmetic = square_modulus(prime)?;
+    let (product, count) = (2..prime)
+        .step_by(2)
+        .map(|factor| match factor + 1 < prime {
+            true => factor as u128 * (factor + 1) as u128,
+            false => factor as u128,
+        })
+        .fold((1, 0), |(product, count), factors| {
+            (arithmetic.mul(product, factors), count + 1)
+        });
+
+    let radix = arithmetic.to_form(arithmetic.to_form(1));
+    let factorial = arithmetic.mul(product, arithmetic.pow(radix, count));
+
+    Some(((factorial + 1) % arithmetic.modulus() / prime as u128) as u64)
+}
+
+/// The Fibonacci quotient `F(p - (p/5)) / p mod p` of the prime `p`, where `(p/5)` is the
+/// Legendre symbol; this is `0` exactly when `p` is a Wall-Sun-Sun prime, of which none
+/// are known.
+///
+/// The Fibonacci number is calculated modulo `p^2` by fast doubling, i.e.
+/// `F(2k) = F(k) * (2F(k + 1) - F(k))` and `F(2k + 1) = F(k)^2 + F(k + 1)^2`.
+pub fn fibonacci_quotient(prime: u64) -> Option<u64> {
+    // `F(3) = 2`
+    if prime == 2 {
+        return Some(1);
+    }
+
+    let arithmetic = square_modulus(prime)?;
+    let index = match jacobi(5, prime) {
+        1 => prime - 1,
+        -1 => prime + 1,
+        _ => prime,
+    };
+
+    let (mut current, mut next) = (0, arithmetic.to_form(1));
+    for bit in (0..u64::BITS - index.leading_zeros()).rev() {
+        let double = arithmetic.mul(current, arithmetic.sub(arithmetic.add(next, next), current));
+        let double_next =
+            arithmetic.add(arithmetic.mul(current, current), arithmetic.mul(next, next));
+
+        (current, next) = match (index >> bit) & 1 {
+            1 => (double_next, arithmetic.add(double, double_next)),
+            _ => (double, double_next),
+        };
+    }
+
+    Some((arithmetic.from_form(current) / prime as u128) as u64 % prime)
+}
+
+/// The quotient `q` of `kind` for every prime `p` in `lbound..=ubound` with
+/// `|q| <= max_quotient`, in ascending order of `p`, where `q` is taken in
+/// `(-p/2, p/2]`.
+///
+/// A quotient of `0` is a prime of `kind`, while the other small quotients are the near
+/// misses. The primes are found by a [`SegmentedSieve`], and their quotients calculated
+/// in parallel over its segments. Primes not below `2^63` are skipped.
+pub fn special_prime_quotients(
+    sieve: enums::SieveMethod,
+    kind: enums::SpecialPrime,
+    lbound: u64,
+    ubound: u64,
+    max_quotient: u64,
+) -> Vec<(u64, i64)> {
+    SegmentedSieve::new(sieve, ubound)
+        .par_segments(lbound, ubound)
+        .flat_map_iter(|(start, mask)| {
+            mask.iter()
+                .enumerate()
+                .filter(|(_, is_prime)| is_prime.is_true())
+                .filter_map(|(index, _)| {
+                    let prime = start + index as u64;
+                    let quotient = kind.quotient(prime)?;
+                    let signed = match quotient > prime / 2 {
+                        true => quotient as i64 - prime as i64,
+                        false => quotient as i64,
+                    };
+
+                    (signed.unsigned_abs() <= max_quotient).then_some((prime, signed))
+                })
+                .collect::<Vec<(u64, i64)>>()
+        })
+        .collect()
+}
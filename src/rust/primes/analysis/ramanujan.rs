@@ -0,0 +1,69 @@
+use crate::primes::constructs::*;
+use crate::primes::formulas::upper_bound_of_nth_prime;
+use crate::py_compatibility::enums;
+
+/// The primes up to the upper bound of `p(3n)`, which bounds the `n`th Ramanujan prime
+/// and therefore also the `n`th Labos prime.
+fn primes_for(sieve: enums::SieveMethod, n: u64) -> Vec<u64> {
+    sieve.list_primes(upper_bound_of_nth_prime(n.saturating_mul(3)), None)
+}
+
+/// Every prime `p` in `primes` with `π(p) - π(p/2)`, the number of primes in `(p/2, p]`.
+///
+/// `primes` must be every prime in ascending order up to some limit, so that their
+/// indices are their ranks; `π(p/2)` then only moves forward with `p`.
+fn half_interval_counts(primes: &[u64]) -> impl Iterator<Item = (u64, u64)> + '_ {
+    let mut half = 0;
+
+    primes.iter().enumerate().map(move |(index, &prime)| {
+        while half < primes.len() && primes[half] <= prime / 2 {
+            half += 1;
+        }
+
+        (prime, (index + 1 - half) as u64)
+    })
+}
+
+/// List the first `n` Ramanujan primes, where the `k`th is the smallest `R(k)` such that
+/// `π(x) - π(x/2) >= k` for all `x >= R(k)`.
+///
+/// `π(x) - π(x/2)` only rises at primes, by `1`, so `R(k)` is the largest prime `p` with
+/// `π(p) - π(p/2) <= k`. As `R(k) <= p(3k)` by Laishram, it suffices to sweep the primes
+/// up to the upper bound of `p(3n)` once.
+pub fn ramanujan_primes(sieve: enums::SieveMethod, n: u64) -> Vec<u64> {
+    let primes = primes_for(sieve, n);
+    let mut largest = vec![0; n as usize];
+
+    // Counts at primes are at least `1`, for the prime itself.
+    for (prime, count) in half_interval_counts(&primes) {
+        if count <= n {
+            largest[count as usize - 1] = prime;
+        }
+    }
+
+    largest
+        .into_iter()
+        .scan(0, |maximum, prime| {
+            *maximum = prime.max(*maximum);
+            Some(*maximum)
+        })
+        .collect()
+}
+
+/// List the first `n` Labos primes, where the `k`th is the smallest `x` with
+/// `π(x) - π(x/2) = k`, i.e. where the count first reaches `k`.
+///
+/// This happens at a prime, and no later than the `k`th Ramanujan prime, so the same
+/// sweep as for [`ramanujan_primes`] finds them all.
+pub fn labos_primes(sieve: enums::SieveMethod, n: u64) -> Vec<u64> {
+    let primes = primes_for(sieve, n);
+    let mut first = vec![0; n as usize];
+
+    for (prime, count) in half_interval_counts(&primes) {
+        if count <= n && first[count as usize - 1] == 0 {
+            first[count as usize - 1] = prime;
+        }
+    }
+
+    first
+}
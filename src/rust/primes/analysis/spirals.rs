@@ -0,0 +1,294 @@
+use std::f64::consts::TAU;
+use std::io::{self, Write};
+
+use ndarray::Array2;
+use rayon::prelude::*;
+
+use crate::primes::constructs::*;
+use crate::primes::formulas::isqrt;
+use crate::primes::primality::miller_rabin;
+use crate::primes::SegmentedSieve;
+use crate::py_compatibility::enums;
+
+/// The grey level of the primes, and of the widest gaps when colouring by gap size.
+const PRIME_LEVEL: u8 = 255;
+
+/// The grey level of the narrowest gaps when colouring by gap size.
+const GAP_FLOOR: u8 = 64;
+
+/// The grey level of the composite values of the highlighted polynomials.
+const HIGHLIGHT_LEVEL: u8 = 48;
+
+/// The number of indices laid out by [`ulam_position`] to cover a square of `side`, some
+/// of which fall outside of it if `side` is even.
+pub fn ulam_length(side: usize) -> u64 {
+    match side {
+        0 => 0,
+        side => (2 * (side as u64 / 2) + 1).pow(2),
+    }
+}
+
+/// The `[row, column]` of the `index`-th number of an Ulam spiral in a square of `side`,
+/// starting at the centre and turning anticlockwise from the right; or [`None`] if it
+/// falls outside of the square.
+///
+/// The ring `m` around the centre holds the indices from `(2m - 1)^2` to `(2m + 1)^2 - 1`,
+/// running up its right side first, then left along its top, down its left side and
+/// right along its bottom, `2m` each.
+pub fn ulam_position(index: u64, side: usize) -> Option<(usize, usize)> {
+    let ring = isqrt(index).div_ceil(2);
+    let (x, y) = match ring {
+        0 => (0, 0),
+        ring => {
+            let offset = index - (2 * ring - 1).pow(2);
+            let (ring, step) = (ring as i64, (offset % (2 * ring)) as i64 + 1);
+
+            match offset / (2 * ring as u64) {
+                0 => (ring, step - ring),
+                1 => (ring - step, ring),
+                2 => (-ring, ring - step),
+                _ => (step - ring, -ring),
+            }
+        }
+    };
+
+    let centre = (side as i64 - 1) / 2;
+    let (row, column) = (centre - y, centre + x);
+
+    let within = |coordinate: i64| (0..side as i64).contains(&coordinate);
+
+    (within(row) && within(column)).then_some((row as usize, column as usize))
+}
+
+/// The number of numbers from `0` onwards laid out by [`sacks_position`] to fill a square
+/// of `side`.
+pub fn sacks_length(side: usize) -> u64 {
+    match side {
+        0 => 0,
+        side => ((side - 1) as f64 / 2.).powi(2).floor() as u64 + 1,
+    }
+}
+
+/// The `[row, column]` of the pixel of the number `num` on a Sacks spiral in a square of
+/// `side`; or [`None`] if it falls outside of the square.
+///
+/// The number `num` lies at the polar coordinates `(sqrt(num), 2π sqrt(num))` around the
+/// centre, turning anticlockwise from the right, so that every turn passes through the
+/// next perfect square one pixel further out.
+pub fn sacks_position(num: u64, side: usize) -> Option<(usize, usize)> {
+    let radius = (num as f64).sqrt();
+    let angle = TAU * radius.fract();
+    let centre = (side as f64 - 1.) / 2.;
+
+    let (row, column) = (
+        (centre - radius * angle.sin()).round(),
+        (centre + radius * angle.cos()).round(),
+    );
+
+    let within = |coordinate: f64| (0. ..side as f64).contains(&coordinate);
+
+    (within(row) && within(column)).then_some((row as usize, column as usize))
+}
+
+/// The values of `a k^2 + b k + c` for every `k >= 0` in `lbound..=ubound`, in the order
+/// of `k`; a constant is taken only once.
+///
+/// On either side of its vertex, a polynomial is strictly monotonic, so the `k` within
+/// the range on each side are bounded by binary search, without walking through any of
+/// the values outside of the range.
+pub fn quadratic_values(coefficients: (i64, i64, i64), lbound: u64, ubound: u64) -> Vec<u64> {
+    let (a, b, c) = (
+        coefficients.0 as i128,
+        coefficients.1 as i128,
+        coefficients.2 as i128,
+    );
+    let (lbound, ubound) = (lbound as i128, ubound as i128);
+
+    if a == 0 && b == 0 {
+        return match (lbound..=ubound).contains(&c) {
+            true => vec![c as u64],
+            false => Vec::new(),
+        };
+    }
+
+    // Beyond `i128`, the value only grows further in the direction of the leading term.
+    let overflow = match a.signum() {
+        0 => b.signum(),
+        sign => sign,
+    } * i128::MAX;
+    let value = |k: i128| {
+        a.checked_mul(k)
+            .and_then(|value| value.checked_add(b))
+            .and_then(|value| value.checked_mul(k))
+            .and_then(|value| value.checked_add(c))
+            .unwrap_or(overflow)
+    };
+
+    // The first `k` in `lower..=upper` for which a monotonic `holds` is true, or
+    // `upper + 1` if there is none.
+    let first = |lower: i128, upper: i128, holds: &dyn Fn(i128) -> bool| {
+        let (mut low, mut high) = (lower, upper + 1);
+        while low < high {
+            let middle = low + (high - low) / 2;
+            match holds(middle) {
+                true => high = middle,
+                false => low = middle + 1,
+            }
+        }
+
+        low
+    };
+
+    // The first `k` from which the polynomial is monotonic, i.e. the ceiling of its
+    // vertex `-b / 2a`.
+    let vertex = match a.signum() {
+        0 => 0,
+        sign => (-(sign * b).div_euclid(2 * a.abs())).max(0),
+    };
+    let increasing = a > 0 || (a == 0 && b > 0);
+
+    let mut values = Vec::new();
+    for (lower, upper, increasing) in [
+        (0, vertex - 1, !increasing),
+        (vertex, u64::MAX as i128, increasing),
+    ] {
+        let (start, end) = match increasing {
+            true => (
+                first(lower, upper, &|k| value(k) >= lbound),
+                first(lower, upper, &|k| value(k) > ubound),
+            ),
+            false => (
+                first(lower, upper, &|k| value(k) <= ubound),
+                first(lower, upper, &|k| value(k) < lbound),
+            ),
+        };
+
+        values.extend((start..end).map(|k| value(k) as u64));
+    }
+
+    values
+}
+
+/// Lay the numbers from `start` onwards out on a spiral of the `kind` in a square image
+/// of `side` pixels, with the primes lit up as grey levels.
+///
+/// The primes come from a [`SegmentedSieve`] over the numbers laid out. If `colour_gaps`,
+/// each prime is lit from [`GAP_FLOOR`] up to [`PRIME_LEVEL`] in proportion to the gap to
+/// the next prime, relative to the widest gap in the image. If any `polynomials`
+/// `(a, b, c)` are given, their values `a k^2 + b k + c` for `k >= 0` are highlighted:
+/// the primes among them are lit as usual while all other primes are dimmed to half, and
+/// the composites among them are lit at [`HIGHLIGHT_LEVEL`]. Where several numbers fall
+/// on the same pixel, as at the centre of a Sacks spiral, the brightest is kept.
+pub fn prime_spiral(
+    sieve: enums::SieveMethod,
+    kind: enums::SpiralKind,
+    side: usize,
+    start: u64,
+    colour_gaps: bool,
+    polynomials: &[(i64, i64, i64)],
+) -> Array2<u8> {
+    let mut image = Array2::zeros((side, side));
+    let length = kind.length(side, start);
+    if length == 0 {
+        return image;
+    }
+
+    let ubound = start.saturating_add(length - 1);
+    let primes: Vec<u64> = SegmentedSieve::new(sieve, ubound)
+        .par_segments(start, ubound)
+        .flat_map_iter(|(segment_start, mask)| {
+            mask.iter()
+                .enumerate()
+                .filter(|(_, is_prime)| is_prime.is_true())
+                .map(|(index, _)| segment_start + index as u64)
+                .collect::<Vec<u64>>()
+        })
+        .collect();
+
+    let mut highlighted = Array2::from_elem((side, side), false);
+    for &coefficients in polynomials {
+        for value in quadratic_values(coefficients, start, ubound) {
+            if let Some(position) = kind.position(value, start, side) {
+                highlighted[position] = true;
+                image[position] = image[position].max(HIGHLIGHT_LEVEL);
+            }
+        }
+    }
+
+    let gaps: Vec<u64> = match (colour_gaps, primes.last()) {
+        (true, Some(&last)) => {
+            let next = (last + 1..).find(|&num| miller_rabin(num));
+
+            primes
+                .windows(2)
+                .map(|pair| pair[1] - pair[0])
+                .chain(next.map(|next| next - last))
+                .collect()
+        }
+        _ => Vec::new(),
+    };
+    let widest = gaps.iter().copied().max().unwrap_or(1);
+
+    let lit: Vec<((usize, usize), u8)> = primes
+        .par_iter()
+        .enumerate()
+        .filter_map(|(index, &prime)| {
+            let position = kind.position(prime, start, side)?;
+            let level = match gaps.get(index) {
+                Some(&gap) => GAP_FLOOR + ((PRIME_LEVEL - GAP_FLOOR) as u64 * gap / widest) as u8,
+                None => PRIME_LEVEL,
+            };
+
+            match polynomials.is_empty() || highlighted[position] {
+                true => Some((position, level)),
+                false => Some((position, level / 2)),
+            }
+        })
+        .collect();
+
+    for (position, level) in lit {
+        image[position] = image[position].max(level);
+    }
+
+    image
+}
+
+/// Write a grey `image` to `writer` as a binary PGM, i.e. a header `P5` with its width,
+/// height and maximum level, followed by a byte for each pixel row by row.
+pub fn write_pgm(image: &Array2<u8>, mut writer: impl Write) -> io::Result<()> {
+    let (height, width) = image.dim();
+
+    write!(writer, "P5\n{} {}\n255\n", width, height)?;
+    writer.write_all(
+        image
+            .as_standard_layout()
+            .as_slice()
+            .expect("standard layout"),
+    )?;
+
+    writer.flush()
+}
+
+/// Write a grey `image` to `writer` as a PNG, with a bit depth of `8`.
+pub fn write_png(image: &Array2<u8>, writer: impl Write) -> io::Result<()> {
+    let (height, width) = image.dim();
+    let too_large = |_| io::Error::new(io::ErrorKind::InvalidInput, "image too large for PNG");
+
+    let mut encoder = png::Encoder::new(
+        writer,
+        u32::try_from(width).map_err(too_large)?,
+        u32::try_from(height).map_err(too_large)?,
+    );
+    encoder.set_color(png::ColorType::Grayscale);
+    encoder.set_depth(png::BitDepth::Eight);
+
+    let mut png_writer = encoder.write_header()?;
+    png_writer.write_image_data(
+        image
+            .as_standard_layout()
+            .as_slice()
+            .expect("standard layout"),
+    )?;
+
+    Ok(png_writer.finish()?)
+}
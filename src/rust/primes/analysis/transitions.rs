@@ -0,0 +1,82 @@
+use ndarray::Array2;
+use rayon::prelude::*;
+
+use crate::primes::constructs::*;
+use crate::primes::SegmentedSieve;
+use crate::py_compatibility::enums;
+
+/// Count the transitions between the residues of consecutive primes up to and including
+/// `ubound`, modulo `modulus`.
+///
+/// The value at `[i, j]` of the returned matrix is the number of consecutive primes
+/// `p(n), p(n + 1) <= ubound` with `p(n) % modulus == i` and
+/// `p(n + 1) % modulus == j`. Lemke Oliver and Soundararajan observed that the
+/// diagonal is noticeably under-represented.
+///
+/// The range is split by a [`SegmentedSieve`], where each segment is counted in
+/// parallel. Only the first and last primes of each segment are kept for stitching the
+/// transitions across the segment boundaries, so memory stays constant in `ubound`.
+pub fn residue_transitions(sieve: enums::SieveMethod, ubound: u64, modulus: u64) -> Array2<u64> {
+    let segmented = SegmentedSieve::new(sieve, ubound);
+
+    segmented
+        .par_segments(0, ubound)
+        .map(|(lbound, mask)| {
+            let mut transitions = Transitions::empty(modulus);
+
+            mask.iter()
+                .enumerate()
+                .filter(|(_, is_prime)| is_prime.is_true())
+                .for_each(|(offset, _)| transitions.push(lbound + offset as u64));
+
+            transitions
+        })
+        .reduce(|| Transitions::empty(modulus), |lhs, rhs| lhs.concat(rhs))
+        .counts
+}
+
+/// Transition counts over a run of consecutive primes, along with the first and last
+/// prime of the run.
+struct Transitions {
+    modulus: u64,
+    first: Option<u64>,
+    last: Option<u64>,
+    counts: Array2<u64>,
+}
+impl Transitions {
+    fn empty(modulus: u64) -> Self {
+        Self {
+            modulus,
+            first: None,
+            last: None,
+            counts: Array2::zeros((modulus as usize, modulus as usize)),
+        }
+    }
+
+    fn count(&mut self, from: u64, to: u64) {
+        self.counts[[(from % self.modulus) as usize, (to % self.modulus) as usize]] += 1;
+    }
+
+    /// Append the next prime to the run.
+    fn push(&mut self, prime: u64) {
+        if let Some(last) = self.last {
+            self.count(last, prime);
+        }
+
+        self.first.get_or_insert(prime);
+        self.last = Some(prime);
+    }
+
+    /// Join two runs, where `self` comes immediately before `other`.
+    fn concat(mut self, other: Self) -> Self {
+        if let (Some(last), Some(first)) = (self.last, other.first) {
+            self.count(last, first);
+        }
+
+        self.counts += &other.counts;
+        self.first = self.first.or(other.first);
+        self.last = other.last.or(self.last);
+
+        self
+    }
+}
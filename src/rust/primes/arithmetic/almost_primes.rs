@@ -0,0 +1,95 @@
+use rayon::prelude::*;
+
+use super::omega::prime_omegas_with;
+use crate::primes::constructs::*;
+use crate::primes::formulas::{iroot, isqrt};
+use crate::primes::summation::LucyTable;
+use crate::py_compatibility::enums;
+
+/// The number of integers in each window of [`prime_omegas_with`] while listing.
+const WINDOW_SIZE: u64 = 1 << 22;
+
+/// List the `k`-almost primes in `lbound..=ubound` in ascending order, i.e. the numbers
+/// with exactly `k` prime factors counted with multiplicity.
+///
+/// `Omega(n)` is sieved by [`prime_omegas_with`] over windows of the range in parallel, so
+/// that the memory of the sieve stays bounded however large the range. The primes up to
+/// `sqrt(ubound)` are listed once, and shared by every window.
+pub fn list_almost_primes(sieve: enums::SieveMethod, k: u32, lbound: u64, ubound: u64) -> Vec<u64> {
+    // `0` has no factorisation, while the windows hold `Omega(0) = 0` by convention.
+    let lbound = lbound.max(1);
+    let window_count = match lbound > ubound {
+        true => 0,
+        false => (ubound - lbound) / WINDOW_SIZE + 1,
+    };
+    let base_primes = match window_count {
+        0 => Vec::new(),
+        _ => sieve.list_primes(isqrt(ubound), None),
+    };
+
+    (0..window_count)
+        .into_par_iter()
+        .flat_map_iter(|index| {
+            let start = lbound + index * WINDOW_SIZE;
+            let end = ubound.min(start.saturating_add(WINDOW_SIZE - 1));
+
+            prime_omegas_with(&base_primes, start, end)
+                .big_omega
+                .iter()
+                .zip(start..)
+                .filter(|(&big_omega, _)| big_omega as u32 == k)
+                .map(|(_, num)| num)
+                .collect::<Vec<u64>>()
+        })
+        .collect()
+}
+
+/// The number of `k`-almost primes up to `quotient`, whose prime factors are all at
+/// least `primes[start]`.
+///
+/// The smallest factor `p` is chosen in ascending order while `p^k <= quotient`, and the
+/// rest counted recursively up to `quotient / p`; a single factor left is counted by the
+/// prime counts of the [`LucyTable`], less the `start` primes below `primes[start]`.
+fn count_from(primes: &[u64], table: &LucyTable, k: u32, quotient: u64, start: usize) -> u64 {
+    if k == 1 {
+        return (table.get(quotient) as u64).saturating_sub(start as u64);
+    }
+
+    let max_factor = iroot(quotient, k);
+
+    primes[start..]
+        .iter()
+        .take_while(|&&prime| prime <= max_factor)
+        .enumerate()
+        .map(|(offset, &prime)| count_from(primes, table, k - 1, quotient / prime, start + offset))
+        .sum()
+}
+
+/// Count the `k`-almost primes up to and including `num`, i.e. the numbers with exactly
+/// `k` prime factors counted with multiplicity, in `O(num^(3/4))` for small `k`.
+///
+/// Writing such a number as `p_1 * ... * p_k` with `p_1 <= ... <= p_k`, every choice of
+/// the first `k - 1` factors leaves `π(num / (p_1 * ... * p_(k - 1)))` less the primes
+/// below `p_(k - 1)` for the last. Every such quotient is of the form `num / i`, so all
+/// the prime counts come from a single [`LucyTable`], which only needs the primes up to
+/// `sqrt(num)`. The choices of the smallest factor are counted in parallel.
+pub fn count_almost_primes(sieve: enums::SieveMethod, k: u32, num: u64) -> u64 {
+    if k == 0 {
+        return (num >= 1) as u64;
+    }
+
+    let primes = sieve.list_primes(isqrt(num), None);
+    let table = LucyTable::new(&primes, num, 0).expect("prime counts always fit into u128");
+
+    if k == 1 {
+        return table.get(num) as u64;
+    }
+
+    let max_factor = iroot(num, k);
+    let candidates = primes.partition_point(|&prime| prime <= max_factor);
+
+    (0..candidates)
+        .into_par_iter()
+        .map(|index| count_from(&primes, &table, k - 1, num / primes[index], index))
+        .sum()
+}
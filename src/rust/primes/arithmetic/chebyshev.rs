@@ -0,0 +1,76 @@
+use ndarray::Array;
+
+use crate::primes::constructs::*;
+use crate::py_compatibility::enums;
+
+/// Returns an [`Array`] of [`f64`] containing the von Mangoldt function `Lambda(k)` at
+/// each index `k`, for all `k` up to and including `ubound`.
+///
+/// `Lambda(k)` is `ln(p)` if `k` is a power of a prime `p`, and `0` otherwise.
+pub fn von_mangoldt_sieve(sieve: enums::SieveMethod, ubound: u64) -> OwnedSieve<f64> {
+    von_mangoldt_sieve_with_mask(&sieve.sieve(ubound))
+}
+
+/// Perform the von Mangoldt sieve over an existing prime mask.
+pub fn von_mangoldt_sieve_with_mask(mask: &NonAtomicSieve) -> OwnedSieve<f64> {
+    let mut lambda: OwnedSieve<f64> = Array::zeros((mask.len(),));
+
+    for prime in mask.collect_into_primes(None) {
+        let log = (prime as f64).ln();
+
+        let mut power = prime as usize;
+        while power < lambda.len() {
+            lambda[power] = log;
+
+            power = match power.checked_mul(prime as usize) {
+                Some(next_power) => next_power,
+                None => break,
+            };
+        }
+    }
+
+    lambda
+}
+
+/// Calculate the first Chebyshev function `theta(num)`, the sum of `ln(p)` over all
+/// primes `p <= num`.
+///
+/// The logarithms are summed with a [`KahanSum`], so the error stays at the order of a
+/// single rounding regardless of `num`.
+pub fn chebyshev_theta(sieve: enums::SieveMethod, num: u64) -> f64 {
+    let mut total = KahanSum::new();
+
+    total.extend(
+        sieve
+            .sieve(num)
+            .iter()
+            .enumerate()
+            .filter(|(_, is_prime)| is_prime.is_true())
+            .map(|(prime, _)| (prime as f64).ln()),
+    );
+
+    total.total()
+}
+
+/// Calculate the second Chebyshev function `psi(num)`, the sum of `Lambda(k)` over all
+/// `k <= num`.
+///
+/// Each prime `p` contributes `ln(p)` once for every power of `p` up to `num`; these
+/// are summed with a [`KahanSum`].
+pub fn chebyshev_psi(sieve: enums::SieveMethod, num: u64) -> f64 {
+    let mut total = KahanSum::new();
+
+    total.extend(
+        sieve
+            .sieve(num)
+            .iter()
+            .enumerate()
+            .filter(|(_, is_prime)| is_prime.is_true())
+            .map(|(prime, _)| {
+                let prime = prime as u64;
+                (prime as f64).ln() * num.ilog(prime) as f64
+            }),
+    );
+
+    total.total()
+}
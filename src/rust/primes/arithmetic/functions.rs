@@ -0,0 +1,82 @@
+//! Common multiplicative functions, implemented through [`MultiplicativeFn`].
+
+use crate::primes::constructs::{MultiplicativeFn, PrimePolynomialFn};
+
+/// Euler's totient `phi(n)`.
+pub struct EulerTotient;
+impl MultiplicativeFn for EulerTotient {
+    type Output = u64;
+
+    fn at_prime_power(&self, prime: u64, exponent: u32) -> u64 {
+        prime.pow(exponent - 1) * (prime - 1)
+    }
+}
+
+impl PrimePolynomialFn for EulerTotient {
+    fn prime_polynomial(&self) -> Vec<i128> {
+        vec![-1, 1]
+    }
+}
+
+/// The Möbius function `mu(n)`.
+pub struct Mobius;
+impl MultiplicativeFn for Mobius {
+    type Output = i64;
+
+    fn at_prime_power(&self, _prime: u64, exponent: u32) -> i64 {
+        if exponent == 1 {
+            -1
+        } else {
+            0
+        }
+    }
+}
+
+impl PrimePolynomialFn for Mobius {
+    fn prime_polynomial(&self) -> Vec<i128> {
+        vec![-1]
+    }
+}
+
+/// The Liouville function `lambda(n)`.
+pub struct Liouville;
+impl MultiplicativeFn for Liouville {
+    type Output = i64;
+
+    fn at_prime_power(&self, _prime: u64, exponent: u32) -> i64 {
+        if exponent.is_multiple_of(2) {
+            1
+        } else {
+            -1
+        }
+    }
+}
+
+impl PrimePolynomialFn for Liouville {
+    fn prime_polynomial(&self) -> Vec<i128> {
+        vec![-1]
+    }
+}
+
+/// The divisor function `sigma_k(n)`, the sum of the `k`-th powers of all divisors of
+/// `n`. `DivisorSigma(0)` counts the divisors, and `DivisorSigma(1)` sums them.
+pub struct DivisorSigma(pub u32);
+impl MultiplicativeFn for DivisorSigma {
+    type Output = u64;
+
+    fn at_prime_power(&self, prime: u64, exponent: u32) -> u64 {
+        let base = prime.pow(self.0);
+
+        (0..=exponent).map(|power| base.pow(power)).sum()
+    }
+}
+impl PrimePolynomialFn for DivisorSigma {
+    fn prime_polynomial(&self) -> Vec<i128> {
+        // sigma_k(p) = 1 + p^k
+        let mut coefficients = vec![0; self.0 as usize + 1];
+        coefficients[0] += 1;
+        coefficients[self.0 as usize] += 1;
+
+        coefficients
+    }
+}
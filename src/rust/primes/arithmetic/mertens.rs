@@ -0,0 +1,73 @@
+use std::cmp;
+
+use super::mobius_sieve;
+use crate::primes::formulas::icbrt;
+use crate::py_compatibility::enums;
+
+/// The largest Möbius sieve that [`mertens`] will build for its small values.
+///
+/// The prefix sums are kept as [`i32`], so this caps the memory used at around 160MB.
+pub const MAX_MERTENS_SIEVE_SIZE: u64 = 2_u64.pow(25);
+
+/// Calculate the Mertens function `M(num)`, the sum of `mu(k)` for all `k` up to and
+/// including `num`.
+///
+/// This is the plain recursion of `M` over a sieved prefix, in around `O(num^(2/3))`:
+///
+/// - `M(v)` for all `v` up to around `num^(2/3)` are taken from the prefix sums of
+///   [`mobius_sieve`], using the given `sieve` method;
+/// - `M(num / k)` for the remaining large values are then resolved in descending `v`
+///   through the identity
+///
+/// ```text
+/// M(v) = 1 - sum(M(v / d) for d in 2..=v)
+/// ```
+///
+///   where the terms are grouped by the distinct values of `v / d`, so each `v` only
+///   costs `O(sqrt(v))`.
+pub fn mertens(sieve: enums::SieveMethod, num: u64) -> i64 {
+    let cbrt = icbrt(num);
+    let small_bound = (cbrt * cbrt).clamp(1, MAX_MERTENS_SIEVE_SIZE);
+
+    let small = mobius_sieve(sieve, cmp::min(small_bound, num))
+        .iter()
+        .scan(0_i32, |total, &mu| {
+            *total += mu as i32;
+            Some(*total)
+        })
+        .collect::<Vec<i32>>();
+
+    if num < small.len() as u64 {
+        return small[num as usize] as i64;
+    }
+
+    let small_bound = small.len() as u64 - 1;
+    let large_count = (num / (small_bound + 1)) as usize;
+
+    // large[k] = M(num / k), for all `k` where `num / k` is above `small_bound`.
+    let mut large = vec![0_i64; large_count + 1];
+
+    for k in (1..=large_count).rev() {
+        let value = num / k as u64;
+        let mut result = 1_i64;
+        let mut divisor = 2_u64;
+
+        while divisor <= value {
+            let quotient = value / divisor;
+            let divisor_max = value / quotient;
+
+            let mertens_of_quotient = if quotient <= small_bound {
+                small[quotient as usize] as i64
+            } else {
+                large[k * divisor as usize]
+            };
+
+            result -= (divisor_max - divisor + 1) as i64 * mertens_of_quotient;
+            divisor = divisor_max + 1;
+        }
+
+        large[k] = result;
+    }
+
+    large[1]
+}
@@ -0,0 +1,40 @@
+use ndarray::{s, Array};
+
+use crate::primes::constructs::*;
+use crate::py_compatibility::enums;
+
+/// Returns an [`Array`] of [`i8`] containing the Möbius function `mu(k)` at each index
+/// `k`, for all `k` up to and including `ubound`.
+///
+/// By convention `mu(0) == 0`.
+pub fn mobius_sieve(sieve: enums::SieveMethod, ubound: u64) -> OwnedSieve<i8> {
+    mobius_sieve_with_mask(&sieve.sieve(ubound))
+}
+
+/// Perform the Möbius sieve over an existing prime mask.
+///
+/// Starting from `mu(k) = 1`, every prime in `mask` flips the sign of its multiples,
+/// then zeros all the multiples of its square. Both are done on a `slice_mut` over
+/// steps, in the same way as [`SieveOfEratosthenes`].
+///
+/// [`SieveOfEratosthenes`]: crate::primes::SieveOfEratosthenes
+pub fn mobius_sieve_with_mask(mask: &NonAtomicSieve) -> OwnedSieve<i8> {
+    let mut mu: OwnedSieve<i8> = Array::from_elem((mask.len(),), 1);
+
+    if let Some(zero) = mu.get_mut(0) {
+        *zero = 0;
+    }
+
+    for prime in mask.collect_into_primes(None) {
+        let prime = prime as usize;
+
+        mu.slice_mut(s![prime..; prime])
+            .mapv_inplace(|value| -value);
+
+        if let Some(square) = prime.checked_mul(prime).filter(|&square| square < mu.len()) {
+            mu.slice_mut(s![square..; square]).fill(0);
+        }
+    }
+
+    mu
+}
@@ -0,0 +1,23 @@
+//! Arithmetic functions built on top of the prime sieves.
+
+pub mod almost_primes;
+pub mod chebyshev;
+pub mod functions;
+pub mod mertens;
+pub mod mobius;
+pub mod omega;
+pub mod smooth;
+pub mod totient;
+
+pub use almost_primes::{count_almost_primes, list_almost_primes};
+pub use chebyshev::{
+    chebyshev_psi, chebyshev_theta, von_mangoldt_sieve, von_mangoldt_sieve_with_mask,
+};
+pub use functions::{DivisorSigma, EulerTotient, Liouville, Mobius};
+pub use mertens::mertens;
+pub use mobius::{mobius_sieve, mobius_sieve_with_mask};
+pub use omega::{prime_omegas, prime_omegas_with, PrimeOmegas};
+pub use smooth::{
+    count_smooth_numbers, dickman_rho, estimate_smooth_numbers, rough_numbers, smooth_numbers,
+};
+pub use totient::{totient, totient_sieve, totient_sieve_with_mask};
@@ -0,0 +1,107 @@
+use ndarray::{s, Array, Zip};
+
+use crate::primes::constructs::*;
+use crate::primes::formulas::isqrt;
+use crate::py_compatibility::enums;
+
+/// The prime omega functions over a window of consecutive integers
+/// `lbound..=ubound`, where index `i` of each array corresponds to `lbound + i`.
+///
+/// By convention, both functions are `0` at `0` and `1`.
+pub struct PrimeOmegas {
+    pub lbound: u64,
+
+    /// `omega(n)`, the number of distinct prime factors of `n`.
+    pub omega: OwnedSieve<u8>,
+
+    /// `Omega(n)`, the number of prime factors of `n` counted with multiplicity.
+    pub big_omega: OwnedSieve<u8>,
+}
+impl PrimeOmegas {
+    /// The Liouville function `lambda(n) = (-1)^Omega(n)` over the same window.
+    ///
+    /// By convention `lambda(0) == 0`.
+    pub fn liouville(&self) -> OwnedSieve<i8> {
+        Array::from_iter(
+            (self.lbound..)
+                .zip(self.big_omega.iter())
+                .map(|(num, &big_omega)| match num {
+                    0 => 0,
+                    _ if big_omega % 2 == 0 => 1,
+                    _ => -1,
+                }),
+        )
+    }
+}
+
+/// Calculate `omega(n)` and `Omega(n)` for every `n` in `lbound..=ubound`.
+///
+/// Only the primes up to `sqrt(ubound)` are required from `sieve`. Each of them strides
+/// through its own multiples in the window, then through the multiples of each of its
+/// powers, dividing itself out of a running remainder. Anything left in the remainder
+/// at the end is a single prime factor above `sqrt(ubound)`.
+pub fn prime_omegas(sieve: enums::SieveMethod, lbound: u64, ubound: u64) -> PrimeOmegas {
+    prime_omegas_with(&sieve.list_primes(isqrt(ubound), None), lbound, ubound)
+}
+
+/// Calculate `omega(n)` and `Omega(n)` for every `n` in `lbound..=ubound`, as
+/// [`prime_omegas`] does, with `base_primes` listing at least every prime up to
+/// `sqrt(ubound)`, so that they can be shared between windows.
+pub fn prime_omegas_with(base_primes: &[u64], lbound: u64, ubound: u64) -> PrimeOmegas {
+    let mut remainder: OwnedSieve<u64> = Array::from_iter(lbound..=ubound);
+    let mut omega: OwnedSieve<u8> = Array::zeros((remainder.len(),));
+    let mut big_omega: OwnedSieve<u8> = Array::zeros((remainder.len(),));
+
+    let max_prime = isqrt(ubound);
+    for &prime in base_primes.iter().take_while(|&&prime| prime <= max_prime) {
+        let mut power = prime;
+
+        // The first non-zero multiple of `power` in the window.
+        while let Some(first) = first_multiple_from(lbound, power).filter(|&first| first <= ubound)
+        {
+            // A power beyond the window has at most one multiple in it, and would not fit
+            // into the `isize` step of a slice.
+            let step = power.min(ubound - lbound + 1) as usize;
+            let stride = s![(first - lbound) as usize..; step];
+
+            if power == prime {
+                let mut omega_slice = omega.slice_mut(stride);
+                omega_slice += 1;
+            }
+
+            let mut big_omega_slice = big_omega.slice_mut(stride);
+            big_omega_slice += 1;
+
+            remainder
+                .slice_mut(stride)
+                .mapv_inplace(|value| value / prime);
+
+            power = match power.checked_mul(prime) {
+                Some(next_power) if next_power <= ubound => next_power,
+                _ => break,
+            };
+        }
+    }
+
+    Zip::from(&remainder)
+        .and(&mut omega)
+        .and(&mut big_omega)
+        .for_each(|&value, omega, big_omega| {
+            if value > 1 {
+                *omega += 1;
+                *big_omega += 1;
+            }
+        });
+
+    PrimeOmegas {
+        lbound,
+        omega,
+        big_omega,
+    }
+}
+
+/// The smallest multiple of `factor` that is no less than `lbound`, skipping `0`, if it
+/// is within `u64`.
+fn first_multiple_from(lbound: u64, factor: u64) -> Option<u64> {
+    lbound.div_ceil(factor).max(1).checked_mul(factor)
+}
@@ -0,0 +1,286 @@
+use std::f32::consts::LN_2;
+
+use ndarray::{s, Array};
+use rayon::prelude::*;
+
+use crate::primes::constructs::*;
+use crate::primes::formulas::isqrt;
+use crate::primes::summation::LucyTable;
+use crate::py_compatibility::enums;
+
+/// The number of integers in each window while listing.
+const WINDOW_SIZE: u64 = 1 << 20;
+
+/// The number of grid points per unit of `u` for [`dickman_rho`].
+const RHO_STEPS: usize = 4096;
+
+/// The Euler-Mascheroni constant.
+const EULER_GAMMA: f64 = 0.5772156649015329;
+
+/// Split `lbound..=ubound` into windows of [`WINDOW_SIZE`], and collect the numbers of
+/// each that satisfy `filter` in parallel.
+fn filter_windows(
+    lbound: u64,
+    ubound: u64,
+    filter: impl Fn(u64, u64) -> Vec<u64> + Sync,
+) -> Vec<u64> {
+    let window_count = match lbound > ubound {
+        true => 0,
+        false => (ubound - lbound) / WINDOW_SIZE + 1,
+    };
+
+    (0..window_count)
+        .into_par_iter()
+        .flat_map_iter(|index| {
+            let start = lbound + index * WINDOW_SIZE;
+
+            filter(start, ubound.min(start.saturating_add(WINDOW_SIZE - 1)))
+        })
+        .collect()
+}
+
+/// The offset of the smallest non-zero multiple of `factor` from `start`, if it is no
+/// larger than `end`, and so within `u64`.
+fn first_multiple(start: u64, end: u64, factor: u64) -> Option<usize> {
+    let first = start.div_ceil(factor).max(1).checked_mul(factor)?;
+
+    (first <= end).then_some((first - start) as usize)
+}
+
+/// List the `bound`-smooth numbers in `lbound..=ubound` in ascending order, i.e. the
+/// positive numbers without any prime factor above `bound`.
+///
+/// Each window accumulates `ln(p)` at the multiples of every power of every prime
+/// `p <= bound`, so that each number ends up with the logarithm of its `bound`-smooth
+/// part. Any other part is at least `2`, so a number is smooth exactly when the sum is
+/// within `ln(2) / 2` of its own logarithm, halfway to the closest a non-smooth number
+/// can come, which leaves a margin well beyond the rounding of [`f32`] on either side.
+pub fn smooth_numbers(sieve: enums::SieveMethod, bound: u64, lbound: u64, ubound: u64) -> Vec<u64> {
+    let primes = sieve.list_primes(bound.min(ubound), None);
+
+    filter_windows(lbound.max(1), ubound, |start, end| {
+        let mut logarithms = Array::<f32, _>::zeros(((end - start + 1) as usize,));
+
+        for &prime in &primes {
+            let logarithm = (prime as f32).ln();
+            let mut power = prime;
+
+            while let Some(offset) = first_multiple(start, end, power) {
+                // A power beyond the window has at most one multiple in it, and would not
+                // fit into the `isize` step of a slice.
+                let step = power.min(end - start + 1) as usize;
+                let mut slice = logarithms.slice_mut(s![offset..; step]);
+                slice += logarithm;
+
+                power = match power.checked_mul(prime) {
+                    Some(next_power) if next_power <= end => next_power,
+                    _ => break,
+                };
+            }
+        }
+
+        (start..=end)
+            .zip(logarithms.iter())
+            // Halfway between the logarithm of a smooth number, and the at most
+            // `ln(num) - ln(2)` of a non-smooth one.
+            .filter(|&(num, &logarithm)| logarithm >= (num as f32).ln() - LN_2 / 2.)
+            .map(|(num, _)| num)
+            .collect()
+    })
+}
+
+/// List the `bound`-rough numbers in `lbound..=ubound` in ascending order, i.e. the
+/// positive numbers without any prime factor up to `bound`.
+///
+/// Every multiple of every prime `p <= bound` is struck off each window, as in a
+/// segmented sieve that stops at `bound`.
+pub fn rough_numbers(sieve: enums::SieveMethod, bound: u64, lbound: u64, ubound: u64) -> Vec<u64> {
+    let primes = sieve.list_primes(bound.min(ubound), None);
+
+    filter_windows(lbound.max(1), ubound, |start, end| {
+        let mut mask = Array::from_elem(((end - start + 1) as usize,), true);
+
+        for &prime in &primes {
+            if let Some(offset) = first_multiple(start, end, prime) {
+                mask.slice_mut(s![offset..; prime as usize]).fill(false);
+            }
+        }
+
+        (start..=end)
+            .zip(mask.iter())
+            .filter(|(_, &is_rough)| is_rough)
+            .map(|(num, _)| num)
+            .collect()
+    })
+}
+
+/// The largest `bound^2` below `num` for which the prime counts of [`count_smooth_numbers`]
+/// are looked up in a list of primes, rather than in a [`LucyTable`] of `num`.
+const LISTED_COUNTS_LIMIT: u64 = 1 << 26;
+
+/// The prime counts for [`count_smooth_numbers`], at values of the form `num / i` up to
+/// `min(bound^2, num)`.
+enum PrimeCounts {
+    /// Every prime up to `bound^2`, ranked by binary search.
+    Listed(Vec<u64>),
+
+    /// The prime counts at every `num / i`.
+    Lucy(LucyTable),
+}
+impl PrimeCounts {
+    fn new(sieve: enums::SieveMethod, bound: u64, num: u64) -> Self {
+        match bound.checked_mul(bound) {
+            Some(square) if square < num && square <= LISTED_COUNTS_LIMIT => {
+                Self::Listed(sieve.list_primes(square, None))
+            }
+            _ => Self::Lucy(
+                LucyTable::new(&sieve.list_primes(isqrt(num), None), num, 0)
+                    .expect("prime counts always fit into u128"),
+            ),
+        }
+    }
+
+    fn get(&self, value: u64) -> u64 {
+        match self {
+            Self::Listed(primes) => primes.partition_point(|&prime| prime <= value) as u64,
+            Self::Lucy(table) => table.get(value) as u64,
+        }
+    }
+}
+
+/// `Ψ(quotient, primes[count - 1])`, the number of positive numbers up to `quotient`
+/// without any prime factor beyond the first `count` of `primes`.
+fn count_smooth_from(primes: &[u64], counts: &PrimeCounts, quotient: u64, count: usize) -> u64 {
+    if count == 0 || quotient <= 1 {
+        return quotient.min(1);
+    }
+
+    let largest = primes[count - 1];
+    if largest >= quotient {
+        return quotient;
+    }
+    // Only the powers of `2`.
+    if count == 1 {
+        return quotient.ilog2() as u64 + 1;
+    }
+
+    // Each number up to `quotient` has at most one prime factor `r` above
+    // `sqrt(quotient)`, so the non-smooth ones are counted by `quotient / r`, grouped by
+    // its value.
+    if largest * largest >= quotient {
+        let rough = (1..=quotient / (largest + 1))
+            .map(|multiple| {
+                let below = match quotient / (multiple + 1) > largest {
+                    true => counts.get(quotient / (multiple + 1)),
+                    false => count as u64,
+                };
+
+                multiple * (counts.get(quotient / multiple) - below)
+            })
+            .sum::<u64>();
+
+        return quotient - rough;
+    }
+
+    1 + (0..count)
+        .map(|index| count_smooth_from(primes, counts, quotient / primes[index], index + 1))
+        .sum::<u64>()
+}
+
+/// Count the `bound`-smooth numbers up to and including `num`, de Bruijn's
+/// `Ψ(num, bound)`, exactly.
+///
+/// By the Buchstab identity `Ψ(x, y) = 1 + Σ_{p <= y} Ψ(x / p, p)`, where each smooth
+/// number above `1` is counted by its largest prime factor `p`. Once `p^2 >= x`, the
+/// numbers up to `x` with a prime factor above `p` are counted directly from the prime
+/// counts at `x / k` instead; these are all of the form `num / i`, so come from a
+/// [`LucyTable`], or a list of the primes up to `bound^2` if that is smaller. The
+/// largest prime factors are counted in parallel.
+pub fn count_smooth_numbers(sieve: enums::SieveMethod, bound: u64, num: u64) -> u64 {
+    let primes = sieve.list_primes(bound.min(num), None);
+
+    match primes.last() {
+        None => num.min(1),
+        Some(_) if bound >= num => num,
+        Some(&largest) => {
+            let counts = PrimeCounts::new(sieve, largest, num);
+
+            1 + (0..primes.len())
+                .into_par_iter()
+                .map(|index| count_smooth_from(&primes, &counts, num / primes[index], index + 1))
+                .sum::<u64>()
+        }
+    }
+}
+
+/// The Dickman function `ρ(u)`, the limit of `Ψ(x, x^(1/u)) / x`.
+///
+/// `ρ(u) = 1` up to `1` and `1 - ln(u)` up to `2`. Beyond that, `u ρ(u)` is the integral
+/// of `ρ` over `[u - 1, u]`, which is taken by the trapezoidal rule on a grid of
+/// [`RHO_STEPS`] points per unit, with `ln(ρ)` interpolated linearly between them. Every
+/// term is positive, so the relative error stays small however tiny `ρ(u)` becomes.
+pub fn dickman_rho(u: f64) -> f64 {
+    match u {
+        u if u < 0. => return 0.,
+        u if u <= 1. => return 1.,
+        u if u <= 2. => return 1. - u.ln(),
+        _ => {}
+    }
+
+    let step = 1. / RHO_STEPS as f64;
+    let position = u * RHO_STEPS as f64;
+    let last = position.ceil() as usize;
+
+    let mut rho: Vec<f64> = (0..=2 * RHO_STEPS)
+        .map(|index| match index <= RHO_STEPS {
+            true => 1.,
+            false => 1. - (index as f64 * step).ln(),
+        })
+        .collect();
+    // The sum of `ρ` strictly inside the unit below the next grid point.
+    let mut interior: f64 = rho[RHO_STEPS + 2..].iter().sum();
+
+    for index in 2 * RHO_STEPS + 1..=last {
+        // Summed afresh every unit, so that the rounding of the running sum stays small
+        // next to `ρ` as it falls.
+        if index % RHO_STEPS == 0 {
+            interior = rho[index + 1 - RHO_STEPS..index].iter().sum();
+        }
+
+        let t = index as f64 * step;
+        let value = step * (rho[index - RHO_STEPS] / 2. + interior) / (t - step / 2.);
+
+        interior += value - rho[index + 1 - RHO_STEPS];
+        rho.push(value);
+    }
+
+    let lower = position.floor() as usize;
+    let fraction = position - lower as f64;
+
+    match lower == last {
+        true => rho[last],
+        false => (rho[lower].ln() * (1. - fraction) + rho[last].ln() * fraction).exp(),
+    }
+}
+
+/// Estimate `Ψ(num, bound)`, the number of `bound`-smooth numbers up to `num`, by
+/// `x (ρ(u) + (1 - γ) ρ(u - 1) / ln(x))` with `u = ln(x) / ln(y)`.
+///
+/// This is de Bruijn's expansion of `Ψ(x, y)` up to its second term; by Hildebrand, the
+/// first term alone is asymptotic to it whenever `y > exp((ln ln x)^(5/3 + ε))`.
+pub fn estimate_smooth_numbers(bound: u64, num: u64) -> f64 {
+    if num < 1 {
+        return 0.;
+    }
+    if bound < 2 {
+        return 1.;
+    }
+    if bound >= num {
+        return num as f64;
+    }
+
+    let (x, y) = (num as f64, bound as f64);
+    let u = x.ln() / y.ln();
+
+    x * (dickman_rho(u) + (1. - EULER_GAMMA) * dickman_rho(u - 1.) / x.ln())
+}
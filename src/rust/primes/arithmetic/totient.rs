@@ -0,0 +1,62 @@
+use ndarray::{s, Array};
+
+use crate::primes::constructs::*;
+use crate::primes::formulas::isqrt;
+use crate::py_compatibility::enums;
+
+/// Calculate Euler's totient `phi(num)`, the number of integers in `1..=num` that are
+/// coprime to `num`.
+///
+/// The prime factors of `num` are found by trial division using the primes up to
+/// `sqrt(num)` from `sieve`. By convention `phi(0) == 0`.
+pub fn totient(sieve: enums::SieveMethod, num: u64) -> u64 {
+    let mut remainder = num;
+    let mut result = num;
+
+    for prime in sieve.list_primes(isqrt(num), None) {
+        if prime * prime > remainder {
+            break;
+        }
+
+        if remainder.is_multiple_of(prime) {
+            while remainder.is_multiple_of(prime) {
+                remainder /= prime;
+            }
+            result -= result / prime;
+        }
+    }
+
+    // Anything left over is a prime factor larger than `sqrt(num)`.
+    if remainder > 1 {
+        result -= result / remainder;
+    }
+
+    result
+}
+
+/// Returns an [`Array`] of [`u64`] containing `phi(k)` at each index `k`, for all `k`
+/// up to and including `ubound`.
+pub fn totient_sieve(sieve: enums::SieveMethod, ubound: u64) -> OwnedSieve<u64> {
+    totient_sieve_with_mask(&sieve.sieve(ubound))
+}
+
+/// Perform the totient sieve over an existing prime mask.
+///
+/// Starting from `phi(k) = k`, every prime in `mask` multiplies each of its multiples
+/// by `(p - 1) / p`. As with [`SieveOfEratosthenes`], this is done on a
+/// `slice_mut` over steps of `p`, which the compiler can optimise well.
+///
+/// [`SieveOfEratosthenes`]: crate::primes::SieveOfEratosthenes
+pub fn totient_sieve_with_mask(mask: &NonAtomicSieve) -> OwnedSieve<u64> {
+    let mut phi: OwnedSieve<u64> = Array::from_iter(0..mask.len() as u64);
+
+    for prime in mask.collect_into_primes(None) {
+        let mut multiples = phi.slice_mut(s![prime as usize..; prime as usize]);
+
+        // phi(k) is always divisible by p here, as the factors of p in k have not been
+        // touched yet.
+        multiples.mapv_inplace(|value| value / prime * (prime - 1));
+    }
+
+    phi
+}
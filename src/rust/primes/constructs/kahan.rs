@@ -0,0 +1,35 @@
+/// A compensated summation of [`f64`] values using Kahan's algorithm.
+///
+/// Summing the logarithms of millions of primes one by one loses precision with every
+/// addition; this keeps track of the lost low-order bits in a separate compensation
+/// term, so that the error of the total stays independent of the number of terms.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct KahanSum {
+    sum: f64,
+    compensation: f64,
+}
+impl KahanSum {
+    /// Builder method for this class.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a value to the running total.
+    pub fn add(&mut self, value: f64) {
+        let corrected = value - self.compensation;
+        let sum = self.sum + corrected;
+
+        self.compensation = (sum - self.sum) - corrected;
+        self.sum = sum;
+    }
+
+    /// The compensated total of all the values added so far.
+    pub fn total(&self) -> f64 {
+        self.sum
+    }
+}
+impl Extend<f64> for KahanSum {
+    fn extend<I: IntoIterator<Item = f64>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|value| self.add(value));
+    }
+}
@@ -10,5 +10,11 @@ pub use sieved_array::SievedArray;
 mod can_sieve;
 pub use can_sieve::*;
 
+mod kahan;
+pub use kahan::KahanSum;
+
+mod multiplicative;
+pub use multiplicative::{dirichlet_convolve, MultiplicativeFn, PrimePolynomialFn};
+
 mod types;
 pub use types::{AtomicSieve, NonAtomicSieve, OwnedSieve, Sieve, ViewSieve};
@@ -0,0 +1,119 @@
+use ndarray::{s, Array, LinalgScalar};
+use num_traits::One;
+
+use super::OwnedSieve;
+
+/// A trait for multiplicative arithmetic functions, i.e. functions where `f(1) == 1`
+/// and `f(m * n) == f(m) * f(n)` whenever `m` and `n` are coprime.
+///
+/// Such a function is fully described by its values at prime powers, which is all that
+/// an implementation needs to provide; [`MultiplicativeFn::linear_sieve()`] then
+/// extends it to every integer.
+pub trait MultiplicativeFn {
+    type Output: LinalgScalar;
+
+    /// The value of the function at `prime.pow(exponent)`, where `exponent >= 1`.
+    fn at_prime_power(&self, prime: u64, exponent: u32) -> Self::Output;
+
+    /// Returns an [`Array`] containing `f(k)` at each index `k`, for all `k` up to and
+    /// including `ubound`. By convention `f(0)` is left as zero.
+    ///
+    /// This is a linear sieve: every composite `k` is visited exactly once, through its
+    /// lowest prime factor `p`. Alongside the values, the sieve keeps the largest power
+    /// of `p` dividing each `k`, so that `k` can be split into that prime power and a
+    /// cofactor coprime to it.
+    fn linear_sieve(&self, ubound: u64) -> OwnedSieve<Self::Output> {
+        let len = (ubound + 1) as usize;
+
+        let mut values: OwnedSieve<Self::Output> = Array::zeros((len,));
+        // The largest power of the lowest prime dividing each index, and its exponent.
+        let mut lowest_power = vec![0_usize; len];
+        let mut exponent = vec![0_u32; len];
+        let mut primes: Vec<usize> = Vec::new();
+
+        if len > 1 {
+            values[1] = Self::Output::one();
+        }
+
+        for num in 2..len {
+            if lowest_power[num] == 0 {
+                primes.push(num);
+                lowest_power[num] = num;
+                exponent[num] = 1;
+                values[num] = self.at_prime_power(num as u64, 1);
+            }
+
+            for &prime in primes.iter() {
+                let multiple = match prime.checked_mul(num) {
+                    Some(multiple) if multiple < len => multiple,
+                    _ => break,
+                };
+
+                if num % prime == 0 {
+                    // `prime` is the lowest prime factor of `num`; raise its power.
+                    lowest_power[multiple] = lowest_power[num] * prime;
+                    exponent[multiple] = exponent[num] + 1;
+
+                    let cofactor = num / lowest_power[num];
+                    values[multiple] = if cofactor == 1 {
+                        self.at_prime_power(prime as u64, exponent[multiple])
+                    } else {
+                        values[cofactor] * values[lowest_power[multiple]]
+                    };
+
+                    break;
+                } else {
+                    // `prime` is coprime to `num`.
+                    lowest_power[multiple] = prime;
+                    exponent[multiple] = 1;
+                    values[multiple] = values[num] * values[prime];
+                }
+            }
+        }
+
+        values
+    }
+}
+
+/// A [`MultiplicativeFn`] whose values at primes are given by a polynomial in `p`, such
+/// as `phi(p) = p - 1` or `sigma_1(p) = p + 1`.
+///
+/// This allows the sum of the function up to `n` to be found without sieving every
+/// value up to `n`; see [`min_25_sum`].
+///
+/// [`min_25_sum`]: crate::primes::summation::min_25_sum
+pub trait PrimePolynomialFn: MultiplicativeFn<Output: Into<i128>> {
+    /// The coefficients `c` such that `f(p) == sum(c[i] * p.pow(i))` for every prime
+    /// `p`, starting from the constant term. Powers of up to `3` are supported.
+    fn prime_polynomial(&self) -> Vec<i128>;
+}
+
+/// Calculate the Dirichlet convolution `(f * g)(n) = sum(f(d) * g(n / d) for d | n)` of
+/// two sieved arrays, such as the ones returned by
+/// [`MultiplicativeFn::linear_sieve()`].
+///
+/// The result is as long as the shorter of the two inputs, with index `0` left as zero.
+/// Each `f(d)` is scaled onto `g` and added to a `slice_mut` over steps of `d`, so this
+/// takes `O(n log n)` operations.
+pub fn dirichlet_convolve<T>(lhs: &OwnedSieve<T>, rhs: &OwnedSieve<T>) -> OwnedSieve<T>
+where
+    T: LinalgScalar,
+{
+    let len = lhs.len().min(rhs.len());
+    let mut result: OwnedSieve<T> = Array::zeros((len,));
+
+    for divisor in 1..len {
+        let coefficient = lhs[divisor];
+        if coefficient.is_zero() {
+            continue;
+        }
+
+        let quotients = (len - 1) / divisor;
+
+        result
+            .slice_mut(s![divisor..; divisor])
+            .scaled_add(coefficient, &rhs.slice(s![1..=quotients]));
+    }
+
+    result
+}
@@ -0,0 +1,33 @@
+/// Integer square root of `num`, i.e. the largest `r` such that `r * r <= num`.
+///
+/// [`f64::sqrt`] is only exact for `num` below `2^52`; this corrects the float
+/// estimate so that it can be safely used as a sieving bound for any [`u64`].
+pub fn isqrt(num: u64) -> u64 {
+    iroot(num, 2)
+}
+
+/// Integer cube root of `num`, i.e. the largest `r` such that `r * r * r <= num`.
+pub fn icbrt(num: u64) -> u64 {
+    iroot(num, 3)
+}
+
+/// Integer `k`-th root of `num`, i.e. the largest `r` such that `r.pow(k) <= num`.
+///
+/// The float estimate is corrected in both directions, so this is exact for any
+/// [`u64`].
+pub fn iroot(num: u64, k: u32) -> u64 {
+    if k <= 1 {
+        return num;
+    }
+
+    let mut root = (num as f64).powf(1. / k as f64) as u64;
+
+    while root > 0 && root.checked_pow(k).is_none_or(|power| power > num) {
+        root -= 1;
+    }
+    while (root + 1).checked_pow(k).is_some_and(|power| power <= num) {
+        root += 1;
+    }
+
+    root
+}
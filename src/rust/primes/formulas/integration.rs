@@ -0,0 +1,22 @@
+use crate::primes::constructs::*;
+
+/// The number of intervals for the Simpson's rule in [`simpson`].
+const INTEGRATION_STEPS: usize = 2_usize.pow(16);
+
+/// The integral of `integrand` over `lower..upper`, by Simpson's rule over
+/// [`INTEGRATION_STEPS`] intervals, with the weighted values added up by [`KahanSum`].
+pub fn simpson(integrand: impl Fn(f64) -> f64, lower: f64, upper: f64) -> f64 {
+    let step = (upper - lower) / INTEGRATION_STEPS as f64;
+    let mut integral = KahanSum::new();
+    integral.add(integrand(lower) + integrand(upper));
+    integral.extend((1..INTEGRATION_STEPS).map(|index| {
+        let weight = match index % 2 {
+            0 => 2.0,
+            _ => 4.0,
+        };
+
+        weight * integrand(lower + index as f64 * step)
+    }));
+
+    integral.total() * step / 3.0
+}
@@ -1,3 +1,15 @@
+pub mod integer;
+pub mod integration;
+pub mod modular;
+pub mod montgomery;
+pub mod polynomial;
+pub mod products;
 pub mod upper_bound;
 
+pub use integer::{icbrt, iroot, isqrt};
+pub use integration::simpson;
+pub use modular::{gcd, jacobi, mod_inverse, mul_mod, pow_mod, sqrt_mod};
+pub use montgomery::Montgomery;
+pub use polynomial::Polynomial;
+pub use products::{primorial, primorial_of_nth, product_tree};
 pub use upper_bound::{list_n_primes, nth_prime, upper_bound_of_nth_prime};
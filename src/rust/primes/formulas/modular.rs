@@ -0,0 +1,126 @@
+/// Greatest common divisor of `lhs` and `rhs`, by Euclid's algorithm.
+pub fn gcd(mut lhs: u64, mut rhs: u64) -> u64 {
+    while rhs != 0 {
+        (lhs, rhs) = (rhs, lhs % rhs);
+    }
+
+    lhs
+}
+
+/// The inverse of `value` modulo `modulus`, i.e. `x` such that `value * x % modulus == 1`.
+///
+/// Returns [`None`] if `value` and `modulus` are not coprime.
+pub fn mod_inverse(value: u64, modulus: u64) -> Option<u64> {
+    // Extended Euclid, keeping track of the coefficient of `value` only.
+    let (mut old_remainder, mut remainder) = ((value % modulus) as i128, modulus as i128);
+    let (mut old_coefficient, mut coefficient) = (1_i128, 0_i128);
+
+    while remainder != 0 {
+        let quotient = old_remainder / remainder;
+
+        (old_remainder, remainder) = (remainder, old_remainder - quotient * remainder);
+        (old_coefficient, coefficient) = (coefficient, old_coefficient - quotient * coefficient);
+    }
+
+    match old_remainder {
+        1 => Some(old_coefficient.rem_euclid(modulus as i128) as u64),
+        _ if modulus == 1 => Some(0),
+        _ => None,
+    }
+}
+
+/// `lhs * rhs % modulus`, without overflowing.
+pub fn mul_mod(lhs: u64, rhs: u64, modulus: u64) -> u64 {
+    (lhs as u128 * rhs as u128 % modulus as u128) as u64
+}
+
+/// `base ^ exponent % modulus`, by binary exponentiation.
+pub fn pow_mod(mut base: u64, mut exponent: u64, modulus: u64) -> u64 {
+    let mut result = 1 % modulus;
+    base %= modulus;
+
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = mul_mod(result, base, modulus);
+        }
+        base = mul_mod(base, base, modulus);
+        exponent >>= 1;
+    }
+
+    result
+}
+
+/// The Jacobi symbol `(value / modulus)` for an odd `modulus`, by quadratic reciprocity.
+///
+/// It is `0` if `value` and `modulus` are not coprime; for a prime `modulus`, it is `1`
+/// if `value` is a quadratic residue and `-1` otherwise.
+pub fn jacobi(value: u64, modulus: u64) -> i8 {
+    let (mut value, mut modulus) = (value % modulus, modulus);
+    let mut sign = 1;
+
+    while value != 0 {
+        while value.is_multiple_of(2) {
+            value /= 2;
+            if modulus % 8 == 3 || modulus % 8 == 5 {
+                sign = -sign;
+            }
+        }
+
+        (value, modulus) = (modulus, value);
+        if value % 4 == 3 && modulus % 4 == 3 {
+            sign = -sign;
+        }
+        value %= modulus;
+    }
+
+    match modulus {
+        1 => sign,
+        _ => 0,
+    }
+}
+
+/// A square root of `value` modulo an odd `prime`, by the Tonelli–Shanks algorithm; or
+/// [`None`] if `value` is a quadratic non-residue.
+///
+/// With `prime - 1 = odd * 2^twos`, the candidate `value^((odd + 1) / 2)` is off by a
+/// factor whose order is a power of `2`, which is corrected one power at a time by the
+/// powers of a non-residue.
+pub fn sqrt_mod(value: u64, prime: u64) -> Option<u64> {
+    let value = value % prime;
+    match jacobi(value, prime) {
+        0 => return Some(0),
+        -1 => return None,
+        _ if prime % 4 == 3 => return Some(pow_mod(value, prime.div_ceil(4), prime)),
+        _ => {}
+    }
+
+    let twos = (prime - 1).trailing_zeros();
+    let odd = (prime - 1) >> twos;
+    let non_residue = (2..prime).find(|&candidate| jacobi(candidate, prime) == -1)?;
+
+    let mut order = twos;
+    let mut correction = pow_mod(non_residue, odd, prime);
+    let mut error = pow_mod(value, odd, prime);
+    let mut root = pow_mod(value, odd.div_ceil(2), prime);
+
+    while error != 1 {
+        // The order of `error` is `2^exponent`.
+        let exponent = (1..order)
+            .scan(error, |power, exponent| {
+                *power = mul_mod(*power, *power, prime);
+                Some((*power, exponent))
+            })
+            .find(|&(power, _)| power == 1)
+            .map_or(order, |(_, exponent)| exponent);
+
+        let factor =
+            (exponent + 1..order).fold(correction, |factor, _| mul_mod(factor, factor, prime));
+
+        order = exponent;
+        correction = mul_mod(factor, factor, prime);
+        error = mul_mod(error, correction, prime);
+        root = mul_mod(root, factor, prime);
+    }
+
+    Some(root)
+}
@@ -0,0 +1,131 @@
+/// The lower 64 bits of a [`u128`].
+const LOW_MASK: u128 = u64::MAX as u128;
+
+/// The full product of `lhs` and `rhs`, as its upper and lower 128 bits.
+fn widening_mul(lhs: u128, rhs: u128) -> (u128, u128) {
+    let (lhs_high, lhs_low) = (lhs >> 64, lhs & LOW_MASK);
+    let (rhs_high, rhs_low) = (rhs >> 64, rhs & LOW_MASK);
+
+    let low = lhs_low * rhs_low;
+    let cross_low = lhs_low * rhs_high;
+    let cross_high = lhs_high * rhs_low;
+    let high = lhs_high * rhs_high;
+
+    let middle = (low >> 64) + (cross_low & LOW_MASK) + (cross_high & LOW_MASK);
+
+    (
+        high + (cross_low >> 64) + (cross_high >> 64) + (middle >> 64),
+        (low & LOW_MASK) | (middle << 64),
+    )
+}
+
+/// Montgomery arithmetic modulo an odd `modulus` below `2^127`, e.g. the square of any
+/// odd prime below `2^63`.
+///
+/// Numbers are held in the Montgomery form `x * R mod modulus` with `R = 2^128`, so that
+/// each multiplication is reduced by a few multiplications and shifts instead of a
+/// division of the 256-bit product.
+#[derive(Clone, Copy, Debug)]
+pub struct Montgomery {
+    modulus: u128,
+
+    /// `-modulus^-1` modulo `R`.
+    inverse: u128,
+
+    /// `R^2` modulo `modulus`, for converting into the Montgomery form.
+    square: u128,
+}
+impl Montgomery {
+    /// Builder method for this class.
+    ///
+    /// Returns [`None`] if `modulus` is even, or not below `2^127`.
+    pub fn new(modulus: u128) -> Option<Self> {
+        if modulus.is_multiple_of(2) || modulus >= 1 << 127 {
+            return None;
+        }
+
+        // Newton's iteration doubles the correct bits of the inverse, starting from the
+        // 3 bits of `modulus * modulus = 1 mod 8`.
+        let inverse = (0..6).fold(modulus, |inverse, _| {
+            inverse.wrapping_mul(2_u128.wrapping_sub(modulus.wrapping_mul(inverse)))
+        });
+
+        // `R mod modulus`, doubled another 128 times; as `modulus` is below `2^127`, each
+        // doubling needs at most one subtraction.
+        let square = (0..128).fold((u128::MAX % modulus + 1) % modulus, |power, _| {
+            match power << 1 >= modulus {
+                true => (power << 1) - modulus,
+                false => power << 1,
+            }
+        });
+
+        Some(Self {
+            modulus,
+            inverse: inverse.wrapping_neg(),
+            square,
+        })
+    }
+
+    /// The modulus.
+    pub fn modulus(&self) -> u128 {
+        self.modulus
+    }
+
+    /// `lhs * rhs * R^-1 mod modulus`, for any `lhs` and `rhs` with a product below
+    /// `modulus * R`.
+    pub fn mul(&self, lhs: u128, rhs: u128) -> u128 {
+        let (high, low) = widening_mul(lhs, rhs);
+        let (reduction, _) = widening_mul(low.wrapping_mul(self.inverse), self.modulus);
+
+        // The lower halves sum to `0` modulo `R`, carrying exactly when `low` is nonzero.
+        let result = high + reduction + (low != 0) as u128;
+
+        match result >= self.modulus {
+            true => result - self.modulus,
+            false => result,
+        }
+    }
+
+    /// `value` in the Montgomery form.
+    pub fn to_form(&self, value: u128) -> u128 {
+        self.mul(value % self.modulus, self.square)
+    }
+
+    /// `value` out of the Montgomery form.
+    pub fn from_form(&self, value: u128) -> u128 {
+        self.mul(value, 1)
+    }
+
+    /// `lhs + rhs mod modulus`, in either form.
+    pub fn add(&self, lhs: u128, rhs: u128) -> u128 {
+        // Both are below `2^127`, so this does not overflow.
+        match lhs + rhs >= self.modulus {
+            true => lhs + rhs - self.modulus,
+            false => lhs + rhs,
+        }
+    }
+
+    /// `lhs - rhs mod modulus`, in either form.
+    pub fn sub(&self, lhs: u128, rhs: u128) -> u128 {
+        match lhs >= rhs {
+            true => lhs - rhs,
+            false => lhs + self.modulus - rhs,
+        }
+    }
+
+    /// `base ^ exponent mod modulus` in the Montgomery form, for `base` in the Montgomery
+    /// form, by binary exponentiation.
+    pub fn pow(&self, mut base: u128, mut exponent: u128) -> u128 {
+        let mut result = self.to_form(1);
+
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result = self.mul(result, base);
+            }
+            base = self.mul(base, base);
+            exponent >>= 1;
+        }
+
+        result
+    }
+}
@@ -0,0 +1,330 @@
+use super::modular::{gcd, mod_inverse, mul_mod, sqrt_mod};
+
+/// The primes up to which the roots of [`Polynomial::roots_mod`] are found by evaluating
+/// every residue, rather than by Cantor–Zassenhaus.
+const BRUTE_FORCE_LIMIT: u64 = 64;
+
+/// A polynomial over `Z / prime Z`, with the coefficient of `x^i` at index `i` and no
+/// trailing zeros, so that `0` is empty.
+type Residues = Vec<u64>;
+
+/// `lhs * rhs % prime`, in [`u64`] whenever the product fits.
+fn mul(lhs: u64, rhs: u64, prime: u64) -> u64 {
+    match prime >> 32 {
+        0 => lhs * rhs % prime,
+        _ => mul_mod(lhs, rhs, prime),
+    }
+}
+
+/// `lhs + rhs % prime`, for residues `lhs` and `rhs`.
+fn add(lhs: u64, rhs: u64, prime: u64) -> u64 {
+    match lhs.overflowing_add(rhs) {
+        (sum, false) if sum < prime => sum,
+        (sum, _) => sum.wrapping_sub(prime),
+    }
+}
+
+/// `lhs - rhs % prime`, for residues `lhs` and `rhs`.
+fn sub(lhs: u64, rhs: u64, prime: u64) -> u64 {
+    match lhs >= rhs {
+        true => lhs - rhs,
+        false => lhs.wrapping_sub(rhs).wrapping_add(prime),
+    }
+}
+
+/// Drop the trailing zeros of `poly`.
+fn trim(mut poly: Residues) -> Residues {
+    while poly.last() == Some(&0) {
+        poly.pop();
+    }
+
+    poly
+}
+
+/// `poly` divided by its leading coefficient, for a non-zero `poly`.
+fn monic(poly: Residues, prime: u64) -> Residues {
+    let inverse = mod_inverse(poly[poly.len() - 1], prime).expect("prime modulus");
+
+    poly.into_iter()
+        .map(|coefficient| mul(coefficient, inverse, prime))
+        .collect()
+}
+
+/// The quotient and remainder of `dividend` by a monic `divisor`.
+fn divide(mut dividend: Residues, divisor: &[u64], prime: u64) -> (Residues, Residues) {
+    let degree = divisor.len() - 1;
+    let mut quotient = vec![0; dividend.len().saturating_sub(degree)];
+
+    while dividend.len() > degree {
+        let factor = dividend[dividend.len() - 1];
+        let shift = dividend.len() - 1 - degree;
+
+        quotient[shift] = factor;
+        for (index, &coefficient) in divisor.iter().enumerate() {
+            let subtrahend = mul(factor, coefficient, prime);
+            dividend[shift + index] = sub(dividend[shift + index], subtrahend, prime);
+        }
+        dividend = trim(dividend);
+    }
+
+    (trim(quotient), dividend)
+}
+
+/// `dividend mod divisor`, for a monic `divisor`.
+fn remainder(dividend: Residues, divisor: &[u64], prime: u64) -> Residues {
+    divide(dividend, divisor, prime).1
+}
+
+/// `lhs * rhs mod modulus`, for a monic `modulus`.
+fn multiply(lhs: &[u64], rhs: &[u64], modulus: &[u64], prime: u64) -> Residues {
+    if lhs.is_empty() || rhs.is_empty() {
+        return Residues::new();
+    }
+
+    let mut product = vec![0; lhs.len() + rhs.len() - 1];
+    for (lhs_index, &lhs_coefficient) in lhs.iter().enumerate() {
+        for (rhs_index, &rhs_coefficient) in rhs.iter().enumerate() {
+            let term = mul(lhs_coefficient, rhs_coefficient, prime);
+            product[lhs_index + rhs_index] = add(product[lhs_index + rhs_index], term, prime);
+        }
+    }
+
+    remainder(trim(product), modulus, prime)
+}
+
+/// `base ^ exponent mod modulus`, by binary exponentiation, for a monic `modulus`.
+fn power(base: &[u64], mut exponent: u64, modulus: &[u64], prime: u64) -> Residues {
+    let mut base = remainder(base.to_vec(), modulus, prime);
+    let mut result = remainder(vec![1], modulus, prime);
+
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = multiply(&result, &base, modulus, prime);
+        }
+        base = multiply(&base, &base, modulus, prime);
+        exponent >>= 1;
+    }
+
+    result
+}
+
+/// The monic greatest common divisor of `lhs` and `rhs`, by Euclid's algorithm, for a
+/// non-zero `lhs`.
+fn greatest_common_divisor(lhs: Residues, rhs: Residues, prime: u64) -> Residues {
+    let (mut lhs, mut rhs) = (monic(lhs, prime), rhs);
+
+    while !rhs.is_empty() {
+        let next = monic(rhs, prime);
+        rhs = remainder(lhs, &next, prime);
+        lhs = next;
+    }
+
+    lhs
+}
+
+/// The roots of a monic `poly` that splits into distinct linear factors, by splitting
+/// it along the quadratic residues of `x + shift` for one `shift` after another.
+fn split_roots(poly: Residues, prime: u64, shift: u64, roots: &mut Vec<u64>) {
+    match poly.len() {
+        0 | 1 => return,
+        // `x + c` has the root `-c`.
+        2 => return roots.push((prime - poly[0]) % prime),
+        _ => {}
+    }
+
+    // Half of the roots `r` make `r + shift` a quadratic residue, and the other half do
+    // not, unless unlucky with `shift`.
+    let mut half = power(&[shift % prime, 1], (prime - 1) / 2, &poly, prime);
+    match half.is_empty() {
+        true => half.push(prime - 1),
+        false => half[0] = sub(half[0], 1, prime),
+    }
+    let factor = greatest_common_divisor(poly.clone(), trim(half), prime);
+
+    match factor.len() == 1 || factor.len() == poly.len() {
+        true => split_roots(poly, prime, shift + 1, roots),
+        false => {
+            let (cofactor, _) = divide(poly, &factor, prime);
+
+            split_roots(factor, prime, shift + 1, roots);
+            split_roots(cofactor, prime, shift + 1, roots);
+        }
+    }
+}
+
+/// A polynomial with [`i64`] coefficients, in one variable.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Polynomial {
+    /// The coefficient of `x^i` at index `i`, with a non-zero leading coefficient.
+    coefficients: Vec<i64>,
+}
+impl Polynomial {
+    /// Builder method for this class, from the `coefficients` from the highest power down
+    /// to the constant, as in `numpy.polyval`.
+    ///
+    /// Returns [`None`] if every coefficient is `0`.
+    pub fn new(coefficients: &[i64]) -> Option<Self> {
+        let first = coefficients
+            .iter()
+            .position(|&coefficient| coefficient != 0)?;
+
+        Some(Self {
+            coefficients: coefficients[first..].iter().rev().copied().collect(),
+        })
+    }
+
+    /// The degree of this polynomial.
+    pub fn degree(&self) -> usize {
+        self.coefficients.len() - 1
+    }
+
+    /// The coefficient of the highest power.
+    pub fn leading_coefficient(&self) -> i64 {
+        self.coefficients[self.degree()]
+    }
+
+    /// The coefficients from the highest power down to the constant.
+    pub fn coefficients(&self) -> Vec<i64> {
+        self.coefficients.iter().rev().copied().collect()
+    }
+
+    /// The greatest common divisor of the coefficients.
+    pub fn content(&self) -> u64 {
+        self.coefficients.iter().fold(0, |content, &coefficient| {
+            gcd(content, coefficient.unsigned_abs())
+        })
+    }
+
+    /// The value at `x`, by Horner's method; or [`None`] if it overflows [`i128`].
+    pub fn evaluate(&self, x: i128) -> Option<i128> {
+        self.coefficients
+            .iter()
+            .rev()
+            .try_fold(0_i128, |value, &coefficient| {
+                value.checked_mul(x)?.checked_add(coefficient as i128)
+            })
+    }
+
+    /// The value at `x`, by Horner's method in [`f64`].
+    pub fn evaluate_float(&self, x: f64) -> f64 {
+        self.coefficients
+            .iter()
+            .rev()
+            .fold(0., |value, &coefficient| value * x + coefficient as f64)
+    }
+
+    /// An upper bound of `|f(n)|` for every `n` in `0..=ubound`, i.e. the sum of the
+    /// absolute value of every term at `ubound`; or [`None`] if it overflows [`u128`].
+    pub fn value_bound(&self, ubound: u64) -> Option<u128> {
+        self.coefficients
+            .iter()
+            .rev()
+            .try_fold(0_u128, |bound, &coefficient| {
+                bound
+                    .checked_mul(ubound as u128)?
+                    .checked_add(coefficient.unsigned_abs() as u128)
+            })
+    }
+
+    /// The first `n >= 0` from which `|f(n)| > bound` for every larger `n` as well; or
+    /// [`None`] for a constant polynomial.
+    ///
+    /// With `S` the sum of the absolute values of the lower coefficients, `|f(n)|` is at
+    /// least `n^(d - 1) (|a_d| n - S)`, which only grows from `n = S / |a_d|` onwards, so
+    /// the first `n` beyond `bound` is found by binary search.
+    pub fn small_values_end(&self, bound: u64) -> Option<u64> {
+        let degree = self.degree();
+        if degree == 0 {
+            return None;
+        }
+
+        let leading = self.leading_coefficient().unsigned_abs() as u128;
+        let lower = self.coefficients[..degree]
+            .iter()
+            .map(|&coefficient| coefficient.unsigned_abs() as u128)
+            .sum::<u128>();
+        let minimum =
+            |n: u128| (0..degree - 1).fold(leading * n - lower, |value, _| value.saturating_mul(n));
+
+        let (mut low, mut high) = (lower.div_ceil(leading), lower / leading + bound as u128 + 1);
+        while low < high {
+            let middle = low + (high - low) / 2;
+            match minimum(middle) > bound as u128 {
+                true => high = middle,
+                false => low = middle + 1,
+            }
+        }
+
+        Some(low.min(u64::MAX as u128) as u64)
+    }
+
+    /// The distinct roots of this polynomial modulo `prime` in ascending order; every
+    /// residue if `prime` divides every coefficient.
+    ///
+    /// The roots of `f` are those of `gcd(f, x^p - x)`, the product of its distinct
+    /// linear factors, which the Cantor–Zassenhaus algorithm splits by `gcd` with
+    /// `(x + s)^((p - 1) / 2) - 1` for one shift `s` after another. Linear and quadratic
+    /// polynomials are solved directly by [`sqrt_mod`], and small primes are simply
+    /// evaluated at every residue instead.
+    pub fn roots_mod(&self, prime: u64) -> Vec<u64> {
+        let residues = trim(
+            self.coefficients
+                .iter()
+                .map(|&coefficient| (coefficient as i128).rem_euclid(prime as i128) as u64)
+                .collect(),
+        );
+
+        if residues.is_empty() {
+            return (0..prime).collect();
+        }
+        if prime <= BRUTE_FORCE_LIMIT {
+            return (0..prime)
+                .filter(|&x| {
+                    residues.iter().rev().fold(0, |value, &coefficient| {
+                        add(mul(value, x, prime), coefficient, prime)
+                    }) == 0
+                })
+                .collect();
+        }
+
+        let poly = monic(residues, prime);
+        match poly.len() {
+            // `x + c` has the root `-c`.
+            2 => return vec![sub(0, poly[0], prime)],
+            // `x^2 + b x + c` has the roots `(-b ± sqrt(b^2 - 4c)) / 2`.
+            3 => {
+                let (linear, constant) = (poly[1], poly[0]);
+                let discriminant = sub(mul(linear, linear, prime), mul(4, constant, prime), prime);
+                let Some(root) = sqrt_mod(discriminant, prime) else {
+                    return Vec::new();
+                };
+
+                let half = prime.div_ceil(2);
+                let mut roots = vec![
+                    mul(sub(root, linear, prime), half, prime),
+                    mul(sub(prime - root, linear, prime), half, prime),
+                ];
+                roots.sort_unstable();
+                roots.dedup();
+
+                return roots;
+            }
+            _ => {}
+        }
+
+        let mut frobenius = power(&[0, 1], prime, &poly, prime);
+        frobenius.resize(frobenius.len().max(2), 0);
+        frobenius[1] = sub(frobenius[1], 1, prime);
+
+        let mut roots = Vec::new();
+        split_roots(
+            greatest_common_divisor(poly, trim(frobenius), prime),
+            prime,
+            0,
+            &mut roots,
+        );
+        roots.sort_unstable();
+
+        roots
+    }
+}
@@ -0,0 +1,42 @@
+use num_bigint::BigUint;
+use num_traits::One;
+
+use super::upper_bound::list_n_primes;
+use crate::primes::constructs::*;
+use crate::py_compatibility::enums;
+
+/// The number of values multiplied one by one at the leaves of a [`product_tree`].
+const LEAF_SIZE: usize = 32;
+
+/// The product of `values`, by multiplying the products of both halves recursively in
+/// parallel.
+///
+/// Multiplying a running product by one value at a time takes quadratic time in the
+/// size of the result, while the halves of a product tree stay balanced in size, so that
+/// the faster multiplication algorithms of large integers apply.
+pub fn product_tree(values: &[u64]) -> BigUint {
+    match values.len() {
+        length if length <= LEAF_SIZE => values
+            .iter()
+            .fold(BigUint::one(), |product, &value| product * value),
+        length => {
+            let (lower, upper) = values.split_at(length / 2);
+            let (lower, upper) = rayon::join(|| product_tree(lower), || product_tree(upper));
+
+            lower * upper
+        }
+    }
+}
+
+/// The primorial `n#`, the product of every prime up to and including `n`.
+pub fn primorial(sieve: enums::SieveMethod, n: u64) -> BigUint {
+    product_tree(&sieve.list_primes(n, None))
+}
+
+/// The primorial `p(n)#`, the product of the first `n` primes.
+pub fn primorial_of_nth(sieve: enums::SieveMethod, n: u64) -> BigUint {
+    match n {
+        0 => BigUint::one(),
+        n => product_tree(&list_n_primes(sieve, n)),
+    }
+}
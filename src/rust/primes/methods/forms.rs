@@ -0,0 +1,286 @@
+use ndarray::{s, Array};
+use rayon::prelude::*;
+use std::cmp;
+
+use crate::primes::constructs::*;
+use crate::primes::formulas::{isqrt, mod_inverse};
+use crate::py_compatibility::enums;
+
+/// The primes the wheel is built from, in the order they are added to it.
+const WHEEL_PRIMES: [u64; 6] = [2, 3, 5, 7, 11, 13];
+
+/// The minimum number of candidates per residue class before another prime is added to
+/// the wheel; below this, the cost of setting up each class outweighs the savings.
+const MIN_CLASS_LENGTH: u64 = 2_u64.pow(12);
+
+/// The maximum number of candidates of a residue class sieved at once.
+const MAX_CLASS_SEGMENT: u64 = 2_u64.pow(22);
+
+/// A linear form `multiplier * n + offset`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LinearForm {
+    pub multiplier: u64,
+    pub offset: i64,
+}
+impl LinearForm {
+    /// Builder method for this class.
+    pub fn new(multiplier: u64, offset: i64) -> Self {
+        Self { multiplier, offset }
+    }
+
+    /// The value of this form at `n`, or [`None`] if it is negative or overflows [`u64`].
+    pub fn at(&self, n: u64) -> Option<u64> {
+        let value = (self.multiplier as i128)
+            .checked_mul(n as i128)?
+            .checked_add(self.offset as i128)?;
+
+        u64::try_from(value).ok()
+    }
+
+    /// The value of this form at `n`, modulo `modulus`.
+    fn at_mod(&self, n: u64, modulus: u64) -> u64 {
+        (self.multiplier % modulus * (n % modulus) + self.offset.rem_euclid(modulus as i64) as u64)
+            % modulus
+    }
+}
+
+/// A sieve over the integers `n`, for which every one of a set of [`LinearForm`]s is
+/// prime at `n`; this covers prime constellations `n + d` as well as Cunningham chains
+/// `2^j * n ± (2^j - 1)`.
+///
+/// Every form must be at least `n` for all `n >= 1`, i.e. its multiplier is at least
+/// `1`, and `multiplier + offset >= 1`.
+///
+/// The candidates are first restricted by a wheel, to the residue classes `r` modulo
+/// the product `W` of the [`WHEEL_PRIMES`] in which none of the forms are divisible by
+/// any of these primes; for twin primes and the full wheel, only 1485 of every 30030
+/// integers remain. Each class `n = r + W * j` is then sieved by every other base prime
+/// `p`, which divides `a * n + b` exactly when `j = -(a * r + b) * (a * W)^-1 mod p`, so
+/// all the forms are sieved simultaneously with a single modular inverse per form and
+/// prime.
+///
+/// Values of `n` small enough for a form to equal one of the sieving primes are instead
+/// sieved along each form directly, without the wheel.
+pub struct LinearFormSieve {
+    forms: Vec<LinearForm>,
+    lbound: u64,
+    ubound: u64,
+    base_primes: Vec<u64>,
+    wheel: u64,
+    classes: Vec<u64>,
+
+    /// Every base prime not in the wheel, and for each form the inverse of its
+    /// multiplier times the wheel modulo the prime, if it exists.
+    inverses: Vec<(u64, Vec<Option<u64>>)>,
+
+    /// The values of `n` below this are sieved without the wheel.
+    threshold: u64,
+}
+impl LinearFormSieve {
+    /// Builder method for this class, able to sieve every `n` in `lbound..=ubound`.
+    ///
+    /// Returns [`None`] if any of the forms overflows [`u64`] within the range.
+    pub fn new(
+        sieve: enums::SieveMethod,
+        forms: Vec<LinearForm>,
+        lbound: u64,
+        ubound: u64,
+    ) -> Option<Self> {
+        let max_value = forms
+            .iter()
+            .map(|form| {
+                let value = form.multiplier as i128 * ubound as i128 + form.offset as i128;
+
+                u64::try_from(cmp::max(value, 0)).ok()
+            })
+            .try_fold(0, |max_value, value| Some(cmp::max(max_value, value?)))?;
+        let base_primes = sieve.list_primes(isqrt(max_value), None);
+
+        let length = ubound.saturating_sub(lbound);
+        let wheel_primes: Vec<u64> = WHEEL_PRIMES
+            .iter()
+            .scan(1_u64, |wheel, &prime| {
+                *wheel *= prime;
+                Some((*wheel, prime))
+            })
+            .take_while(|&(wheel, prime)| prime == 2 || wheel * MIN_CLASS_LENGTH <= length)
+            .map(|(_, prime)| prime)
+            .collect();
+        let wheel: u64 = wheel_primes.iter().product();
+
+        let classes = (0..wheel)
+            .filter(|&residue| {
+                wheel_primes
+                    .iter()
+                    .all(|&prime| forms.iter().all(|form| form.at_mod(residue, prime) != 0))
+            })
+            .collect();
+
+        let inverses = base_primes
+            .iter()
+            .filter(|prime| !wheel_primes.contains(prime))
+            .map(|&prime| {
+                (
+                    prime,
+                    forms
+                        .iter()
+                        .map(|form| mod_inverse(form.multiplier % prime * (wheel % prime), prime))
+                        .collect(),
+                )
+            })
+            .collect();
+
+        let threshold = cmp::max(isqrt(max_value), WHEEL_PRIMES[WHEEL_PRIMES.len() - 1]) + 1;
+
+        Some(Self {
+            forms,
+            lbound,
+            ubound,
+            base_primes,
+            wheel,
+            classes,
+            inverses,
+            threshold,
+        })
+    }
+
+    /// The values of `n` below the threshold, in ascending order.
+    fn small_values(&self) -> Vec<u64> {
+        let (lbound, ubound) = (self.lbound, cmp::min(self.ubound, self.threshold - 1));
+        if lbound > ubound {
+            return Vec::new();
+        }
+
+        let mut mask: NonAtomicSieve = Array::from_elem(((ubound - lbound + 1) as usize,), true);
+
+        for form in self.forms.iter() {
+            // Values below 2 are not primes; the forms are increasing, so these are the
+            // smallest `n`.
+            (lbound..=ubound)
+                .take_while(|&n| form.at(n).is_none_or(|value| value < 2))
+                .for_each(|n| mask[(n - lbound) as usize] = false);
+
+            for &prime in self.base_primes.iter() {
+                let (mut n, step) = match mod_inverse(form.multiplier % prime, prime) {
+                    Some(inverse) => {
+                        let target = (prime - form.at_mod(0, prime)) % prime * inverse % prime;
+
+                        (lbound + (target + prime - lbound % prime) % prime, prime)
+                    }
+                    // The form is constant modulo the prime, so it divides either all or
+                    // none of the values.
+                    None if form.at_mod(0, prime) == 0 => (lbound, 1),
+                    None => continue,
+                };
+
+                while n <= ubound {
+                    // The prime itself is not a multiple.
+                    if form.at(n) != Some(prime) {
+                        mask[(n - lbound) as usize] = false;
+                    }
+                    n += step;
+                }
+            }
+        }
+
+        mask.iter()
+            .enumerate()
+            .filter(|(_, is_value)| is_value.is_true())
+            .map(|(index, _)| lbound + index as u64)
+            .collect()
+    }
+
+    /// Sieve the values of `n` at or above the threshold, in parallel over segments of
+    /// each residue class.
+    ///
+    /// Each item is the residue class, the first `j` of the segment, and its mask where
+    /// index `i` indicates whether every form is prime at `residue + W * (j + i)`.
+    fn par_class_segments(&self) -> impl ParallelIterator<Item = (u64, u64, NonAtomicSieve)> + '_ {
+        let (lbound, ubound) = (cmp::max(self.lbound, self.threshold), self.ubound);
+
+        self.classes
+            .par_iter()
+            .flat_map_iter(move |&residue| {
+                let (first, last) = match lbound <= ubound && residue <= ubound {
+                    true => (
+                        lbound.saturating_sub(residue).div_ceil(self.wheel),
+                        (ubound - residue) / self.wheel,
+                    ),
+                    false => (1, 0),
+                };
+
+                (first..=last)
+                    .step_by(MAX_CLASS_SEGMENT as usize)
+                    .map(move |start| {
+                        (
+                            residue,
+                            start,
+                            cmp::min(start + MAX_CLASS_SEGMENT - 1, last),
+                        )
+                    })
+            })
+            .map(|(residue, start, end)| (residue, start, self.sieve_class(residue, start, end)))
+    }
+
+    fn sieve_class(&self, residue: u64, start: u64, end: u64) -> NonAtomicSieve {
+        let mut mask: NonAtomicSieve = Array::from_elem(((end - start + 1) as usize,), true);
+
+        for (prime, inverses) in self.inverses.iter() {
+            for (form, inverse) in self.forms.iter().zip(inverses.iter()) {
+                let target = match inverse {
+                    Some(inverse) => {
+                        (prime - form.at_mod(residue, *prime)) % prime * inverse % prime
+                    }
+                    // Every value of the class is a multiple of the prime.
+                    None if form.at_mod(residue, *prime) == 0 => {
+                        mask.fill(false);
+                        return mask;
+                    }
+                    None => continue,
+                };
+                let index = (target + prime - start % prime) % prime;
+
+                if index <= end - start {
+                    mask.slice_mut(s![index as usize..; *prime as usize])
+                        .fill(false);
+                }
+            }
+        }
+
+        mask
+    }
+
+    /// List all the values of `n` in ascending order, for which every form is prime.
+    pub fn find(&self) -> Vec<u64> {
+        let mut values = self.small_values();
+        let mut large_values: Vec<u64> = self
+            .par_class_segments()
+            .flat_map_iter(|(residue, start, mask)| {
+                let wheel = self.wheel;
+
+                mask.into_iter()
+                    .enumerate()
+                    .filter(|(_, is_value)| is_value.is_true())
+                    .map(move |(index, _)| residue + wheel * (start + index as u64))
+            })
+            .collect();
+
+        large_values.par_sort_unstable();
+        values.append(&mut large_values);
+
+        values
+    }
+
+    /// Count the values of `n`, for which every form is prime.
+    ///
+    /// Unlike [`find`](Self::find), the values are never held in memory.
+    pub fn count(&self) -> u64 {
+        self.small_values().len() as u64
+            + self
+                .par_class_segments()
+                .map(|(_, _, mask)| {
+                    mask.iter().filter(|is_value| is_value.is_true()).count() as u64
+                })
+                .sum::<u64>()
+    }
+}
@@ -16,6 +16,18 @@ pub const DEFAULT_WORKERS: usize = 4;
 mod atomics;
 pub use atomics::SieveOfEratosthenesAtomic;
 
+mod forms;
+pub use forms::{LinearForm, LinearFormSieve};
+
+mod polynomial;
+pub use polynomial::PolynomialSieve;
+
+mod retained;
+pub use retained::RetainedSieve;
+
+mod segmented;
+pub use segmented::{SegmentedSieve, DEFAULT_SEGMENT_SIZE};
+
 #[allow(unused_imports)]
 use timeit::timeit_loops;
 
@@ -0,0 +1,210 @@
+use ndarray::{s, Array};
+use rayon::prelude::*;
+use std::cmp;
+
+use crate::primes::constructs::*;
+use crate::primes::formulas::{isqrt, Polynomial};
+use crate::primes::primality::miller_rabin;
+use crate::py_compatibility::enums;
+
+/// The largest prime the values are sieved by; any value beyond the square of the
+/// largest sieving prime that survives the sieve is confirmed by [`miller_rabin`] instead.
+const MAX_SIEVING_PRIME: u64 = 2_u64.pow(27);
+
+/// The number of consecutive `n` sieved at once.
+const MAX_SEGMENT: u64 = 2_u64.pow(23);
+
+/// A sieve over the integers `n`, for which an integer [`Polynomial`] `f(n)` is prime.
+///
+/// Each base prime `p` divides `f(n)` exactly when `n` is congruent to one of the roots
+/// of `f` modulo `p`, so every segment of `n` is struck off along every root of every
+/// base prime, with the roots found once by [`Polynomial::roots_mod`]. If the content of
+/// `f` is above `1`, it divides every value, so none is sieved at all.
+///
+/// Values of `n` small enough for `|f(n)|` to equal one of the sieving primes, or the
+/// content, are instead tested directly.
+pub struct PolynomialSieve {
+    polynomial: Polynomial,
+    lbound: u64,
+    ubound: u64,
+
+    /// The greatest common divisor of the coefficients.
+    content: u64,
+
+    /// Every base prime with each of the roots of the polynomial modulo it.
+    roots: Vec<(u64, u64)>,
+
+    /// Whether the base primes reach the square root of every value, so that every value
+    /// surviving the sieve is a prime without any further test.
+    complete: bool,
+
+    /// The values of `n` below this are tested directly, or every value if [`None`].
+    threshold: Option<u64>,
+}
+impl PolynomialSieve {
+    /// Builder method for this class, able to sieve every `n` in `lbound..=ubound`.
+    ///
+    /// Returns [`None`] if `|f(n)|` overflows [`u64`] within the range.
+    pub fn new(
+        sieve: enums::SieveMethod,
+        polynomial: Polynomial,
+        lbound: u64,
+        ubound: u64,
+    ) -> Option<Self> {
+        let max_value = u64::try_from(polynomial.value_bound(ubound)?).ok()?;
+        let root = isqrt(max_value);
+        let content = polynomial.content();
+
+        // Beyond the number of `n`, each prime strikes off at most a few of them, so
+        // finding its roots costs more than testing these directly.
+        let max_prime = cmp::min(
+            cmp::min(root, MAX_SIEVING_PRIME),
+            (ubound.saturating_sub(lbound)).saturating_add(1),
+        );
+
+        let threshold = polynomial.small_values_end(cmp::max(max_prime, content));
+        let roots = match (threshold, content) {
+            (Some(threshold), 1) if threshold <= ubound => sieve
+                .list_primes(max_prime, None)
+                .into_par_iter()
+                .flat_map_iter(|prime| {
+                    polynomial
+                        .roots_mod(prime)
+                        .into_iter()
+                        .map(move |root| (prime, root))
+                })
+                .collect(),
+            _ => Vec::new(),
+        };
+
+        Some(Self {
+            polynomial,
+            lbound,
+            ubound,
+            content,
+            roots,
+            complete: root <= max_prime,
+            threshold,
+        })
+    }
+
+    /// Whether `f(n)` is a prime, by [`miller_rabin`].
+    fn is_prime_at(&self, n: u64) -> bool {
+        let value = self
+            .polynomial
+            .evaluate(n as i128)
+            .expect("values fit into u64");
+
+        value > 1 && miller_rabin(value as u64)
+    }
+
+    /// The values of `n` below the threshold, in ascending order.
+    fn small_values(&self) -> Vec<u64> {
+        let ubound = match self.threshold {
+            // A constant is prime either everywhere or nowhere, which is tested here once.
+            None => cmp::min(self.ubound, self.lbound),
+            Some(threshold) => cmp::min(self.ubound, threshold.saturating_sub(1)),
+        };
+        if self.lbound > ubound || self.threshold == Some(0) {
+            return Vec::new();
+        }
+
+        (self.lbound..=ubound)
+            .into_par_iter()
+            .filter(|&n| self.is_prime_at(n))
+            .collect()
+    }
+
+    /// Sieve the values of `n` at or above the threshold, in parallel over segments.
+    ///
+    /// Each item is the first `n` of the segment, and its mask where index `i` indicates
+    /// whether `f(start + i)` is prime.
+    fn par_segments(&self) -> impl ParallelIterator<Item = (u64, NonAtomicSieve)> + '_ {
+        let lbound = match self.threshold {
+            Some(threshold) => cmp::max(self.lbound, threshold),
+            None => self.ubound.saturating_add(1),
+        };
+        let segments = match lbound <= self.ubound {
+            true => (self.ubound - lbound) / MAX_SEGMENT + 1,
+            false => 0,
+        };
+
+        (0..segments).into_par_iter().map(move |index| {
+            let start = lbound + index * MAX_SEGMENT;
+            let end = cmp::min(self.ubound, start + (MAX_SEGMENT - 1));
+
+            (start, self.sieve_segment(start, end))
+        })
+    }
+
+    fn sieve_segment(&self, start: u64, end: u64) -> NonAtomicSieve {
+        // Any other content divides every value.
+        let mut mask: NonAtomicSieve =
+            Array::from_elem(((end - start + 1) as usize,), self.content == 1);
+
+        for &(prime, root) in self.roots.iter() {
+            let index = (root + prime - start % prime) % prime;
+
+            if index <= end - start {
+                mask.slice_mut(s![index as usize..; prime as usize])
+                    .fill(false);
+            }
+        }
+
+        mask.iter_mut()
+            .zip(start..)
+            .filter(|(is_value, _)| is_value.is_true())
+            .for_each(|(is_value, n)| {
+                *is_value = match self.complete {
+                    true => self
+                        .polynomial
+                        .evaluate(n as i128)
+                        .is_some_and(|value| value > 1),
+                    false => self.is_prime_at(n),
+                }
+            });
+
+        mask
+    }
+
+    /// List all the values of `n` in ascending order, for which the polynomial is prime.
+    pub fn find(&self) -> Vec<u64> {
+        let mut values = self.small_values();
+
+        match (self.threshold, values.first()) {
+            (None, Some(_)) => (self.lbound..=self.ubound).collect(),
+            (None, None) => Vec::new(),
+            _ => {
+                values.par_extend(self.par_segments().flat_map_iter(|(start, mask)| {
+                    mask.into_iter()
+                        .zip(start..)
+                        .filter(|(is_value, _)| is_value.is_true())
+                        .map(|(_, n)| n)
+                        .collect::<Vec<u64>>()
+                }));
+
+                values
+            }
+        }
+    }
+
+    /// Count the values of `n`, for which the polynomial is prime.
+    ///
+    /// Unlike [`find`](Self::find), the values are never held in memory.
+    pub fn count(&self) -> u64 {
+        let small_count = self.small_values().len() as u64;
+
+        match self.threshold {
+            None => small_count * (self.ubound.saturating_sub(self.lbound) + 1),
+            Some(_) => {
+                small_count
+                    + self
+                        .par_segments()
+                        .map(|(_, mask)| {
+                            mask.iter().filter(|is_value| is_value.is_true()).count() as u64
+                        })
+                        .sum::<u64>()
+            }
+        }
+    }
+}
@@ -0,0 +1,35 @@
+use crate::primes::constructs::*;
+use crate::primes::primality::miller_rabin;
+use crate::py_compatibility::enums;
+
+/// A prime mask retained in memory up to a limit, for primality lookups in `O(1)`;
+/// numbers beyond the limit fall back to [`miller_rabin`].
+///
+/// This suits searches that test many scattered numbers for primality, e.g. the digit
+/// reversals and rotations of primes, which a [`SegmentedSieve`] cannot look up.
+///
+/// [`SegmentedSieve`]: super::SegmentedSieve
+pub struct RetainedSieve {
+    mask: NonAtomicSieve,
+}
+impl RetainedSieve {
+    /// Builder method for this class, retaining the mask up to `limit`.
+    pub fn new(sieve: enums::SieveMethod, limit: u64) -> Self {
+        Self {
+            mask: sieve.sieve(limit),
+        }
+    }
+
+    /// The largest number in the retained mask.
+    pub fn limit(&self) -> u64 {
+        self.mask.len() as u64 - 1
+    }
+
+    /// Whether `num` is prime.
+    pub fn is_prime(&self, num: u64) -> bool {
+        match self.mask.get(num as usize) {
+            Some(is_prime) => num >= 2 && is_prime.is_true(),
+            None => miller_rabin(num),
+        }
+    }
+}
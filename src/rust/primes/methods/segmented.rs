@@ -0,0 +1,101 @@
+use ndarray::{s, Array};
+use rayon::prelude::*;
+use std::cmp;
+
+use crate::primes::constructs::*;
+use crate::primes::formulas::isqrt;
+use crate::py_compatibility::enums;
+
+/// The default number of integers in each segment of a [`SegmentedSieve`].
+pub const DEFAULT_SEGMENT_SIZE: u64 = 2_u64.pow(20);
+
+/// Segmented Sieve of Eratosthenes, for sieving ranges far beyond what a single
+/// [`NonAtomicSieve`] can hold.
+///
+/// The base primes up to `sqrt(ubound)` are found once by a [`SieveMethod`]; each
+/// segment is then sieved independently using `slice_mut` over steps of each base
+/// prime, so segments can be processed in parallel and discarded as soon as they are
+/// consumed.
+///
+/// [`SieveMethod`]: enums::SieveMethod
+pub struct SegmentedSieve {
+    base_primes: Vec<u64>,
+    segment_size: u64,
+}
+impl SegmentedSieve {
+    /// Builder method for this class, able to sieve any range up to `ubound`.
+    pub fn new(sieve: enums::SieveMethod, ubound: u64) -> Self {
+        Self {
+            base_primes: sieve.list_primes(isqrt(ubound), None),
+            segment_size: DEFAULT_SEGMENT_SIZE,
+        }
+    }
+
+    /// Change the number of integers in each segment.
+    pub fn with_segment_size(mut self, segment_size: u64) -> Self {
+        self.segment_size = cmp::max(segment_size, 1);
+        self
+    }
+
+    /// The primes up to `sqrt(ubound)` used for sieving.
+    pub fn base_primes(&self) -> &[u64] {
+        &self.base_primes
+    }
+
+    /// Returns a mask of `lbound..=ubound`, where index `i` indicates whether
+    /// `lbound + i` is a prime number.
+    pub fn sieve_segment(&self, lbound: u64, ubound: u64) -> NonAtomicSieve {
+        if lbound > ubound {
+            return Array::from_elem((0,), false);
+        }
+
+        let mut sieve: NonAtomicSieve = Array::from_elem(((ubound - lbound + 1) as usize,), true);
+
+        // 0 and 1 are not primes.
+        for non_prime in lbound..cmp::min(2, ubound + 1) {
+            sieve[(non_prime - lbound) as usize] = false;
+        }
+
+        for &prime in self.base_primes.iter() {
+            let square = prime * prime;
+            if square > ubound {
+                break;
+            }
+
+            let start = cmp::max(square, lbound.div_ceil(prime) * prime);
+            if start > ubound {
+                continue;
+            }
+
+            sieve
+                .slice_mut(s![(start - lbound) as usize..; prime as usize])
+                .fill(false);
+        }
+
+        sieve
+    }
+
+    /// Split `lbound..=ubound` into consecutive segments, and sieve each of them in
+    /// parallel.
+    ///
+    /// Each item is the lower bound of the segment and its mask, in ascending order;
+    /// any order-dependent combination should therefore use an associative `reduce`
+    /// rather than a `fold` across segments.
+    pub fn par_segments(
+        &self,
+        lbound: u64,
+        ubound: u64,
+    ) -> impl IndexedParallelIterator<Item = (u64, NonAtomicSieve)> + '_ {
+        let segment_count = match lbound > ubound {
+            true => 0,
+            false => ((ubound - lbound) / self.segment_size + 1) as usize,
+        };
+
+        (0..segment_count).into_par_iter().map(move |index| {
+            let start = lbound + index as u64 * self.segment_size;
+            let end = cmp::min(start + (self.segment_size - 1), ubound);
+
+            (start, self.sieve_segment(start, end))
+        })
+    }
+}
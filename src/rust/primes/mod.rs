@@ -1,8 +1,17 @@
+pub mod analysis;
+pub mod arithmetic;
 pub mod constructs;
 pub mod formulas;
 pub(crate) mod methods;
+pub mod primality;
+pub mod summation;
 
 pub use methods::{
+    LinearForm,
+    LinearFormSieve,
+    PolynomialSieve,
+    RetainedSieve,
+    SegmentedSieve,
     SieveOfAtkin,
     SieveOfEratosthenes,
     SieveOfEratosthenesAtomic,
@@ -10,4 +19,5 @@ pub use methods::{
     // WheelFactorisedPrimeCheck,
 };
 
+pub use arithmetic::{mertens, mobius_sieve, prime_omegas, totient, totient_sieve};
 pub use formulas::{list_n_primes, nth_prime, upper_bound_of_nth_prime};
@@ -0,0 +1,125 @@
+use num_bigint::BigUint;
+use num_traits::{ToPrimitive, Zero};
+
+use super::miller_rabin::{miller_rabin, strong_probable_prime};
+use crate::primes::formulas::jacobi;
+
+/// The primes below `100`, for trial division ahead of the probable prime tests.
+const TRIAL_PRIMES: [u32; 25] = [
+    2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53, 59, 61, 67, 71, 73, 79, 83, 89, 97,
+];
+
+/// The Jacobi symbol `(discriminant / num)` for an odd `discriminant` and an odd `num`,
+/// turned around by quadratic reciprocity so that only `num mod |discriminant|` is needed.
+fn jacobi_signed(discriminant: i64, num: &BigUint) -> i8 {
+    let magnitude = discriminant.unsigned_abs();
+    let residue = (num % magnitude).to_u64().unwrap_or(0);
+    let num_mod_4 = (num % 4_u32).to_u8().unwrap_or(0);
+
+    let mut symbol = jacobi(residue, magnitude);
+    if magnitude % 4 == 3 && num_mod_4 == 3 {
+        symbol = -symbol;
+    }
+    // `(-1 / num) = -1` exactly when `num = 3 mod 4`.
+    if discriminant < 0 && num_mod_4 == 3 {
+        symbol = -symbol;
+    }
+
+    symbol
+}
+
+/// `value` as a residue modulo `num`.
+fn residue(value: i64, num: &BigUint) -> BigUint {
+    let magnitude = BigUint::from(value.unsigned_abs()) % num;
+
+    match value < 0 && !magnitude.is_zero() {
+        true => num - magnitude,
+        false => magnitude,
+    }
+}
+
+/// Whether the odd `num` is a strong Lucas probable prime for the parameters `P = 1` and
+/// `Q = (1 - discriminant) / 4`.
+///
+/// With `num + 1 = odd * 2^twos`, this holds if `U(odd) = 0` or `V(odd * 2^r) = 0` for
+/// some `r < twos`, modulo `num`. The sequences are climbed along the bits of `odd` by
+/// `U(2k) = U(k)V(k)`, `V(2k) = V(k)^2 - 2Q^k` and
+/// `U(k + 1) = (U(k) + V(k)) / 2`, `V(k + 1) = (D U(k) + V(k)) / 2`.
+fn strong_lucas_probable_prime(num: &BigUint, discriminant: i64) -> bool {
+    let (discriminant_residue, q) = (
+        residue(discriminant, num),
+        residue((1 - discriminant) / 4, num),
+    );
+    // Halving modulo the odd `num`, for `value` below `num`.
+    let half = |value: BigUint| match value.bit(0) {
+        true => (value + num) >> 1,
+        false => value >> 1,
+    };
+    let double_v = |v: &BigUint, power: &BigUint| (v * v + (num << 1) - (power << 1)) % num;
+
+    let plus_one = num + 1_u32;
+    let twos = plus_one.trailing_zeros().unwrap_or(0);
+    let odd = &plus_one >> twos;
+
+    let (mut u, mut v, mut power) = (BigUint::from(1_u32), BigUint::from(1_u32), q.clone());
+    for bit in (0..odd.bits() - 1).rev() {
+        u = &u * &v % num;
+        v = double_v(&v, &power);
+        power = &power * &power % num;
+
+        if odd.bit(bit) {
+            (u, v) = (
+                half((&u + &v) % num),
+                half((&discriminant_residue * &u + &v) % num),
+            );
+            power = power * &q % num;
+        }
+    }
+
+    if u.is_zero() || v.is_zero() {
+        return true;
+    }
+
+    (1..twos).any(|_| {
+        v = double_v(&v, &power);
+        power = &power * &power % num;
+        v.is_zero()
+    })
+}
+
+/// Whether `num` is probably prime, by the Baillie-PSW test: a strong probable prime test
+/// to base `2`, followed by a strong Lucas probable prime test with the parameters of
+/// Selfridge's method A. No composite passing both is known, and there are none within
+/// [`u64`], for which the deterministic [`miller_rabin`] is used instead.
+pub fn bpsw(num: &BigUint) -> bool {
+    if let Ok(num) = u64::try_from(num) {
+        return miller_rabin(num);
+    }
+    if TRIAL_PRIMES.iter().any(|&prime| (num % prime).is_zero()) {
+        return false;
+    }
+    if !strong_probable_prime(num, 2) {
+        return false;
+    }
+    // A square has no discriminant with `(D / num) = -1`.
+    if &num.sqrt().pow(2) == num {
+        return false;
+    }
+
+    // The first of `5, -7, 9, -11, ...` with `(D / num) = -1`; `0` reveals a factor, as
+    // `num` is larger than `|D|`.
+    let selfridge = (0_i64..)
+        .map(|index| match index % 2 {
+            0 => 5 + 2 * index,
+            _ => -(5 + 2 * index),
+        })
+        .find_map(|discriminant| match jacobi_signed(discriminant, num) {
+            1 => None,
+            symbol => Some((discriminant, symbol)),
+        });
+
+    match selfridge {
+        Some((discriminant, -1)) => strong_lucas_probable_prime(num, discriminant),
+        _ => false,
+    }
+}
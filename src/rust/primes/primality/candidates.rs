@@ -0,0 +1,292 @@
+use ndarray::{Array2, Zip};
+use rayon::prelude::*;
+use std::cmp;
+use std::collections::HashMap;
+use std::hash::{BuildHasherDefault, Hasher};
+use std::io::{self, Write};
+
+use crate::primes::constructs::*;
+use crate::primes::formulas::{isqrt, mod_inverse, mul_mod, pow_mod};
+use crate::primes::SegmentedSieve;
+use crate::py_compatibility::enums;
+
+/// A grid of the candidates `k * base^n + offset`, for a list of multipliers `k` and
+/// every exponent `n` in `min_exponent..=max_exponent`, after removing those with a
+/// small prime factor.
+pub struct CandidateGrid {
+    pub multipliers: Vec<u64>,
+    pub base: u64,
+    pub min_exponent: u64,
+    pub max_exponent: u64,
+    pub offset: i64,
+
+    /// Index `[i, j]` indicates whether `multipliers[i] * base^(min_exponent + j) + offset`
+    /// is still a candidate.
+    mask: Array2<bool>,
+}
+impl CandidateGrid {
+    /// List the remaining candidates as `(k, n)`, in ascending order of `k` then `n`.
+    pub fn candidates(&self) -> Vec<(u64, u64)> {
+        self.mask
+            .indexed_iter()
+            .filter(|(_, &is_candidate)| is_candidate)
+            .map(|((row, column), _)| (self.multipliers[row], self.min_exponent + column as u64))
+            .collect()
+    }
+
+    /// Count the remaining candidates.
+    pub fn count(&self) -> u64 {
+        self.mask
+            .iter()
+            .filter(|&&is_candidate| is_candidate)
+            .count() as u64
+    }
+
+    /// Write the remaining candidates in the ABC format read by LLR and PFGW, i.e. a
+    /// header `ABC $a*base^$b+offset` followed by a line `k n` for every candidate.
+    pub fn write_abc(&self, mut writer: impl Write) -> io::Result<()> {
+        writeln!(writer, "ABC $a*{}^$b{:+}", self.base, self.offset)?;
+        for (k, n) in self.candidates() {
+            writeln!(writer, "{} {}", k, n)?;
+        }
+
+        writer.flush()
+    }
+}
+
+/// A multiplicative hash of the residues of the baby steps, which are already uniform
+/// but are too small for the top bits a [`HashMap`] relies on.
+#[derive(Default)]
+struct ResidueHasher(u64);
+impl Hasher for ResidueHasher {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        bytes
+            .iter()
+            .for_each(|&byte| self.write_u64(self.0 ^ byte as u64));
+    }
+
+    fn write_u64(&mut self, value: u64) {
+        self.0 = value.wrapping_mul(0x9E37_79B9_7F4A_7C15);
+    }
+}
+
+/// The baby steps of [`discrete_log`].
+type BabySteps = HashMap<u64, u64, BuildHasherDefault<ResidueHasher>>;
+
+/// The value of `k * base^n + offset`, or [`None`] if it overflows [`i128`].
+fn small_value(k: u64, base: u64, n: u64, offset: i64) -> Option<i128> {
+    (base as i128)
+        .checked_pow(u32::try_from(n).ok()?)?
+        .checked_mul(k as i128)?
+        .checked_add(offset as i128)
+}
+
+/// The smallest `steps * q + j` with `q` in `first_step..=last_step`, for which
+/// `base^(steps * q + j) = target` modulo `prime`, where `baby` maps each `base^j` to the
+/// smallest such `j < steps` and `giant` is `base^-steps`.
+fn discrete_log(
+    baby: &BabySteps,
+    (steps, giant): (u64, u64),
+    target: u64,
+    prime: u64,
+    (first_step, last_step): (u64, u64),
+) -> Option<u64> {
+    let mut value = mul_mod(target, pow_mod(giant, first_step, prime), prime);
+
+    for step in first_step..=last_step {
+        if let Some(&index) = baby.get(&value) {
+            return Some(step * steps + index);
+        }
+        value = mul_mod(value, giant, prime);
+    }
+
+    None
+}
+
+/// The inverse of every value modulo `prime`, or [`None`] for its multiples, by
+/// Montgomery's trick of inverting only their product.
+fn batch_inverse(values: &[u64], prime: u64) -> Vec<Option<u64>> {
+    let residues: Vec<u64> = values.iter().map(|value| value % prime).collect();
+    let prefixes: Vec<u64> = residues
+        .iter()
+        .scan(1, |product, &residue| {
+            if residue != 0 {
+                *product = mul_mod(*product, residue, prime);
+            }
+            Some(*product)
+        })
+        .collect();
+
+    let mut inverse = mod_inverse(prefixes.last().copied().unwrap_or(1), prime).unwrap_or(1);
+    let mut inverses = vec![None; values.len()];
+    for index in (0..values.len()).rev() {
+        if residues[index] != 0 {
+            let before = index.checked_sub(1).map_or(1, |before| prefixes[before]);
+
+            inverses[index] = Some(mul_mod(inverse, before, prime));
+            inverse = mul_mod(inverse, residues[index], prime);
+        }
+    }
+
+    inverses
+}
+
+/// Remove every candidate of `mask` divisible by `prime`, other than `prime` itself.
+///
+/// The candidate `k * b^n + c` is divisible by `prime` exactly when
+/// `b^n = -c * k^-1` modulo `prime`, so the first such `n` of each row is a discrete log,
+/// found by baby-step giant-step over the range of exponents. The baby steps are shared
+/// by every `k`, so there are `sqrt(K * L)` of them for `K` multipliers and `L`
+/// exponents, and `sqrt(L / K)` giant steps for each `k`. The rest follow every
+/// multiplicative order of `b` after the first.
+fn sieve_prime(
+    mask: &mut Array2<bool>,
+    multipliers: &[u64],
+    base: u64,
+    min_exponent: u64,
+    offset: i64,
+    prime: u64,
+) {
+    let length = mask.ncols() as u64;
+    let residue = offset.rem_euclid(prime as i64) as u64;
+
+    let mut remove = |row: usize, first: u64, step: u64| {
+        let k = multipliers[row];
+
+        (first..length).step_by(step as usize).for_each(|column| {
+            let n = min_exponent + column;
+            if n >= i128::BITS as u64 || small_value(k, base, n, offset) != Some(prime as i128) {
+                mask[[row, column as usize]] = false;
+            }
+        })
+    };
+
+    // Every candidate of the row is `offset` modulo the prime.
+    if base.is_multiple_of(prime) {
+        if residue == 0 {
+            (0..multipliers.len()).for_each(|row| remove(row, 0, 1));
+        }
+        return;
+    }
+    // Only the rows with `k` a multiple of the prime are `offset` modulo it.
+    if residue == 0 {
+        multipliers
+            .iter()
+            .enumerate()
+            .filter(|(_, &k)| k % prime == 0)
+            .for_each(|(row, _)| remove(row, 0, 1));
+        return;
+    }
+
+    let steps = cmp::min(isqrt(multipliers.len() as u64 * length) + 1, length);
+    let mut baby = BabySteps::with_capacity_and_hasher(steps as usize, Default::default());
+    let mut power = 1;
+    let mut order = None;
+    for index in 0..steps {
+        if index > 0 && power == 1 {
+            order = Some(index);
+            break;
+        }
+        baby.insert(power, index);
+        power = mul_mod(power, base % prime, prime);
+    }
+
+    // The base is coprime to the prime, so its powers have inverses.
+    let inverse = mod_inverse(base % prime, prime).unwrap_or(1);
+    let giant = (steps, pow_mod(inverse, steps, prime));
+
+    // Once the order is below the number of baby steps, they hold every power of the base.
+    let last_step = match order {
+        Some(_) => 0,
+        None => (length - 1) / steps,
+    };
+    let shift = pow_mod(inverse, min_exponent, prime);
+    let order = order
+        .or_else(|| discrete_log(&baby, giant, 1, prime, (1, last_step)))
+        .unwrap_or(length);
+
+    for (row, k_inverse) in batch_inverse(multipliers, prime).into_iter().enumerate() {
+        // Without an inverse, every candidate of the row is `offset` modulo the prime.
+        let target = match k_inverse {
+            Some(k_inverse) => mul_mod(mul_mod(prime - residue, k_inverse, prime), shift, prime),
+            None => continue,
+        };
+
+        if let Some(first) = discrete_log(&baby, giant, target, prime, (0, last_step)) {
+            remove(row, first, order);
+        }
+    }
+}
+
+/// Sieve the candidates `k * base^n + offset` for every `k` of `multipliers` and every
+/// `n` in `min_exponent..=max_exponent`, by the primes up to `max_prime`, as srsieve
+/// does for primality tests of such forms.
+///
+/// The primes are taken from a [`SegmentedSieve`], and each segment of them is sieved
+/// by [`sieve_prime`] in parallel. Candidates below `2` are removed too.
+///
+/// The exponents must be at least `1`, so that every candidate is `offset` modulo the
+/// primes dividing `base`.
+pub fn sieve_candidates(
+    sieve: enums::SieveMethod,
+    multipliers: &[u64],
+    base: u64,
+    min_exponent: u64,
+    max_exponent: u64,
+    offset: i64,
+    max_prime: u64,
+) -> CandidateGrid {
+    let length = (max_exponent + 1).saturating_sub(min_exponent) as usize;
+    let shape = (multipliers.len(), length);
+
+    let mut mask = match length {
+        0 => Array2::from_elem(shape, true),
+        _ => SegmentedSieve::new(sieve, max_prime)
+            .par_segments(2, max_prime)
+            .fold(
+                || Array2::from_elem(shape, true),
+                |mut mask, (start, segment)| {
+                    segment
+                        .iter()
+                        .enumerate()
+                        .filter(|(_, is_prime)| is_prime.is_true())
+                        .for_each(|(index, _)| {
+                            let prime = start + index as u64;
+                            sieve_prime(&mut mask, multipliers, base, min_exponent, offset, prime)
+                        });
+
+                    mask
+                },
+            )
+            .reduce(
+                || Array2::from_elem(shape, true),
+                |mut mask, other| {
+                    Zip::from(&mut mask)
+                        .and(&other)
+                        .for_each(|is_candidate, &other| *is_candidate &= other);
+
+                    mask
+                },
+            ),
+    };
+
+    for ((row, column), is_candidate) in mask.indexed_iter_mut() {
+        let n = min_exponent + column as u64;
+        if let Some(value) = small_value(multipliers[row], base, n, offset) {
+            *is_candidate &= value >= 2;
+        }
+    }
+
+    CandidateGrid {
+        multipliers: multipliers.to_vec(),
+        base,
+        min_exponent,
+        max_exponent,
+        offset,
+        mask,
+    }
+}
@@ -0,0 +1,48 @@
+use num_bigint::BigUint;
+use num_traits::One;
+
+/// Reduce `value` modulo the Fermat number `modulus = 2^bits + 1`.
+///
+/// Since `2^bits` is `-1` modulo `modulus`, the bits above `bits` are subtracted from
+/// the lower bits instead of a long division.
+fn reduce_fermat(value: BigUint, bits: u64, modulus: &BigUint) -> BigUint {
+    if value.bits() <= bits {
+        return value;
+    }
+
+    let low = &value & ((BigUint::one() << bits) - 1_u32);
+    let high = value >> bits;
+
+    match low >= high {
+        true => reduce_fermat(low - high, bits, modulus),
+        false => match reduce_fermat(high - low, bits, modulus) {
+            difference if difference.bits() == 0 => difference,
+            difference => modulus - difference,
+        },
+    }
+}
+
+/// The Fermat number `2^(2^index) + 1`.
+pub fn fermat_number(index: u32) -> BigUint {
+    (BigUint::one() << (1_u64 << index)) + 1_u32
+}
+
+/// Whether the Fermat number `F = 2^(2^index) + 1` is prime, by Pépin's test.
+///
+/// For `index >= 1`, `F` is prime if and only if `3^((F - 1) / 2) = -1` modulo `F`; as
+/// `(F - 1) / 2` is `2^(2^index - 1)`, this is `2^index - 1` squarings of `3`, each
+/// reduced by [`reduce_fermat`].
+pub fn pepin(index: u32) -> bool {
+    if index == 0 {
+        return true;
+    }
+
+    let bits = 1_u64 << index;
+    let modulus = fermat_number(index);
+
+    let power = (0..bits - 1).fold(BigUint::from(3_u32), |power, _| {
+        reduce_fermat(&power * &power, bits, &modulus)
+    });
+
+    power == modulus - 1_u32
+}
@@ -0,0 +1,109 @@
+use num_bigint::BigUint;
+use num_traits::One;
+use rayon::prelude::*;
+
+use crate::primes::constructs::*;
+use crate::primes::formulas::pow_mod;
+use crate::py_compatibility::enums;
+
+/// The number of candidate factors `2kp + 1` tried by [`mersenne_trial_factor`].
+const TRIAL_FACTOR_LIMIT: u64 = 2_u64.pow(16);
+
+/// The Mersenne number `2^exponent - 1`.
+pub fn mersenne_number(exponent: u64) -> BigUint {
+    (BigUint::one() << exponent) - 1_u32
+}
+
+/// Reduce `value` modulo the Mersenne number `modulus = 2^exponent - 1`.
+///
+/// Since `2^exponent` is `1` modulo `modulus`, the bits above `exponent` can be folded
+/// back onto the lower bits by a shift and an addition, instead of a long division.
+fn reduce_mersenne(mut value: BigUint, exponent: u64, modulus: &BigUint) -> BigUint {
+    while value.bits() > exponent {
+        value = (&value & modulus) + (value >> exponent);
+    }
+
+    match &value == modulus {
+        true => BigUint::default(),
+        false => value,
+    }
+}
+
+/// Search for a small factor of the Mersenne number `2^exponent - 1`, for an odd prime
+/// `exponent`.
+///
+/// Every factor `q` of `2^exponent - 1` is of the form `2k * exponent + 1`, and is
+/// `1` or `7` modulo `8`; this tries the first [`TRIAL_FACTOR_LIMIT`] values of `k`,
+/// stopping before `q^2` exceeds `2^exponent - 1` so that a prime is never its own
+/// factor.
+pub fn mersenne_trial_factor(exponent: u64) -> Option<u64> {
+    (1..=TRIAL_FACTOR_LIMIT)
+        .map_while(|k| {
+            let factor = 2_u64
+                .checked_mul(k)?
+                .checked_mul(exponent)?
+                .checked_add(1)?;
+
+            // `factor^2 <= 2^exponent - 1` if and only if `2 * bits(factor) <= exponent`,
+            // except when `factor` is a power of 2, which it never is.
+            (exponent >= 128 || 2 * (u64::BITS - factor.leading_zeros()) as u64 <= exponent)
+                .then_some(factor)
+        })
+        .filter(|factor| factor % 8 == 1 || factor % 8 == 7)
+        .find(|&factor| pow_mod(2, exponent, factor) == 1)
+}
+
+/// Whether the Mersenne number `2^exponent - 1` is prime, by the Lucas-Lehmer test.
+///
+/// `2^exponent - 1` can only be prime if `exponent` is prime; for an odd prime
+/// `exponent`, it is prime if and only if `s(exponent - 2)` is `0` modulo
+/// `2^exponent - 1`, where `s(0) = 4` and `s(i + 1) = s(i)^2 - 2`. Each squaring is
+/// reduced by [`reduce_mersenne`], without any division.
+///
+/// The test is skipped if [`mersenne_trial_factor`] finds a factor.
+pub fn lucas_lehmer(sieve: enums::SieveMethod, exponent: u64) -> bool {
+    match exponent {
+        0 | 1 => false,
+        2 => true,
+        _ if !sieve.is_prime(exponent) => false,
+        _ if mersenne_trial_factor(exponent).is_some() => false,
+        _ => {
+            let modulus = mersenne_number(exponent);
+            let two = BigUint::from(2_u32);
+
+            let residue = (0..exponent - 2).fold(BigUint::from(4_u32), |residue, _| {
+                let square = reduce_mersenne(&residue * &residue, exponent, &modulus);
+
+                match square < two {
+                    true => square + &modulus - &two,
+                    false => square - &two,
+                }
+            });
+
+            residue.bits() == 0
+        }
+    }
+}
+
+/// List the exponents `p` up to and including `max_exponent` in ascending order, for
+/// which the Mersenne number `2^p - 1` is prime.
+///
+/// Only the prime exponents are tested, in parallel.
+pub fn mersenne_exponents(sieve: enums::SieveMethod, max_exponent: u64) -> Vec<u64> {
+    sieve
+        .list_primes(max_exponent, None)
+        .into_par_iter()
+        .filter(|&exponent| lucas_lehmer(sieve, exponent))
+        .collect()
+}
+
+/// List the even perfect numbers `2^(p - 1) * (2^p - 1)` in ascending order, for every
+/// Mersenne prime `2^p - 1` with `p` up to and including `max_exponent`.
+///
+/// By the Euclid-Euler theorem, these are all the even perfect numbers.
+pub fn perfect_numbers(sieve: enums::SieveMethod, max_exponent: u64) -> Vec<BigUint> {
+    mersenne_exponents(sieve, max_exponent)
+        .into_iter()
+        .map(|exponent| mersenne_number(exponent) << (exponent - 1))
+        .collect()
+}
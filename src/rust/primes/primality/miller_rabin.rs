@@ -0,0 +1,77 @@
+use num_bigint::BigUint;
+use num_traits::One;
+
+use crate::primes::formulas::{mul_mod, pow_mod};
+
+/// The witnesses for which the Miller-Rabin test is deterministic for every [`u64`].
+const WITNESSES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+
+/// Whether `num` is a strong probable prime to the base `witness`, where
+/// `num - 1 = odd * 2^twos`.
+fn is_strong_probable_prime(num: u64, witness: u64, odd: u64, twos: u32) -> bool {
+    let mut power = pow_mod(witness, odd, num);
+    if power == 1 || power == num - 1 {
+        return true;
+    }
+
+    (1..twos).any(|_| {
+        power = mul_mod(power, power, num);
+        power == num - 1
+    })
+}
+
+/// Whether `num` is prime, by the Miller-Rabin test over the first 12 primes as the
+/// witnesses, which is deterministic for every [`u64`].
+pub fn miller_rabin(num: u64) -> bool {
+    if num < 2 {
+        return false;
+    }
+    if let Some(&witness) = WITNESSES
+        .iter()
+        .find(|&&witness| num.is_multiple_of(witness))
+    {
+        return num == witness;
+    }
+
+    let twos = (num - 1).trailing_zeros();
+    let odd = (num - 1) >> twos;
+
+    WITNESSES
+        .iter()
+        .all(|&witness| is_strong_probable_prime(num, witness, odd, twos))
+}
+
+/// Whether the odd `num` is a strong probable prime to the base `witness`.
+pub fn strong_probable_prime(num: &BigUint, witness: u64) -> bool {
+    let minus_one = num - 1_u32;
+    let twos = minus_one.trailing_zeros().unwrap_or(0);
+    let odd = &minus_one >> twos;
+
+    let mut power = BigUint::from(witness).modpow(&odd, num);
+    if power.is_one() || power == minus_one {
+        return true;
+    }
+
+    (1..twos).any(|_| {
+        power = &power * &power % num;
+        power == minus_one
+    })
+}
+
+/// Whether `num` is probably prime, by the Miller-Rabin test over the first 12 primes as
+/// the witnesses; this is exact whenever `num` fits into a [`u64`].
+pub fn probable_prime(num: &BigUint) -> bool {
+    if let Ok(num) = u64::try_from(num) {
+        return miller_rabin(num);
+    }
+    if WITNESSES
+        .iter()
+        .any(|&witness| num % witness == 0_u32.into())
+    {
+        return false;
+    }
+
+    WITNESSES
+        .iter()
+        .all(|&witness| strong_probable_prime(num, witness))
+}
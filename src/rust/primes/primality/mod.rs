@@ -0,0 +1,20 @@
+//! Primality tests for numbers too large for any sieve, both general and for special
+//! forms, and the sieving of such candidates by small primes.
+
+pub mod bpsw;
+pub mod candidates;
+pub mod fermat;
+pub mod mersenne;
+pub mod miller_rabin;
+pub mod primorial;
+pub mod proth;
+
+pub use bpsw::bpsw;
+pub use candidates::{sieve_candidates, CandidateGrid};
+pub use fermat::{fermat_number, pepin};
+pub use mersenne::{
+    lucas_lehmer, mersenne_exponents, mersenne_number, mersenne_trial_factor, perfect_numbers,
+};
+pub use miller_rabin::{miller_rabin, probable_prime, strong_probable_prime};
+pub use primorial::{factorial_primes, primorial_primes};
+pub use proth::{proth, proth_number};
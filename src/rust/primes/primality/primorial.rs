@@ -0,0 +1,68 @@
+use num_bigint::BigUint;
+use num_traits::{CheckedSub, One};
+use rayon::prelude::*;
+
+use super::bpsw::bpsw;
+use crate::primes::constructs::*;
+use crate::py_compatibility::enums;
+
+/// `product + offset`, or [`None`] if it is negative.
+fn offset_product(product: &BigUint, offset: i64) -> Option<BigUint> {
+    match offset >= 0 {
+        true => Some(product + offset.unsigned_abs()),
+        false => product.checked_sub(&BigUint::from(offset.unsigned_abs())),
+    }
+}
+
+/// List every factor in `factors`, for which the running product of `factors` up to and
+/// including it, plus `offset`, is a probable prime by [`bpsw`].
+///
+/// The running products are built one multiplication at a time, a batch at a time, and
+/// each batch is tested in parallel.
+fn probable_prime_products(factors: &[u64], offset: i64) -> Vec<u64> {
+    let batch_size = rayon::current_num_threads() * 4;
+    let mut product = BigUint::one();
+    let mut found = Vec::new();
+
+    for batch in factors.chunks(batch_size) {
+        let products: Vec<(u64, BigUint)> = batch
+            .iter()
+            .map(|&factor| {
+                product *= factor;
+                (factor, product.clone())
+            })
+            .collect();
+
+        found.par_extend(
+            products
+                .into_par_iter()
+                .filter(|(_, product)| {
+                    offset_product(product, offset).is_some_and(|num| bpsw(&num))
+                })
+                .map(|(factor, _)| factor),
+        );
+    }
+
+    found
+}
+
+/// List the primes `p` up to and including `ubound` in ascending order, for which the
+/// primorial `p# + offset` is a probable prime by [`bpsw`].
+pub fn primorial_primes(sieve: enums::SieveMethod, ubound: u64, offset: i64) -> Vec<u64> {
+    probable_prime_products(&sieve.list_primes(ubound, None), offset)
+}
+
+/// List the `n` up to and including `ubound` in ascending order, for which the factorial
+/// `n! + offset` is a probable prime by [`bpsw`].
+pub fn factorial_primes(ubound: u64, offset: i64) -> Vec<u64> {
+    // `0! = 1! = 1`, which the running products start from.
+    let zero = offset_product(&BigUint::one(), offset).is_some_and(|num| bpsw(&num));
+
+    zero.then_some(0)
+        .into_iter()
+        .chain(probable_prime_products(
+            &(1..=ubound).collect::<Vec<u64>>(),
+            offset,
+        ))
+        .collect()
+}
@@ -0,0 +1,49 @@
+use num_bigint::BigUint;
+
+use crate::primes::formulas::jacobi;
+
+/// The Proth number `k * 2^exponent + 1`, or [`None`] if `k` is not odd and less than
+/// `2^exponent`.
+pub fn proth_number(k: u64, exponent: u64) -> Option<BigUint> {
+    let is_proth = k % 2 == 1 && (exponent >= u64::BITS as u64 || k < 1 << exponent);
+
+    is_proth.then(|| (BigUint::from(k) << exponent) + 1_u32)
+}
+
+/// Whether the Proth number `N = k * 2^exponent + 1` is prime, by Proth's theorem; or
+/// [`None`] if it is not a Proth number.
+///
+/// `N` is prime if and only if `a^((N - 1) / 2) = -1` modulo `N`, for any `a` which is a
+/// quadratic non-residue modulo `N`. Such an `a` is found as the smallest odd prime
+/// with a Jacobi symbol `(a / N)` of `-1`, which by quadratic reciprocity is
+/// `(N mod a / a)` since `N` is `1` modulo `4`. This makes the test deterministic.
+pub fn proth(k: u64, exponent: u64) -> Option<bool> {
+    let number = proth_number(k, exponent)?;
+
+    // `N = 3` is the only Proth number that is `3` modulo `4`, and squares do not have
+    // any quadratic non-residues.
+    if number == BigUint::from(3_u32) {
+        return Some(true);
+    }
+    if number.sqrt().pow(2) == number {
+        return Some(false);
+    }
+
+    let witness = (3_u64..)
+        .step_by(2)
+        .filter(|candidate| {
+            (3..*candidate)
+                .step_by(2)
+                .all(|divisor| candidate % divisor != 0)
+        })
+        .find(|&candidate| {
+            let residue = (&number % candidate).iter_u64_digits().next().unwrap_or(0);
+
+            jacobi(residue, candidate) == -1
+        })?;
+
+    let minus_one = &number - 1_u32;
+    let power = BigUint::from(witness).modpow(&(&minus_one >> 1), &number);
+
+    Some(power == minus_one)
+}
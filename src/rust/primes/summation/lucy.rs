@@ -0,0 +1,173 @@
+use crate::primes::formulas::isqrt;
+
+/// Sums of `p^power` over all primes `p <= value`, for every distinct `value` of the
+/// form `num / i`, calculated with the Lucy_Hedgehog method in `O(num^(3/4))`.
+///
+/// There are only around `2 * sqrt(num)` such values: the small ones up to `sqrt(num)`
+/// are stored by value, while the large ones are stored by `i = num / value`.
+///
+/// The sums can either be exact, or reduced modulo a given modulus.
+pub struct LucyTable {
+    num: u64,
+    sqrt: u64,
+    small: Vec<u128>,
+    large: Vec<u128>,
+}
+impl LucyTable {
+    /// Build the table of exact sums from `primes`, which must contain every prime up
+    /// to `sqrt(num)`.
+    ///
+    /// Returns [`None`] if the sums overflow [`u128`], or if `power` is above `3`.
+    pub fn new(primes: &[u64], num: u64, power: u32) -> Option<Self> {
+        Self::with_modulus(primes, num, power, None)
+    }
+
+    /// Build the table from `primes`, which must contain every prime up to
+    /// `sqrt(num)`, optionally reducing every sum modulo `modulus`.
+    ///
+    /// Returns [`None`] if the sums overflow [`u128`], if `power` is above `3`, or if
+    /// `modulus` is `0`.
+    pub fn with_modulus(
+        primes: &[u64],
+        num: u64,
+        power: u32,
+        modulus: Option<u64>,
+    ) -> Option<Self> {
+        if modulus == Some(0) {
+            return None;
+        }
+
+        let arithmetic = Arithmetic(modulus.map(u128::from));
+        let sqrt = isqrt(num);
+
+        // Start with the sums of `n^power` over all `2 <= n <= value`, then remove the
+        // composites one prime at a time.
+        let initial = |value: u64| match value {
+            0 => Some(0),
+            _ => Some(arithmetic.sub(sum_of_powers(value, power, modulus)?, 1)),
+        };
+
+        let mut small = (0..=sqrt).map(initial).collect::<Option<Vec<u128>>>()?;
+        let mut large = (0..=sqrt)
+            .map(|index| match index {
+                0 => Some(0),
+                _ => initial(num / index),
+            })
+            .collect::<Option<Vec<u128>>>()?;
+
+        for &prime in primes.iter().take_while(|&&prime| prime <= sqrt) {
+            let prime_power = arithmetic.reduce((prime as u128).pow(power));
+            let below_prime = small[(prime - 1) as usize];
+            let square = prime * prime;
+
+            for index in 1..=(num / square).min(sqrt) {
+                let quotient_index = index * prime;
+                let quotient = if quotient_index <= sqrt {
+                    large[quotient_index as usize]
+                } else {
+                    small[(num / quotient_index) as usize]
+                };
+
+                large[index as usize] = arithmetic.sub(
+                    large[index as usize],
+                    arithmetic.mul(prime_power, arithmetic.sub(quotient, below_prime)),
+                );
+            }
+
+            for value in (square..=sqrt).rev() {
+                let quotient = small[(value / prime) as usize];
+
+                small[value as usize] = arithmetic.sub(
+                    small[value as usize],
+                    arithmetic.mul(prime_power, arithmetic.sub(quotient, below_prime)),
+                );
+            }
+        }
+
+        Some(Self {
+            num,
+            sqrt,
+            small,
+            large,
+        })
+    }
+
+    /// The sum of `p^power` over all primes `p <= value`.
+    ///
+    /// `value` must be of the form `num / i` for some `i`.
+    pub fn get(&self, value: u64) -> u128 {
+        if value <= self.sqrt {
+            self.small[value as usize]
+        } else {
+            self.large[(self.num / value) as usize]
+        }
+    }
+}
+
+/// Exact or modular arithmetic over [`u128`].
+///
+/// Any modulus is below `2^64`, so the product of two reduced values never overflows.
+#[derive(Clone, Copy)]
+struct Arithmetic(Option<u128>);
+impl Arithmetic {
+    fn reduce(self, value: u128) -> u128 {
+        match self.0 {
+            Some(modulus) => value % modulus,
+            None => value,
+        }
+    }
+
+    fn sub(self, lhs: u128, rhs: u128) -> u128 {
+        match self.0 {
+            Some(modulus) => (lhs + modulus - rhs) % modulus,
+            None => lhs - rhs,
+        }
+    }
+
+    fn mul(self, lhs: u128, rhs: u128) -> u128 {
+        self.reduce(lhs * rhs)
+    }
+}
+
+/// The sum of `n^power` over all `1 <= n <= value`, using the closed form formulas,
+/// optionally reduced modulo `modulus`.
+///
+/// Returns [`None`] if the exact sum overflows [`u128`], or if `power` is above `3`.
+pub fn sum_of_powers(value: u64, power: u32, modulus: Option<u64>) -> Option<u128> {
+    let arithmetic = Arithmetic(modulus.map(u128::from));
+    let value = value as u128;
+
+    // Each formula is a product of factors, divided before multiplying so that only
+    // the final product can overflow.
+    let factors = match power {
+        0 => vec![value],
+        1 => halve_one_of(vec![value, value + 1]),
+        2 => {
+            let mut factors = halve_one_of(vec![value, value + 1, 2 * value + 1]);
+            let triple = factors.iter().position(|factor| factor % 3 == 0)?;
+            factors[triple] /= 3;
+
+            factors
+        }
+        3 => {
+            let triangular = halve_one_of(vec![value, value + 1]);
+            [triangular.clone(), triangular].concat()
+        }
+        _ => return None,
+    };
+
+    factors
+        .into_iter()
+        .try_fold(1_u128, |product, factor| match modulus {
+            Some(_) => Some(arithmetic.mul(product, arithmetic.reduce(factor))),
+            None => product.checked_mul(factor),
+        })
+}
+
+fn halve_one_of(mut factors: Vec<u128>) -> Vec<u128> {
+    if let Some(even) = factors.iter().position(|factor| factor.is_multiple_of(2)) {
+        factors[even] /= 2;
+    }
+
+    factors
+}
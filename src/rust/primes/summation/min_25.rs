@@ -0,0 +1,104 @@
+use super::LucyTable;
+use crate::primes::constructs::*;
+use crate::primes::formulas::isqrt;
+use crate::py_compatibility::enums;
+
+/// Calculate `sum(f(n) for n in 1..=num)` for a [`PrimePolynomialFn`] `f`, using the
+/// min_25 sieve in around `O(num^(3/4) / log(num))`.
+///
+/// The sum over primes is assembled from one [`LucyTable`] per term of
+/// [`PrimePolynomialFn::prime_polynomial()`]; the composites are then added back by
+/// recursing over their lowest prime factors, all of which are below `sqrt(num)`. The
+/// primes up to `sqrt(num)` are listed using `sieve`.
+///
+/// Returns [`None`] if any of the intermediate sums overflow [`i128`].
+pub fn min_25_sum<F>(function: &F, sieve: enums::SieveMethod, num: u64) -> Option<i128>
+where
+    F: PrimePolynomialFn,
+{
+    if num == 0 {
+        return Some(0);
+    }
+
+    let primes = sieve.list_primes(isqrt(num), None);
+
+    let tables = function
+        .prime_polynomial()
+        .into_iter()
+        .enumerate()
+        .filter(|&(_, coefficient)| coefficient != 0)
+        .map(|(power, coefficient)| {
+            Some((coefficient, LucyTable::new(&primes, num, power as u32)?))
+        })
+        .collect::<Option<Vec<_>>>()?;
+
+    // `prefix[j]` is the sum of `f(p)` over the first `j` primes.
+    let prefix = primes.iter().try_fold(vec![0_i128], |mut prefix, &prime| {
+        let value = function.at_prime_power(prime, 1).into();
+        prefix.push(prefix.last()?.checked_add(value)?);
+        Some(prefix)
+    })?;
+
+    let engine = Min25 {
+        function,
+        primes: &primes,
+        prefix: &prefix,
+        tables: &tables,
+    };
+
+    engine.sum_from(num, 0)?.checked_add(1)
+}
+
+struct Min25<'a, F> {
+    function: &'a F,
+    primes: &'a [u64],
+    prefix: &'a [i128],
+    tables: &'a [(i128, LucyTable)],
+}
+impl<'a, F> Min25<'a, F>
+where
+    F: PrimePolynomialFn,
+{
+    /// The sum of `f(p)` over all primes `p <= value`.
+    fn sum_of_primes(&self, value: u64) -> Option<i128> {
+        self.tables
+            .iter()
+            .try_fold(0_i128, |total, (coefficient, table)| {
+                let sum = i128::try_from(table.get(value)).ok()?;
+                total.checked_add(coefficient.checked_mul(sum)?)
+            })
+    }
+
+    /// The sum of `f(n)` over all `2 <= n <= value` whose lowest prime factor is at
+    /// least `primes[index]`.
+    fn sum_from(&self, value: u64, index: usize) -> Option<i128> {
+        let mut total = self.sum_of_primes(value)?.checked_sub(self.prefix[index])?;
+
+        for (offset, &prime) in self.primes[index..].iter().enumerate() {
+            if prime * prime > value {
+                break;
+            }
+
+            let mut prime_power = prime;
+            let mut exponent = 1;
+
+            // Composites of the form `prime^exponent * m` where `m` only has prime
+            // factors above `prime`, plus the prime power `prime^(exponent + 1)`.
+            while prime_power * prime <= value {
+                let at_power: i128 = self.function.at_prime_power(prime, exponent).into();
+                let at_next_power: i128 = self.function.at_prime_power(prime, exponent + 1).into();
+
+                let cofactors = self.sum_from(value / prime_power, index + offset + 1)?;
+
+                total = total
+                    .checked_add(at_power.checked_mul(cofactors)?)?
+                    .checked_add(at_next_power)?;
+
+                prime_power *= prime;
+                exponent += 1;
+            }
+        }
+
+        Some(total)
+    }
+}
@@ -0,0 +1,9 @@
+//! Sub-linear summations over primes and multiplicative functions.
+
+pub mod lucy;
+pub mod min_25;
+pub mod prime_sums;
+
+pub use lucy::{sum_of_powers, LucyTable};
+pub use min_25::min_25_sum;
+pub use prime_sums::{prime_pi, sum_primes};
@@ -0,0 +1,31 @@
+use super::LucyTable;
+use crate::primes::constructs::*;
+use crate::primes::formulas::isqrt;
+use crate::py_compatibility::enums;
+
+/// Calculate the sum of `p^power` over all primes `p <= num`, optionally reduced
+/// modulo `modulus`, in `O(num^(3/4))`.
+///
+/// Only the primes up to `sqrt(num)` are listed from `sieve`; the rest of the sum is
+/// found through a [`LucyTable`].
+///
+/// Returns [`None`] if the exact sum overflows [`u128`], if `power` is above `3`, or
+/// if `modulus` is `0`.
+pub fn sum_primes(
+    sieve: enums::SieveMethod,
+    num: u64,
+    power: u32,
+    modulus: Option<u64>,
+) -> Option<u128> {
+    let primes = sieve.list_primes(isqrt(num), None);
+
+    Some(LucyTable::with_modulus(&primes, num, power, modulus)?.get(num))
+}
+
+/// Count the number of primes up to and including `num` in `O(num^(3/4))`, without
+/// sieving beyond `sqrt(num)`.
+///
+/// This is [`sum_primes`] with a `power` of `0`.
+pub fn prime_pi(sieve: enums::SieveMethod, num: u64) -> u64 {
+    sum_primes(sieve, num, 0, None).expect("prime counts always fit into u128") as u64
+}
@@ -5,7 +5,14 @@ use strum_macros::EnumIter;
 
 use pyo3::prelude::*;
 
+use crate::primes::analysis::{
+    fermat_quotient, fibonacci_quotient, sacks_length, sacks_position, ulam_length, ulam_position,
+    wilson_quotient,
+};
+use crate::primes::arithmetic::{DivisorSigma, EulerTotient, Liouville, Mobius};
 use crate::primes::constructs::OwnedSieve;
+use crate::primes::summation::min_25_sum;
+use crate::primes::LinearForm;
 use crate::primes::{
     constructs::{CanSieve, IsAtomicSieve},
     SieveOfAtkin, SieveOfEratosthenes, SieveOfEratosthenesAtomic, SieveOfEratosthenesThreaded,
@@ -69,3 +76,191 @@ impl CanSieve<bool> for SieveMethod {
         }
     }
 }
+
+/// Multiplicative functions with a known form at primes.
+///
+/// To be used with :func:`~rust_primes.summatory`
+#[pyclass(module = "rust_primes")]
+#[derive(Copy, Clone, Debug, Hash, EnumIter)]
+#[allow(non_camel_case_types, clippy::upper_case_acronyms)]
+pub enum ArithmeticFunction {
+    // Follows Python casing conventions.
+    /// Euler's totient, the number of integers up to ``n`` coprime to ``n``.
+    TOTIENT,
+
+    /// The Möbius function; its sum is the Mertens function.
+    MOBIUS,
+
+    /// The Liouville function, ``(-1)`` raised to the number of prime factors of ``n``.
+    LIOUVILLE,
+
+    /// The number of divisors of ``n``.
+    DIVISOR_COUNT,
+
+    /// The sum of the divisors of ``n``.
+    DIVISOR_SUM,
+}
+#[pymethods]
+impl ArithmeticFunction {
+    fn __hash__(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        self.hash(&mut hasher);
+        hasher.finish()
+    }
+}
+impl ArithmeticFunction {
+    /// The sum of this function over `1..=num`, or [`None`] if it overflows [`i128`].
+    pub fn summatory(&self, sieve: SieveMethod, num: u64) -> Option<i128> {
+        match self {
+            Self::TOTIENT => min_25_sum(&EulerTotient, sieve, num),
+            Self::MOBIUS => min_25_sum(&Mobius, sieve, num),
+            Self::LIOUVILLE => min_25_sum(&Liouville, sieve, num),
+            Self::DIVISOR_COUNT => min_25_sum(&DivisorSigma(0), sieve, num),
+            Self::DIVISOR_SUM => min_25_sum(&DivisorSigma(1), sieve, num),
+        }
+    }
+}
+
+/// Kinds of Cunningham chains.
+///
+/// To be used with :func:`~rust_primes.cunningham_chains`
+#[pyclass(module = "rust_primes")]
+#[derive(Copy, Clone, Debug, Hash, EnumIter)]
+#[allow(non_camel_case_types, clippy::upper_case_acronyms)]
+pub enum CunninghamKind {
+    // Follows Python casing conventions.
+    /// Each member is twice the previous plus one, i.e. ``p, 2p + 1, 4p + 3, ...``; the
+    /// members other than the last are Sophie Germain primes.
+    FIRST,
+
+    /// Each member is twice the previous minus one, i.e. ``p, 2p - 1, 4p - 3, ...``.
+    SECOND,
+}
+#[pymethods]
+impl CunninghamKind {
+    fn __hash__(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        self.hash(&mut hasher);
+        hasher.finish()
+    }
+}
+impl CunninghamKind {
+    /// The `index`-th member of the chain starting from `n`, i.e.
+    /// `2^index * n ± (2^index - 1)`, or [`None`] if the coefficients overflow.
+    pub fn member(&self, index: u32) -> Option<LinearForm> {
+        let multiplier = 1_i64.checked_shl(index).filter(|&power| power > 0)?;
+
+        Some(match self {
+            Self::FIRST => LinearForm::new(multiplier as u64, multiplier - 1),
+            Self::SECOND => LinearForm::new(multiplier as u64, 1 - multiplier),
+        })
+    }
+}
+
+/// Sides from which the digits of truncatable primes are removed.
+///
+/// To be used with :func:`~rust_primes.truncatable_primes`
+#[pyclass(module = "rust_primes")]
+#[derive(Copy, Clone, Debug, Hash, EnumIter)]
+#[allow(non_camel_case_types, clippy::upper_case_acronyms)]
+pub enum TruncationSide {
+    // Follows Python casing conventions.
+    /// The leading digits are removed one by one, e.g. ``3137, 137, 37, 7``; no digit may
+    /// be ``0``.
+    LEFT,
+
+    /// The trailing digits are removed one by one, e.g. ``7393, 739, 73, 7``.
+    RIGHT,
+}
+#[pymethods]
+impl TruncationSide {
+    fn __hash__(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        self.hash(&mut hasher);
+        hasher.finish()
+    }
+}
+
+/// Kinds of primes defined by a quotient modulo `p^2`.
+///
+/// To be used with :func:`~rust_primes.special_primes`
+#[pyclass(module = "rust_primes")]
+#[derive(Copy, Clone, Debug, Hash, EnumIter)]
+#[allow(non_camel_case_types, clippy::upper_case_acronyms)]
+pub enum SpecialPrime {
+    // Follows Python casing conventions.
+    /// Primes ``p`` with ``2^(p - 1) = 1 mod p^2``.
+    WIEFERICH,
+
+    /// Primes ``p`` with ``(p - 1)! = -1 mod p^2``.
+    WILSON,
+
+    /// Primes ``p`` dividing the Fibonacci number ``F(p - (p/5))`` twice.
+    WALL_SUN_SUN,
+}
+#[pymethods]
+impl SpecialPrime {
+    fn __hash__(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        self.hash(&mut hasher);
+        hasher.finish()
+    }
+}
+impl SpecialPrime {
+    /// The quotient of `prime` for this kind, i.e. its defining residue modulo `prime^2`
+    /// divided by `prime`, which is `0` exactly when it is of this kind; or [`None`] if it
+    /// is not defined, or `prime` is not below `2^63`.
+    pub fn quotient(&self, prime: u64) -> Option<u64> {
+        match self {
+            Self::WIEFERICH => fermat_quotient(2, prime),
+            Self::WILSON => wilson_quotient(prime),
+            Self::WALL_SUN_SUN => fibonacci_quotient(prime),
+        }
+    }
+}
+
+/// Kinds of spirals to lay the numbers out on.
+///
+/// To be used with :func:`~rust_primes.prime_spiral`
+#[pyclass(module = "rust_primes")]
+#[derive(Copy, Clone, Debug, Default, Hash, EnumIter)]
+#[allow(non_camel_case_types, clippy::upper_case_acronyms)]
+pub enum SpiralKind {
+    // Follows Python casing conventions.
+    /// The square spiral of Ulam, one number per pixel. *This is the default.*
+    #[default]
+    ULAM,
+
+    /// The Archimedean spiral of Sacks, with each number ``n`` at radius ``sqrt(n)``, so
+    /// that the perfect squares line up along the right.
+    SACKS,
+}
+#[pymethods]
+impl SpiralKind {
+    fn __hash__(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        self.hash(&mut hasher);
+        hasher.finish()
+    }
+}
+impl SpiralKind {
+    /// The number of numbers from `start` onwards laid out to fill a square of `side`.
+    pub fn length(&self, side: usize, start: u64) -> u64 {
+        match self {
+            Self::ULAM => ulam_length(side),
+            Self::SACKS => sacks_length(side).saturating_sub(start),
+        }
+    }
+
+    /// The `[row, column]` of the number `num` on a spiral from `start` onwards in a square
+    /// of `side`, or [`None`] if it falls outside of the square.
+    ///
+    /// An Ulam spiral starts from `start` at the centre, while a Sacks spiral always lays
+    /// every number out at its own radius.
+    pub fn position(&self, num: u64, start: u64, side: usize) -> Option<(usize, usize)> {
+        match self {
+            Self::ULAM => ulam_position(num - start, side),
+            Self::SACKS => sacks_position(num, side),
+        }
+    }
+}
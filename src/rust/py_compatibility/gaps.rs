@@ -0,0 +1,28 @@
+use numpy::IntoPyArray;
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+use crate::primes::analysis::{PrimeGap, PrimeGapStatistics};
+
+impl IntoPy<PyObject> for PrimeGap {
+    /// Convert into a `(start, gap, merit)` tuple.
+    fn into_py(self, py: Python<'_>) -> PyObject {
+        (self.start, self.gap, self.merit()).into_py(py)
+    }
+}
+
+impl PrimeGapStatistics {
+    /// Convert into a `dict`, with the histogram as a `numpy` array.
+    pub fn into_py_dict(self, py: Python<'_>) -> PyResult<PyObject> {
+        let dict = PyDict::new(py);
+
+        dict.set_item("first", self.first)?;
+        dict.set_item("last", self.last)?;
+        dict.set_item("histogram", self.histogram.into_pyarray(py))?;
+        dict.set_item("records", self.records.into_py(py))?;
+        dict.set_item("first_occurrences", self.first_occurrences.into_py(py))?;
+        dict.set_item("max_merit", self.max_merit.into_py(py))?;
+
+        Ok(dict.into())
+    }
+}
@@ -2,3 +2,5 @@
 /// so that they can have compatibility with PyObject.
 ///
 pub mod enums;
+pub mod gaps;
+pub mod polynomials;
@@ -0,0 +1,20 @@
+use numpy::IntoPyArray;
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+use crate::primes::analysis::PolynomialRoots;
+
+impl PolynomialRoots {
+    /// Convert into a `dict`, with the compressed sparse rows as `numpy` arrays.
+    pub fn into_py_dict(self, py: Python<'_>) -> PyResult<PyObject> {
+        let dict = PyDict::new(py);
+        let alpha = self.murphy_alpha();
+
+        dict.set_item("primes", self.primes.into_pyarray(py))?;
+        dict.set_item("offsets", self.offsets.into_pyarray(py))?;
+        dict.set_item("roots", self.roots.into_pyarray(py))?;
+        dict.set_item("alpha", alpha)?;
+
+        Ok(dict.into())
+    }
+}
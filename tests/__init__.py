@@ -0,0 +1,9 @@
+# -*- coding: utf-8 -*-
+import math
+
+
+def is_prime(num: int) -> bool:
+    """
+    Whether ``num`` is prime by trial division, independently of any sieve.
+    """
+    return num > 1 and all(num % _divisor for _divisor in range(2, math.isqrt(num) + 1))
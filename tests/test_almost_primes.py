@@ -0,0 +1,76 @@
+# -*- coding: utf-8 -*-
+import pytest
+
+import rust_primes
+
+UBOUND = 100000
+
+
+def _big_omegas(num: int) -> list:
+    """
+    ``Omega(n)`` for every ``n`` up to ``num``, by trial division with the primes.
+    """
+    _omegas = [0] * (num + 1)
+    _remainders = list(range(num + 1))
+    for _prime in rust_primes.list_primes(int(num**0.5)):
+        _power = _prime
+        while _power <= num:
+            for _multiple in range(_power, num + 1, _power):
+                _omegas[_multiple] += 1
+                _remainders[_multiple] //= _prime
+            _power *= _prime
+
+    return [
+        _omega + (_remainder > 1) for _omega, _remainder in zip(_omegas, _remainders)
+    ]
+
+
+OMEGAS = _big_omegas(UBOUND)
+
+
+@pytest.mark.parametrize(["k"], [[_k] for _k in range(0, 8)])
+def test_list_almost_primes(k):
+    """
+    Test the listed almost primes against their number of prime factors.
+    """
+    _expected = [_num for _num in range(1, UBOUND + 1) if OMEGAS[_num] == k]
+
+    assert rust_primes.list_almost_primes(k, UBOUND) == _expected
+    assert rust_primes.list_almost_primes(k, 5000, lbound=1234) == [
+        _num for _num in _expected if 1234 <= _num <= 5000
+    ]
+
+
+@pytest.mark.parametrize(["k"], [[_k] for _k in range(0, 8)])
+def test_count_almost_primes(k):
+    """
+    Test the counts of almost primes against their number of prime factors.
+    """
+    for _num in [0, 1, 2, 7, 8, 100, 999, 1024, 65536, UBOUND]:
+        assert rust_primes.count_almost_primes(k, _num) == sum(
+            1 for _n in range(1, _num + 1) if OMEGAS[_n] == k
+        )
+
+
+@pytest.mark.parametrize(
+    ["exponent", "expected"],
+    [[1, 4], [2, 34], [3, 299], [4, 2625], [6, 210035], [9, 160788536]],
+)
+def test_count_semiprimes(exponent, expected):
+    """
+    Test the counts of semiprimes up to powers of ten against the known ones.
+    """
+    assert rust_primes.count_almost_primes(2, 10**exponent) == expected
+
+
+def test_semiprimes_in():
+    """
+    Test the semiprimes in a range against the counts at both ends.
+    """
+    _lbound, _ubound = 10**10, 10**10 + 10**6
+    _semiprimes = rust_primes.semiprimes_in(_lbound, _ubound)
+
+    assert len(_semiprimes) == rust_primes.count_almost_primes(
+        2, _ubound
+    ) - rust_primes.count_almost_primes(2, _lbound - 1)
+    assert all(OMEGAS[_num] == 2 for _num in rust_primes.semiprimes_in(0, UBOUND))
@@ -0,0 +1,85 @@
+# -*- coding: utf-8 -*-
+import pytest
+
+import rust_primes
+
+RANGES = ([0, 0], [0, 30], [1, 3], [0, 10**5], [10**5, 2 * 10**5])
+
+
+def _member(kind, prime, index):
+    """
+    The ``index``-th member of the Cunningham chain of ``kind`` starting from ``prime``.
+    """
+    if kind == rust_primes.CunninghamKind.FIRST:
+        return 2**index * prime + (2**index - 1)
+
+    return 2**index * prime - (2**index - 1)
+
+
+@pytest.mark.parametrize(
+    ["kind"], ([rust_primes.CunninghamKind.FIRST], [rust_primes.CunninghamKind.SECOND])
+)
+@pytest.mark.parametrize(["length"], ([1], [2], [3], [4], [5]))
+@pytest.mark.parametrize(["lbound", "ubound"], RANGES)
+def test_cunningham_chains(kind, length, lbound, ubound):
+    """
+    Test if the chains match those found from :func:`list_primes`.
+    """
+    _primes = set(rust_primes.list_primes(max(_member(kind, ubound, length - 1), 0)))
+    _expected = [
+        _start
+        for _start in range(lbound, ubound + 1)
+        if all(_member(kind, _start, _index) in _primes for _index in range(length))
+    ]
+
+    assert rust_primes.cunningham_chains(length, lbound, ubound, kind=kind) == _expected
+    assert rust_primes.cunningham_chains(
+        length, lbound, ubound, kind=kind, count_only=True
+    ) == len(_expected)
+
+
+@pytest.mark.parametrize(
+    ["kind", "length", "first"],
+    (
+        [rust_primes.CunninghamKind.FIRST, 6, 89],
+        [rust_primes.CunninghamKind.FIRST, 7, 1_122_659],
+        [rust_primes.CunninghamKind.SECOND, 6, 16_651],
+        [rust_primes.CunninghamKind.SECOND, 7, 16_651],
+    ),
+)
+def test_known_chains(kind, length, first):
+    """
+    Test the smallest starts of long chains against known values.
+    """
+    assert rust_primes.cunningham_chains(length, 0, first, kind=kind)[0] == first
+
+
+@pytest.mark.parametrize(["lbound", "ubound"], RANGES)
+def test_sophie_germain_and_safe_primes(lbound, ubound):
+    """
+    Test that the Sophie Germain and safe primes match :func:`list_primes`.
+    """
+    _primes = set(rust_primes.list_primes(2 * ubound + 1))
+
+    assert rust_primes.sophie_germain_primes(lbound, ubound) == [
+        _prime for _prime in range(lbound, ubound + 1)
+        if _prime in _primes and 2 * _prime + 1 in _primes
+    ]
+    assert rust_primes.safe_primes(lbound, ubound) == [
+        _prime for _prime in range(lbound, ubound + 1)
+        if _prime in _primes and (_prime - 1) // 2 in _primes
+    ]
+    assert rust_primes.safe_primes(lbound, ubound, count_only=True) == len(
+        rust_primes.safe_primes(lbound, ubound)
+    )
+
+
+def test_invalid_chains():
+    """
+    Test that empty chains and overflowing members are rejected.
+    """
+    with pytest.raises(ValueError):
+        rust_primes.cunningham_chains(0, 0, 100)
+
+    with pytest.raises(OverflowError):
+        rust_primes.cunningham_chains(40, 0, 2**40)
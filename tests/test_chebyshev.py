@@ -0,0 +1,63 @@
+# -*- coding: utf-8 -*-
+import math
+
+import pytest
+
+import rust_primes
+
+
+def _prime_power_count(num: int, prime: int) -> int:
+    _count, _power = 0, prime
+    while _power <= num:
+        _count += 1
+        _power *= prime
+
+    return _count
+
+
+@pytest.mark.parametrize(["num"], ([0], [1], [2], [10**3], [10**6], [10**7]))
+@pytest.mark.parametrize(
+    ["method"],
+    (
+        [rust_primes.SieveMethod.ATKIN],
+        [rust_primes.SieveMethod.ERATOSTHENES],
+        [rust_primes.SieveMethod.ERATOSTHENES_ATOMIC],
+    ),
+)
+def test_chebyshev(num, method):
+    """
+    Test if the compensated sums are as accurate as :func:`math.fsum`.
+    """
+    _primes = rust_primes.list_primes(num)
+
+    assert rust_primes.chebyshev_theta(num, method=method) == pytest.approx(
+        math.fsum(math.log(_prime) for _prime in _primes), rel=1e-15
+    )
+    assert rust_primes.chebyshev_psi(num, method=method) == pytest.approx(
+        math.fsum(
+            math.log(_prime) * _prime_power_count(num, _prime) for _prime in _primes
+        ),
+        rel=1e-15,
+    )
+
+
+def test_von_mangoldt():
+    """
+    Test if the von Mangoldt function is placed at prime powers only.
+    """
+    _lambda = rust_primes.von_mangoldt(1_000)
+
+    assert len(_lambda) == 1_001
+    for num in range(1_001):
+        _expected = 0.0
+        if num > 1:
+            _factor = next(_factor for _factor in range(2, num + 1) if num % _factor == 0)
+            _remainder = num
+            while _remainder % _factor == 0:
+                _remainder //= _factor
+            if _remainder == 1:
+                _expected = math.log(_factor)
+
+        assert _lambda[num] == _expected
+
+    assert math.fsum(_lambda) == pytest.approx(rust_primes.chebyshev_psi(1_000))
@@ -0,0 +1,111 @@
+# -*- coding: utf-8 -*-
+import pytest
+
+import rust_primes
+
+PATTERNS = (
+    [(0,)],
+    [(0, 2)],
+    [(0, 4)],
+    [(0, 6)],
+    [(0, 2, 6)],
+    [(0, 4, 6)],
+    [(0, 2, 6, 8)],
+    [(0, 2, 4)],
+    [(0, 1)],
+)
+
+
+@pytest.mark.parametrize(["pattern"], PATTERNS)
+@pytest.mark.parametrize(
+    ["lbound", "ubound"],
+    ([0, 0], [0, 20], [5, 7], [0, 10**6], [10**6, 2 * 10**6], [10**6, 10**6 + 100]),
+)
+def test_find_constellations(pattern, lbound, ubound):
+    """
+    Test if the constellations match those found from :func:`list_primes`.
+    """
+    _primes = set(rust_primes.list_primes(ubound))
+    _expected = [
+        _start
+        for _start in range(lbound, ubound - pattern[-1] + 1)
+        if all(_start + _offset in _primes for _offset in pattern)
+    ]
+
+    assert rust_primes.find_constellations(pattern, lbound, ubound) == _expected
+    assert rust_primes.find_constellations(
+        pattern, lbound, ubound, count_only=True
+    ) == len(_expected)
+
+
+@pytest.mark.parametrize(
+    ["pattern", "count"],
+    (
+        [(0, 2), 3_424_506],
+        [(0, 2, 6, 8), 28_388],
+    ),
+)
+def test_known_counts(pattern, count):
+    """
+    Test the counts up to ``10**9`` against known values.
+    """
+    assert rust_primes.find_constellations(pattern, 0, 10**9, count_only=True) == count
+
+
+@pytest.mark.parametrize(
+    ["pattern", "admissible"],
+    (
+        [(0,), True],
+        [(0, 1), False],
+        [(0, 2), True],
+        [(0, 2, 4), False],
+        [(0, 2, 6, 8), True],
+        [(0, 2, 6, 8, 12), True],
+        [(0, 2, 6, 8, 10), False],
+    ),
+)
+def test_is_admissible(pattern, admissible):
+    """
+    Test the admissibility of known patterns, and that the inadmissible ones have a
+    :func:`hardy_littlewood_constant` of ``0``.
+    """
+    assert rust_primes.is_admissible(pattern) is admissible
+    assert (rust_primes.hardy_littlewood_constant(pattern) > 0) is admissible
+
+
+@pytest.mark.parametrize(
+    ["pattern", "constant"],
+    (
+        [(0, 2), 1.3203236316],
+        [(0, 2, 6), 2.8582485957],
+        [(0, 2, 6, 8), 4.1511808632],
+    ),
+)
+def test_hardy_littlewood_constant(pattern, constant):
+    """
+    Test the constants against the published values.
+    """
+    assert rust_primes.hardy_littlewood_constant(pattern) == pytest.approx(
+        constant, rel=1e-8
+    )
+
+
+@pytest.mark.parametrize(["pattern"], ([(0, 2)], [(0, 4)], [(0, 2, 6)]))
+def test_hardy_littlewood_estimate(pattern):
+    """
+    Test that the estimate is close to the actual count.
+    """
+    _count = rust_primes.find_constellations(pattern, 0, 10**7, count_only=True)
+
+    assert rust_primes.hardy_littlewood_estimate(pattern, 0, 10**7) == pytest.approx(
+        _count, rel=0.02
+    )
+
+
+@pytest.mark.parametrize(["pattern"], ([()], [(1, 3)], [(0, 4, 2)], [(0, 2, 2)]))
+def test_invalid_pattern(pattern):
+    """
+    Test that invalid patterns are rejected.
+    """
+    with pytest.raises(ValueError):
+        rust_primes.find_constellations(pattern, 0, 100)
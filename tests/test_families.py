@@ -0,0 +1,208 @@
+# -*- coding: utf-8 -*-
+import pytest
+
+import rust_primes
+
+from . import is_prime
+
+UBOUND = 30000
+BASES = [2, 3, 7, 10, 12]
+
+
+def _to_digits(num: int, base: int) -> list:
+    """
+    The digits of ``num`` in ``base``, most significant first.
+    """
+    _digits = []
+    while True:
+        num, _digit = divmod(num, base)
+        _digits.insert(0, _digit)
+        if num == 0:
+            return _digits
+
+
+def _permutations(digits: list):
+    """
+    The distinct permutations of ``digits``, without repeating equal digits.
+    """
+    if len(digits) <= 1:
+        yield list(digits)
+        return
+
+    for _digit in set(digits):
+        _rest = list(digits)
+        _rest.remove(_digit)
+        for _permutation in _permutations(_rest):
+            yield [_digit] + _permutation
+
+
+def _from_digits(digits: list, base: int) -> int:
+    return sum(_digit * base**_power for _power, _digit in enumerate(reversed(digits)))
+
+
+PRIMES = [_num for _num in range(UBOUND + 1) if is_prime(_num)]
+
+
+@pytest.mark.parametrize(["base"], [[_base] for _base in BASES])
+def test_palindromic_primes(base):
+    """
+    Test the palindromic primes against their definition.
+    """
+    _expected = [
+        _prime
+        for _prime in PRIMES
+        if (_digits := _to_digits(_prime, base)) == _digits[::-1]
+    ]
+
+    assert rust_primes.palindromic_primes(0, UBOUND, base=base) == _expected
+    assert rust_primes.palindromic_primes(100, 1000, base=base) == [
+        _prime for _prime in _expected if 100 <= _prime <= 1000
+    ]
+
+
+@pytest.mark.parametrize(
+    ["lbound", "ubound", "base"],
+    [
+        [0, 100, 10**9],
+        [10**8, 10**8 + 10**6, 10],
+        [12_345, 12_445, 10],
+        [3**12, 3**12 + 5000, 3],
+    ],
+)
+def test_palindromic_primes_narrow(lbound, ubound, base):
+    """
+    Test the palindromic primes in a narrow range against their definition, which only
+    finishes in time if the range rather than ``base`` bounds the work.
+    """
+    assert rust_primes.palindromic_primes(lbound, ubound, base=base) == [
+        _num
+        for _num in range(lbound, ubound + 1)
+        if (_digits := _to_digits(_num, base)) == _digits[::-1] and is_prime(_num)
+    ]
+
+
+@pytest.mark.parametrize(["base"], [[_base] for _base in BASES])
+def test_emirps(base):
+    """
+    Test the emirps against their definition.
+    """
+    _expected = [
+        _prime
+        for _prime in PRIMES
+        if (_reversal := _from_digits(_to_digits(_prime, base)[::-1], base)) != _prime
+        and is_prime(_reversal)
+    ]
+
+    assert rust_primes.emirps(0, UBOUND, base=base) == _expected
+
+
+@pytest.mark.parametrize(["base"], [[_base] for _base in BASES])
+def test_circular_primes(base):
+    """
+    Test the circular primes against their definition.
+    """
+    _expected = [
+        _prime
+        for _prime in PRIMES
+        if all(
+            is_prime(_from_digits(_digits[_index:] + _digits[:_index], base))
+            for _digits in [_to_digits(_prime, base)]
+            for _index in range(len(_digits))
+        )
+    ]
+
+    assert rust_primes.circular_primes(0, UBOUND, base=base) == _expected
+
+
+@pytest.mark.parametrize(["base"], [[_base] for _base in BASES])
+def test_permutable_primes(base):
+    """
+    Test the permutable primes against their definition.
+    """
+    _expected = [
+        _prime
+        for _prime in PRIMES
+        if all(
+            is_prime(_from_digits(_permutation, base))
+            for _permutation in _permutations(_to_digits(_prime, base))
+        )
+    ]
+
+    assert rust_primes.permutable_primes(0, UBOUND, base=base) == _expected
+
+
+@pytest.mark.parametrize(["base"], [[_base] for _base in BASES])
+def test_truncatable_primes(base):
+    """
+    Test the truncatable primes against their definition, from both sides.
+    """
+    _left = [
+        _prime
+        for _prime in PRIMES
+        if 0 not in (_digits := _to_digits(_prime, base))
+        and all(
+            is_prime(_from_digits(_digits[_index:], base)) for _index in range(len(_digits))
+        )
+    ]
+    _right = [
+        _prime
+        for _prime in PRIMES
+        if all(
+            is_prime(_prime // base**_index)
+            for _index in range(len(_to_digits(_prime, base)))
+        )
+    ]
+
+    assert rust_primes.truncatable_primes(0, UBOUND, base=base) == _left
+    assert (
+        rust_primes.truncatable_primes(
+            0, UBOUND, side=rust_primes.TruncationSide.RIGHT, base=base
+        )
+        == _right
+    )
+
+
+def test_truncatable_primes_complete():
+    """
+    Test the number of truncatable primes in base 10 within 64-bit integers.
+    """
+    _right = rust_primes.truncatable_primes(
+        0, 2**64 - 1, side=rust_primes.TruncationSide.RIGHT
+    )
+
+    assert len(_right) == 83
+    assert _right[-1] == 73939133
+    assert len(rust_primes.truncatable_primes(0, 2**64 - 1)) == 4260 - 18
+
+
+@pytest.mark.parametrize(
+    ["base", "exponents"],
+    [
+        [2, [2, 3, 5, 7, 13, 17, 19, 31, 61, 89, 107, 127]],
+        [3, [3, 7, 13, 71, 103]],
+        [10, [2, 19, 23, 317]],
+    ],
+)
+def test_repunit_primes(base, exponents):
+    """
+    Test the repunit primes against the known exponents.
+    """
+    assert rust_primes.repunit_primes(exponents[-1], base=base) == exponents
+
+
+@pytest.mark.parametrize(
+    ["function"],
+    [
+        [rust_primes.palindromic_primes],
+        [rust_primes.emirps],
+        [rust_primes.circular_primes],
+        [rust_primes.permutable_primes],
+        [rust_primes.truncatable_primes],
+    ],
+)
+def test_invalid_base(function):
+    """
+    Test that bases below 2 are rejected.
+    """
+    with pytest.raises(ValueError):
+        function(0, 100, base=1)
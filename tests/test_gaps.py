@@ -0,0 +1,66 @@
+# -*- coding: utf-8 -*-
+import math
+
+import pytest
+
+import rust_primes
+
+
+@pytest.mark.parametrize(
+    ["lbound", "ubound"],
+    (
+        [0, 0],
+        [0, 2],
+        [0, 3],
+        [24, 28],
+        [0, 100],
+        [1000, 10**6],
+        [2**20 - 100, 3 * 2**20 + 17],
+    ),
+)
+def test_prime_gaps(lbound, ubound):
+    """
+    Test if the gap statistics match the consecutive pairs of :func:`list_primes`.
+
+    ``3 * 2**20 + 17`` spans multiple segments, so the joining of segments is also
+    tested.
+    """
+    _primes = [_prime for _prime in rust_primes.list_primes(ubound) if _prime >= lbound]
+    _gaps = rust_primes.prime_gaps(lbound, ubound)
+
+    assert _gaps["first"] == (_primes[0] if _primes else None)
+    assert _gaps["last"] == (_primes[-1] if _primes else None)
+
+    _histogram = {}
+    _records = []
+    _first_occurrences = {}
+    for _prime, _next_prime in zip(_primes[:-1], _primes[1:]):
+        _gap = _next_prime - _prime
+        _histogram[_gap] = _histogram.get(_gap, 0) + 1
+
+        if not _records or _gap > _records[-1][1]:
+            _records.append((_prime, _gap))
+
+        _first_occurrences.setdefault(_gap, _prime)
+
+    assert {
+        _gap: int(_count)
+        for _gap, _count in enumerate(_gaps["histogram"])
+        if _count
+    } == _histogram
+    assert [(_start, _gap) for _start, _gap, _ in _gaps["records"]] == _records
+    assert {
+        _size: _start for _size, (_start, _, _) in _gaps["first_occurrences"].items()
+    } == _first_occurrences
+
+    if len(_primes) < 2:
+        assert _gaps["max_merit"] is None
+    else:
+        _start, _gap, _merit = _gaps["max_merit"]
+        assert _merit == pytest.approx(_gap / math.log(_start))
+        assert _merit == pytest.approx(
+            max(
+                (_next_prime - _prime) / math.log(_prime)
+                for _prime, _next_prime in zip(_primes[:-1], _primes[1:])
+            )
+        )
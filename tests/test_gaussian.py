@@ -0,0 +1,57 @@
+# -*- coding: utf-8 -*-
+import pytest
+
+import rust_primes
+
+from . import is_prime
+
+
+def _is_gaussian_prime(real: int, imaginary: int) -> bool:
+    if real and imaginary:
+        return is_prime(real * real + imaginary * imaginary)
+
+    _magnitude = abs(real + imaginary)
+    return is_prime(_magnitude) and _magnitude % 4 == 3
+
+
+@pytest.mark.parametrize(["norm"], [[0], [1], [2], [9], [10], [50], [1000], [12345]])
+def test_gaussian_primes(norm):
+    """
+    Test the mask of Gaussian primes against their definition.
+    """
+    _radius = int(norm**0.5)
+    _mask = rust_primes.gaussian_primes(norm)
+
+    assert _mask.shape == (2 * _radius + 1, 2 * _radius + 1)
+    assert _mask.tolist() == [
+        [
+            _real * _real + _imaginary * _imaginary <= norm
+            and _is_gaussian_prime(_real, _imaginary)
+            for _imaginary in range(-_radius, _radius + 1)
+        ]
+        for _real in range(-_radius, _radius + 1)
+    ]
+
+
+def test_two_squares():
+    """
+    Test the decompositions of the primes into two squares.
+    """
+    for _prime in rust_primes.list_primes(100000):
+        if _prime == 2 or _prime % 4 == 1:
+            _a, _b = rust_primes.two_squares(_prime)
+            assert _a >= _b and _a * _a + _b * _b == _prime
+        else:
+            with pytest.raises(ValueError):
+                rust_primes.two_squares(_prime)
+
+    assert rust_primes.two_squares(2**64 - 59) == (3995190446, 1576450879)
+
+
+@pytest.mark.parametrize(["num"], [[0], [1], [9], [25], [65], [1000001]])
+def test_two_squares_composite(num):
+    """
+    Test that composite numbers are rejected, even when they are sums of two squares.
+    """
+    with pytest.raises(ValueError):
+        rust_primes.two_squares(num)
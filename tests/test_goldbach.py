@@ -0,0 +1,61 @@
+# -*- coding: utf-8 -*-
+import pytest
+
+import rust_primes
+
+
+@pytest.mark.parametrize(["num"], ([0], [3], [4], [11], [100], [101], [10**4]))
+def test_goldbach_partitions(num):
+    """
+    Test if the partitions match the pairs of :func:`list_primes`.
+    """
+    _primes = set(rust_primes.list_primes(num))
+    _expected = [
+        (_prime, num - _prime)
+        for _prime in range(2, num // 2 + 1)
+        if _prime in _primes and num - _prime in _primes
+    ]
+
+    assert rust_primes.goldbach_partitions(num) == _expected
+    assert rust_primes.goldbach_partitions(num, count_only=True) == len(_expected)
+
+
+@pytest.mark.parametrize(["num"], ([0], [1], [4], [5], [20], [4097], [10**5]))
+def test_goldbach_counts(num):
+    """
+    Test if the convolved counts match :func:`goldbach_partitions` of every number.
+    """
+    _counts = rust_primes.goldbach_counts(num)
+
+    assert len(_counts) == num + 1
+    assert _counts.tolist()[:2000] == [
+        rust_primes.goldbach_partitions(_num, count_only=True)
+        for _num in range(min(num + 1, 2000))
+    ]
+    assert int(_counts[num]) == rust_primes.goldbach_partitions(num, count_only=True)
+
+
+@pytest.mark.parametrize(
+    ["lbound", "ubound"],
+    ([0, 0], [0, 2], [3, 9], [0, 10**5], [10**5 + 1, 2 * 10**5 + 1]),
+)
+def test_verify_goldbach(lbound, ubound):
+    """
+    Test if the smallest primes match the first of :func:`goldbach_partitions`.
+    """
+    _expected = [
+        next(iter(rust_primes.goldbach_partitions(_num)), (0, _num))[0]
+        for _num in range(lbound + lbound % 2, ubound + 1, 2)
+    ]
+
+    assert rust_primes.verify_goldbach(ubound, lbound=lbound).tolist() == _expected
+
+
+def test_verify_goldbach_record():
+    """
+    Test the largest smallest prime up to ``10**8`` against its known value.
+    """
+    _minimal_primes = rust_primes.verify_goldbach(10**8)
+
+    assert int(_minimal_primes.max()) == 1_093
+    assert int(_minimal_primes.argmax()) * 2 == 60_119_912
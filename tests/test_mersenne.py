@@ -0,0 +1,55 @@
+# -*- coding: utf-8 -*-
+import pytest
+
+import rust_primes
+
+MERSENNE_EXPONENTS = [
+    2, 3, 5, 7, 13, 17, 19, 31, 61, 89, 107, 127, 521, 607, 1279, 2203, 2281, 3217,
+    4253, 4423,
+]
+
+
+@pytest.mark.parametrize(["exponent"], [[_exponent] for _exponent in range(0, 130)])
+def test_lucas_lehmer(exponent):
+    """
+    Test the Lucas-Lehmer test against trial division for small exponents, and the
+    known Mersenne primes beyond.
+    """
+    _mersenne = 2**exponent - 1
+
+    if exponent <= 40:
+        _expected = _mersenne > 1 and all(
+            _mersenne % _divisor
+            for _divisor in range(2, int(_mersenne**0.5) + 1)
+        )
+    else:
+        _expected = exponent in MERSENNE_EXPONENTS
+
+    assert rust_primes.lucas_lehmer(exponent) is _expected
+
+
+def test_mersenne_primes():
+    """
+    Test the search against the known Mersenne prime exponents.
+    """
+    assert rust_primes.mersenne_primes(4500) == MERSENNE_EXPONENTS
+    assert rust_primes.mersenne_primes(4422) == MERSENNE_EXPONENTS[:-1]
+
+
+def test_perfect_numbers():
+    """
+    Test that the perfect numbers equal the sum of their proper divisors.
+    """
+    _perfect_numbers = rust_primes.perfect_numbers(607)
+
+    assert _perfect_numbers == [
+        2 ** (_exponent - 1) * (2**_exponent - 1)
+        for _exponent in MERSENNE_EXPONENTS[:14]
+    ]
+
+    for _perfect_number in _perfect_numbers[:4]:
+        assert _perfect_number == sum(
+            _divisor
+            for _divisor in range(1, _perfect_number)
+            if _perfect_number % _divisor == 0
+        )
@@ -0,0 +1,73 @@
+# -*- coding: utf-8 -*-
+import pytest
+
+import rust_primes
+
+
+def _naive_mobius(num: int) -> int:
+    _result = 1
+    _factor = 2
+    while _factor * _factor <= num:
+        if num % _factor == 0:
+            num //= _factor
+            if num % _factor == 0:
+                return 0
+            _result = -_result
+        _factor += 1
+
+    return -_result if num > 1 else _result
+
+
+@pytest.mark.parametrize(
+    ["method"],
+    (
+        [rust_primes.SieveMethod.ATKIN],
+        [rust_primes.SieveMethod.ERATOSTHENES],
+        [rust_primes.SieveMethod.ERATOSTHENES_ATOMIC],
+    ),
+)
+def test_mobius_sieve(method):
+    """
+    Test if the Möbius sieve matches the naive definition.
+    """
+    _sieve = rust_primes.mobius_sieve(10_000, method=method)
+
+    assert _sieve.dtype.name == "int8"
+    assert _sieve[0] == 0
+    assert all(_sieve[num] == _naive_mobius(num) for num in range(1, 10_001))
+
+
+@pytest.mark.parametrize(
+    ["num", "value"],
+    [
+        (0, 0),
+        (1, 1),
+        (10, -1),
+        (10**2, 1),
+        (10**3, 2),
+        (10**4, -23),
+        (10**5, -48),
+        (10**6, 212),
+        (10**7, 1_037),
+        (10**8, 1_928),
+        (10**9, -222),
+        (10**10, -33_722),
+    ],
+)
+def test_mertens(num, value):
+    """
+    Test if the Mertens function is correct.
+    """
+    assert rust_primes.mertens(num) == value
+
+
+def test_mertens_matches_sieve():
+    """
+    Test if the Mertens function agrees with the prefix sums of the Möbius sieve.
+    """
+    _sieve = rust_primes.mobius_sieve(2_000)
+
+    assert all(
+        rust_primes.mertens(num) == int(_sieve[: num + 1].sum())
+        for num in range(0, 2_001, 7)
+    )
@@ -0,0 +1,82 @@
+# -*- coding: utf-8 -*-
+import pytest
+
+import rust_primes
+
+
+@pytest.mark.parametrize(["power"], ([0], [1], [2], [3]))
+def test_divisor_sigma_sieve(power):
+    """
+    Test if the divisor sigma sieve matches the naive sum over divisors.
+    """
+    _sieve = rust_primes.divisor_sigma_sieve(2_000, power=power)
+
+    assert _sieve[0] == 0
+    assert all(
+        _sieve[num]
+        == sum(_divisor**power for _divisor in range(1, num + 1) if num % _divisor == 0)
+        for num in range(1, 2_001)
+    )
+
+
+def test_divisor_sigma_sieve_overflow():
+    """
+    Test if an :class:`OverflowError` is raised for powers that cannot fit in 64 bits.
+    """
+    with pytest.raises(OverflowError):
+        rust_primes.divisor_sigma_sieve(10**6, power=4)
+
+
+def test_perfect_numbers():
+    """
+    Test that the even perfect numbers are found through the divisor sums.
+    """
+    _sieve = rust_primes.divisor_sigma_sieve(10**4)
+
+    assert [num for num in range(1, 10**4 + 1) if _sieve[num] == 2 * num] == [
+        6,
+        28,
+        496,
+        8_128,
+    ]
+
+
+def test_dirichlet_convolution():
+    """
+    Test the Dirichlet convolutions of known multiplicative functions against the
+    identities they satisfy.
+    """
+    _num = 2_000
+    _ones = [0] + [1] * _num
+
+    assert rust_primes.dirichlet_convolution(
+        rust_primes.mobius_sieve(_num), _ones
+    ).tolist() == [0, 1] + [0] * (_num - 1)
+    assert rust_primes.dirichlet_convolution(
+        rust_primes.totient_sieve(_num), _ones
+    ).tolist() == list(range(_num + 1))
+    assert rust_primes.dirichlet_convolution(_ones, _ones).tolist() == (
+        rust_primes.divisor_sigma_sieve(_num, power=0).tolist()
+    )
+
+
+def test_dirichlet_convolution_naive():
+    """
+    Test if the Dirichlet convolution of arbitrary values matches the naive sum over
+    divisors, truncated to the shorter input.
+    """
+    _lhs = [0, 3, -1, 4, 1, -5, 9, 2, -6]
+    _rhs = [0, 5, 3, -5, 8, 9, 7, -9, 3, 2, 3]
+
+    assert rust_primes.dirichlet_convolution(_lhs, _rhs).tolist() == [0] + [
+        sum(_lhs[_d] * _rhs[_n // _d] for _d in range(1, _n + 1) if _n % _d == 0)
+        for _n in range(1, len(_lhs))
+    ]
+
+
+def test_dirichlet_convolution_overflow():
+    """
+    Test if an :class:`OverflowError` is raised for values that may not fit in 64 bits.
+    """
+    with pytest.raises(OverflowError):
+        rust_primes.dirichlet_convolution([0, 2**32, 2**32], [0, 2**32, 1])
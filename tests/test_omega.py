@@ -0,0 +1,70 @@
+# -*- coding: utf-8 -*-
+from typing import Tuple
+
+import pytest
+
+import rust_primes
+
+
+def _naive_omegas(num: int) -> Tuple[int, int]:
+    _omega, _big_omega = 0, 0
+    _factor = 2
+    while _factor * _factor <= num:
+        if num % _factor == 0:
+            _omega += 1
+            while num % _factor == 0:
+                num //= _factor
+                _big_omega += 1
+        _factor += 1
+
+    if num > 1:
+        _omega += 1
+        _big_omega += 1
+
+    return _omega, _big_omega
+
+
+@pytest.mark.parametrize(
+    ["lbound", "num"],
+    [
+        (0, 0),
+        (0, 5_000),
+        (123_456, 133_456),
+        (10**9, 10**9 + 1_000),
+    ],
+)
+def test_omega_sieves(lbound, num):
+    """
+    Test if omega, Omega and lambda match the naive factorisations over the window.
+    """
+    _omega = rust_primes.omega_sieve(num, lbound=lbound)
+    _big_omega = rust_primes.big_omega_sieve(num, lbound=lbound)
+    _liouville = rust_primes.liouville_sieve(num, lbound=lbound)
+
+    assert len(_omega) == len(_big_omega) == len(_liouville) == num - lbound + 1
+
+    for _index, _num in enumerate(range(lbound, num + 1)):
+        _expected_omega, _expected_big_omega = _naive_omegas(_num)
+        assert _omega[_index] == _expected_omega
+        assert _big_omega[_index] == _expected_big_omega
+        assert _liouville[_index] == (
+            0 if _num == 0 else (-1) ** _expected_big_omega
+        )
+
+
+@pytest.mark.parametrize(
+    ["num", "value"],
+    [
+        (10, 0),
+        (10**2, -2),
+        (10**3, -14),
+        (10**4, -94),
+        (10**5, -288),
+        (10**6, -530),
+    ],
+)
+def test_summatory_liouville(num, value):
+    """
+    Test the summatory Liouville function against known values.
+    """
+    assert int(rust_primes.liouville_sieve(num)[1:].sum()) == value
@@ -0,0 +1,166 @@
+# -*- coding: utf-8 -*-
+import math
+
+import pytest
+
+import rust_primes
+
+from . import is_prime
+
+POLYNOMIALS = (
+    [[1, 0, 1]],
+    [[1, 1, 41]],
+    [[2, 0, -1]],
+    [[1, 0, 0, 2]],
+    [[-1, 0, 10**6]],
+    [[1, 3]],
+    [[4, 0, 2]],
+    [[7]],
+    [[1, 0, 0, 0, 1]],
+)
+
+
+def _evaluate(coefficients: list[int], n: int) -> int:
+    _value = 0
+    for _coefficient in coefficients:
+        _value = _value * n + _coefficient
+
+    return _value
+
+
+@pytest.mark.parametrize(["coefficients"], POLYNOMIALS)
+@pytest.mark.parametrize(
+    ["lbound", "ubound"],
+    ([0, 0], [0, 30], [5, 7], [0, 2000], [1000, 1500]),
+)
+def test_polynomial_primes(coefficients, lbound, ubound):
+    """
+    Test if the values of ``n`` match those found by trial division.
+    """
+    _expected = [
+        _n
+        for _n in range(lbound, ubound + 1)
+        if is_prime(_evaluate(coefficients, _n))
+    ]
+
+    assert rust_primes.polynomial_primes(coefficients, lbound, ubound) == _expected
+    assert rust_primes.polynomial_primes(
+        coefficients, lbound, ubound, count_only=True
+    ) == len(_expected)
+
+
+def test_euler():
+    """
+    Test if :math:`n^2 + n + 41` is prime for every ``n`` up to ``39``, but not at ``40``.
+    """
+    assert rust_primes.polynomial_primes([1, 1, 41], 0, 40) == list(range(40))
+
+
+def test_leading_zeros():
+    """
+    Test if leading zero coefficients are ignored.
+    """
+    assert rust_primes.polynomial_primes(
+        [0, 0, 1, 0, 1], 0, 100
+    ) == rust_primes.polynomial_primes([1, 0, 1], 0, 100)
+
+
+@pytest.mark.parametrize(
+    ["coefficients", "constant"],
+    (
+        [[1, 0, 1], 1.37281],
+        [[1, 1, 41], 6.6396],
+        [[1, 0], 1.0],
+        [[1, 1, 2], 0.0],
+    ),
+)
+def test_bateman_horn_constant(coefficients, constant):
+    """
+    Test the Bateman-Horn constants against known values, within the truncation of the
+    product at :math:`2^{22}`, which e.g. leaves that of :math:`n^2 + 1` at ``1.37275``.
+    """
+    assert rust_primes.bateman_horn_constant(coefficients) == pytest.approx(
+        constant, abs=1e-4
+    )
+
+
+@pytest.mark.parametrize(["coefficients"], ([[1, 0, 1]], [[1, 1, 41]], [[2, 0, -1]]))
+def test_bateman_horn_estimate(coefficients):
+    """
+    Test if the estimates are within ``1%`` of the counts.
+    """
+    _count = rust_primes.polynomial_primes(coefficients, 0, 10**6, count_only=True)
+
+    assert rust_primes.bateman_horn_estimate(
+        coefficients, 0, 10**6
+    ) == pytest.approx(_count, rel=0.01)
+
+
+@pytest.mark.parametrize(
+    ["func", "args"],
+    (
+        [rust_primes.polynomial_primes, ([0, 0], 0, 10)],
+        [rust_primes.polynomial_primes, ([], 0, 10)],
+        [rust_primes.bateman_horn_constant, ([5],)],
+        [rust_primes.bateman_horn_constant, ([-1, 0, 1],)],
+        [rust_primes.bateman_horn_estimate, ([0], 0, 10)],
+    ),
+)
+def test_invalid_polynomials(func, args):
+    """
+    Test if invalid polynomials raise a ``ValueError``.
+    """
+    with pytest.raises(ValueError):
+        func(*args)
+
+
+def test_overflow():
+    """
+    Test if values beyond 64 bits raise an ``OverflowError``.
+    """
+    with pytest.raises(OverflowError):
+        rust_primes.polynomial_primes([1, 0, 0, 0], 0, 10**7)
+
+
+def _murphy_alpha(coefficients: list[int], bound: int) -> float:
+    """
+    The Murphy alpha by evaluating every residue modulo every prime.
+    """
+    _alpha = 0.0
+    for _prime in rust_primes.list_primes(bound):
+        _covered = sum(
+            _evaluate(coefficients, _x) % _prime == 0 for _x in range(_prime)
+        ) + (coefficients[0] % _prime == 0)
+        _alpha += (1 - _prime * _covered / (_prime + 1)) * math.log(_prime) / (_prime - 1)
+
+    return _alpha
+
+
+@pytest.mark.parametrize(
+    ["coefficients"],
+    POLYNOMIALS + ([[6, 0, 0, -5]], [[12, -7, 0, 3, 30]], [[35]]),
+)
+def test_polynomial_roots(coefficients):
+    """
+    Test if the roots and the Murphy alpha match those found by evaluating every residue.
+    """
+    _roots = rust_primes.polynomial_roots(coefficients, 1000)
+    _primes = _roots["primes"].tolist()
+    _offsets = _roots["offsets"].tolist()
+
+    assert _primes == rust_primes.list_primes(1000)
+    assert len(_offsets) == len(_primes) + 1 and _offsets[0] == 0
+    for _index, _prime in enumerate(_primes):
+        assert _roots["roots"][_offsets[_index] : _offsets[_index + 1]].tolist() == [
+            _x for _x in range(_prime) if _evaluate(coefficients, _x) % _prime == 0
+        ]
+
+    assert _roots["alpha"] == pytest.approx(_murphy_alpha(coefficients, 1000))
+
+
+def test_polynomial_roots_invalid():
+    """
+    Test if a zero polynomial raises a ``ValueError``.
+    """
+    with pytest.raises(ValueError):
+        rust_primes.polynomial_roots([0], 100)
@@ -0,0 +1,113 @@
+# -*- coding: utf-8 -*-
+import math
+import random
+
+import pytest
+
+import rust_primes
+
+
+def _miller_rabin(num: int) -> bool:
+    """
+    The Miller-Rabin test over the first 20 primes, as a reference for larger numbers.
+    """
+    _witnesses = rust_primes.list_primes(71)
+    if num < 2:
+        return False
+    for _witness in _witnesses:
+        if num % _witness == 0:
+            return num == _witness
+
+    _odd, _twos = num - 1, 0
+    while _odd % 2 == 0:
+        _odd, _twos = _odd // 2, _twos + 1
+
+    for _witness in _witnesses:
+        _power = pow(_witness, _odd, num)
+        if _power in (1, num - 1):
+            continue
+        for _ in range(_twos - 1):
+            _power = _power * _power % num
+            if _power == num - 1:
+                break
+        else:
+            return False
+
+    return True
+
+
+@pytest.mark.parametrize(["n"], [[0], [1], [2], [30], [1000], [100000]])
+def test_primorial(n):
+    """
+    Test the primorial against the product of the listed primes.
+    """
+    assert rust_primes.primorial(n) == math.prod(rust_primes.list_primes(n))
+
+
+@pytest.mark.parametrize(["n"], [[0], [1], [5], [1229], [10000]])
+def test_primorial_of_nth(n):
+    """
+    Test the primorial of the first ``n`` primes against that up to the ``n``th prime.
+    """
+    _primes = rust_primes.list_primes(200000)[:n]
+
+    assert rust_primes.primorial_of_nth(n) == math.prod(_primes)
+
+
+def test_bpsw():
+    """
+    Test the Baillie-PSW test against the Miller-Rabin test on random numbers, and on
+    known primes and composites.
+    """
+    _random = random.Random(42)
+    for _ in range(5000):
+        _num = _random.getrandbits(_random.randint(2, 256))
+        assert rust_primes.bpsw(_num) is _miller_rabin(_num)
+
+    assert rust_primes.bpsw(2**521 - 1)
+    assert not rust_primes.bpsw(2**523 - 1)
+    assert not rust_primes.bpsw((2**89 - 1) ** 2)
+    assert not rust_primes.bpsw((2**61 - 1) * (2**89 - 1))
+
+
+@pytest.mark.parametrize(
+    ["offset", "expected"],
+    [
+        [1, [2, 3, 5, 7, 11, 31, 379, 1019, 1021, 2657]],
+        [-1, [3, 5, 11, 13, 41, 89, 317, 337, 991, 1873, 2053, 2377]],
+    ],
+)
+def test_primorial_primes(offset, expected):
+    """
+    Test the primorial primes against the known ones.
+    """
+    assert rust_primes.primorial_primes(expected[-1], offset=offset) == expected
+
+
+@pytest.mark.parametrize(
+    ["offset", "expected"],
+    [
+        [1, [0, 1, 2, 3, 11, 27, 37, 41, 73, 77, 116, 154, 320, 340, 399, 427]],
+        [-1, [3, 4, 6, 7, 12, 14, 30, 32, 33, 38, 94, 166, 324, 379]],
+    ],
+)
+def test_factorial_primes(offset, expected):
+    """
+    Test the factorial primes against the known ones.
+    """
+    assert rust_primes.factorial_primes(expected[-1], offset=offset) == expected
+
+
+def test_products_offset():
+    """
+    Test the scans with other offsets against the Miller-Rabin test.
+    """
+    for _offset in [-7, 3, 13]:
+        assert rust_primes.factorial_primes(60, offset=_offset) == [
+            _n for _n in range(61) if _miller_rabin(math.factorial(_n) + _offset)
+        ]
+        assert rust_primes.primorial_primes(200, offset=_offset) == [
+            _p
+            for _p in rust_primes.list_primes(200)
+            if _miller_rabin(rust_primes.primorial(_p) + _offset)
+        ]
@@ -0,0 +1,78 @@
+# -*- coding: utf-8 -*-
+import pytest
+
+import rust_primes
+
+MAX_NUM: int = 20_000
+
+
+@pytest.mark.parametrize(["modulus"], ([1], [2], [3], [4], [10], [30], [97]))
+def test_count_primes_mod(modulus):
+    """
+    Test if the counts in each residue class match the full list of primes.
+    """
+    _primes = rust_primes.list_primes(MAX_NUM)
+    _counts = rust_primes.count_primes_mod(MAX_NUM, modulus)
+
+    assert len(_counts) == modulus
+    assert int(_counts.sum()) == len(_primes)
+    for _residue in range(modulus):
+        assert _counts[_residue] == sum(
+            1 for _prime in _primes if _prime % modulus == _residue
+        )
+
+
+@pytest.mark.parametrize(["modulus"], ([1], [2], [4], [6], [10], [12], [97]))
+@pytest.mark.parametrize(
+    ["lbound", "ubound"],
+    [
+        (0, MAX_NUM),
+        (0, 1),
+        (2, 2),
+        (1_000, 1_100),
+        (MAX_NUM - 500, MAX_NUM),
+    ],
+)
+def test_list_primes_in_progression(modulus, lbound, ubound):
+    """
+    Test if sieving the progression only matches filtering the full list of primes.
+    """
+    _primes = rust_primes.list_primes(MAX_NUM)
+
+    for _residue in range(modulus + 2):
+        assert rust_primes.list_primes_in_progression(
+            _residue, modulus, lbound, ubound
+        ) == [
+            _prime
+            for _prime in _primes
+            if lbound <= _prime <= ubound and _prime % modulus == _residue % modulus
+        ]
+
+
+@pytest.mark.parametrize(
+    ["residue", "modulus", "lbound"],
+    ([0, 10, 2**64 - 5], [9, 10, 2**64 - 1], [2**63, 2**63 + 1, 2**64 - 5]),
+)
+def test_list_primes_in_progression_overflow(residue, modulus, lbound):
+    """
+    Test that a progression without any member up to ``2^64 - 1`` is empty, rather than
+    wrapping around.
+    """
+    assert (
+        rust_primes.list_primes_in_progression(residue, modulus, lbound, 2**64 - 1)
+        == []
+    )
+
+
+def test_progression_errors():
+    """
+    Test that a zero or oversized modulus is rejected.
+    """
+    with pytest.raises(ValueError):
+        rust_primes.count_primes_mod(100, 0)
+
+    with pytest.raises(ValueError):
+        rust_primes.count_primes_mod(100, 2**40)
+
+    with pytest.raises(ValueError):
+        rust_primes.list_primes_in_progression(1, 0, 0, 100)
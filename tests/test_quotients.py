@@ -0,0 +1,104 @@
+# -*- coding: utf-8 -*-
+import math
+
+import pytest
+
+import rust_primes
+
+UBOUND = 2000
+PRIMES = rust_primes.list_primes(UBOUND)
+
+
+def _fibonacci(index: int, modulus: int) -> int:
+    """
+    The Fibonacci number of ``index`` modulo ``modulus``, by fast doubling.
+    """
+    _current, _next = 0, 1
+    for _bit in bin(index)[2:]:
+        _double = _current * (2 * _next - _current) % modulus
+        _double_next = (_current * _current + _next * _next) % modulus
+        _current, _next = (
+            (_double_next, (_double + _double_next) % modulus)
+            if _bit == "1"
+            else (_double, _double_next)
+        )
+
+    return _current
+
+
+def _legendre_five(prime: int) -> int:
+    return 0 if prime == 5 else 1 if prime % 5 in (1, 4) else -1
+
+
+QUOTIENTS = {
+    rust_primes.SpecialPrime.WIEFERICH: lambda _p: (pow(2, _p - 1, _p * _p) - 1) // _p,
+    rust_primes.SpecialPrime.WILSON: lambda _p: (math.factorial(_p - 1) + 1) // _p,
+    rust_primes.SpecialPrime.WALL_SUN_SUN: lambda _p: _fibonacci(
+        _p - _legendre_five(_p), _p * _p
+    )
+    // _p,
+}
+
+
+def _signed_quotient(kind, prime: int) -> int:
+    _quotient = QUOTIENTS[kind](prime) % prime
+    return _quotient - prime if _quotient > prime // 2 else _quotient
+
+
+@pytest.mark.parametrize(["kind"], [[_kind] for _kind in QUOTIENTS])
+def test_special_prime_near_misses(kind):
+    """
+    Test the signed quotients against their definitions.
+    """
+    _expected = [
+        (_prime, _signed_quotient(kind, _prime))
+        for _prime in PRIMES
+        # The Fermat quotient to base 2 is not defined for 2.
+        if not (kind == rust_primes.SpecialPrime.WIEFERICH and _prime == 2)
+    ]
+
+    assert rust_primes.special_prime_near_misses(kind, 0, UBOUND, UBOUND) == _expected
+    assert rust_primes.special_prime_near_misses(kind, 100, 1000, 3) == [
+        (_prime, _quotient)
+        for _prime, _quotient in _expected
+        if 100 <= _prime <= 1000 and abs(_quotient) <= 3
+    ]
+
+
+@pytest.mark.parametrize(
+    ["kind", "ubound", "expected"],
+    [
+        [rust_primes.SpecialPrime.WIEFERICH, 10**7, [1093, 3511]],
+        [rust_primes.SpecialPrime.WILSON, 20000, [5, 13, 563]],
+        [rust_primes.SpecialPrime.WALL_SUN_SUN, 10**7, []],
+    ],
+)
+def test_special_primes(kind, ubound, expected):
+    """
+    Test the special primes against the known ones.
+    """
+    assert rust_primes.special_primes(kind, 0, ubound) == expected
+
+
+@pytest.mark.parametrize(
+    ["kind"],
+    [[rust_primes.SpecialPrime.WIEFERICH], [rust_primes.SpecialPrime.WALL_SUN_SUN]],
+)
+def test_special_primes_large(kind):
+    """
+    Test the quotients of large primes, where ``p^2`` exceeds 64-bit integers; the Wilson
+    quotient is left out, as it takes ``O(p)``.
+    """
+    _lbound = 2**50 - 1000
+    _result = rust_primes.special_prime_near_misses(kind, _lbound, 2**50, 2**50)
+
+    assert _result
+    assert all(_quotient == _signed_quotient(kind, _prime) for _prime, _quotient in _result)
+
+
+def test_special_primes_overflow():
+    """
+    Test that upper bounds from ``2^63`` are rejected.
+    """
+    with pytest.raises(OverflowError):
+        rust_primes.special_primes(rust_primes.SpecialPrime.WIEFERICH, 0, 2**63)
@@ -0,0 +1,67 @@
+# -*- coding: utf-8 -*-
+import bisect
+
+import pytest
+
+import rust_primes
+
+N = 1000
+LIMIT = 40 * N
+PRIMES = rust_primes.list_primes(LIMIT)
+
+
+def _half_interval_counts() -> list:
+    """
+    ``pi(x) - pi(x/2)`` for every ``x`` below ``LIMIT``.
+    """
+    return [
+        bisect.bisect_right(PRIMES, _x) - bisect.bisect_right(PRIMES, _x // 2)
+        for _x in range(LIMIT)
+    ]
+
+
+COUNTS = _half_interval_counts()
+
+
+def test_ramanujan_primes():
+    """
+    Test the Ramanujan primes against their definition.
+    """
+    _last = {}
+    for _x, _count in enumerate(COUNTS):
+        _last[_count] = _x
+
+    _expected = []
+    for _k in range(1, N + 1):
+        _expected.append(max([_last[_k - 1] + 1] + _expected[-1:]))
+
+    assert rust_primes.ramanujan_primes(N) == _expected
+
+
+def test_labos_primes():
+    """
+    Test the Labos primes against their definition.
+    """
+    assert rust_primes.labos_primes(N) == [COUNTS.index(_k) for _k in range(1, N + 1)]
+
+
+@pytest.mark.parametrize(
+    ["function"], [[rust_primes.ramanujan_primes], [rust_primes.labos_primes]]
+)
+def test_empty(function):
+    """
+    Test that no primes are listed for ``n = 0``.
+    """
+    assert function(0) == []
+
+
+def test_bound():
+    """
+    Test that every Labos prime is at most the Ramanujan prime of the same index.
+    """
+    assert all(
+        _labos <= _ramanujan
+        for _labos, _ramanujan in zip(
+            rust_primes.labos_primes(N), rust_primes.ramanujan_primes(N)
+        )
+    )
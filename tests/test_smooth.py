@@ -0,0 +1,142 @@
+# -*- coding: utf-8 -*-
+import math
+
+import pytest
+
+import rust_primes
+
+UBOUND = 100000
+BOUNDS = [0, 1, 2, 3, 7, 30, 97, 500, UBOUND, 10**7]
+
+
+def _prime_factor_extremes(num: int) -> tuple:
+    """
+    The smallest and largest prime factor of every number up to ``num``, ``0`` for ``0``
+    and ``1``.
+    """
+    _smallest, _largest = [0] * (num + 1), [0] * (num + 1)
+    for _prime in rust_primes.list_primes(num):
+        for _multiple in range(_prime, num + 1, _prime):
+            _smallest[_multiple] = _smallest[_multiple] or _prime
+            _largest[_multiple] = _prime
+
+    return _smallest, _largest
+
+
+SMALLEST, LARGEST = _prime_factor_extremes(UBOUND)
+
+
+@pytest.mark.parametrize(["bound"], [[_bound] for _bound in BOUNDS])
+def test_smooth_numbers(bound):
+    """
+    Test the smooth numbers against their largest prime factors.
+    """
+    _expected = [_num for _num in range(1, UBOUND + 1) if LARGEST[_num] <= bound]
+
+    assert rust_primes.smooth_numbers(bound, UBOUND) == _expected
+    assert rust_primes.smooth_numbers(bound, 5000, lbound=777) == [
+        _num for _num in _expected if 777 <= _num <= 5000
+    ]
+
+
+@pytest.mark.parametrize(["bound"], [[_bound] for _bound in BOUNDS])
+def test_rough_numbers(bound):
+    """
+    Test the rough numbers against their smallest prime factors.
+    """
+    _expected = [
+        _num for _num in range(1, UBOUND + 1) if _num == 1 or SMALLEST[_num] > bound
+    ]
+
+    assert rust_primes.rough_numbers(bound, 0, UBOUND) == _expected
+    assert rust_primes.rough_numbers(bound, 1000, 5000) == [
+        _num for _num in _expected if 1000 <= _num <= 5000
+    ]
+
+
+def _unfactored_part(num: int, bound: int) -> int:
+    """
+    What remains of ``num`` once every prime factor up to ``bound`` is divided out.
+    """
+    for _prime in rust_primes.list_primes(bound):
+        while num % _prime == 0:
+            num //= _prime
+
+    return num
+
+
+@pytest.mark.parametrize(
+    ["bound", "lbound", "ubound"],
+    [
+        [3, 2**63 - 5, 2**63 + 5],
+        [1000, 2**64 - 101, 2**64 - 1],
+        [1000, 2**64 - 2**21, 2**64 - 2**21 + 3000],
+    ],
+)
+def test_smooth_and_rough_numbers_large(bound, lbound, ubound):
+    """
+    Test the smooth and rough numbers up to ``2^64 - 1`` against dividing out the small
+    prime factors.
+    """
+    _numbers = range(lbound, ubound + 1)
+
+    assert rust_primes.smooth_numbers(bound, ubound, lbound=lbound) == [
+        _num for _num in _numbers if _unfactored_part(_num, bound) == 1
+    ]
+    assert rust_primes.rough_numbers(bound, lbound, ubound) == [
+        _num for _num in _numbers if _unfactored_part(_num, bound) == _num
+    ]
+
+
+@pytest.mark.parametrize(["bound"], [[_bound] for _bound in BOUNDS])
+def test_count_smooth_numbers(bound):
+    """
+    Test the counts of smooth numbers against their largest prime factors.
+    """
+    for _num in [0, 1, 2, 5, 100, 9999, 65536, UBOUND]:
+        assert rust_primes.count_smooth_numbers(bound, _num) == sum(
+            1 for _n in range(1, _num + 1) if LARGEST[_n] <= bound
+        )
+
+
+@pytest.mark.parametrize(["bound"], [[100], [3000], [10**5]])
+@pytest.mark.parametrize(["lbound", "num"], [[0, 10**6], [10**8 - 10**6, 10**8]])
+def test_count_smooth_numbers_large(bound, lbound, num):
+    """
+    Test the counts of smooth numbers in larger ranges against those listed over a
+    window, without listing every smooth number below it.
+    """
+    _count = rust_primes.count_smooth_numbers(bound, num)
+    _count_below = rust_primes.count_smooth_numbers(bound, max(lbound, 1) - 1)
+
+    assert _count - _count_below == len(
+        rust_primes.smooth_numbers(bound, num, lbound=lbound)
+    )
+
+
+@pytest.mark.parametrize(
+    ["u", "expected"],
+    [
+        [0.5, 1.0],
+        [2, 1 - math.log(2)],
+        [3, 0.0486083882911],
+        [4, 0.00491092564776],
+        [5, 0.000354724700456],
+        [10, 2.77017183772596e-11],
+    ],
+)
+def test_dickman_rho(u, expected):
+    """
+    Test the Dickman function against its known values.
+    """
+    assert rust_primes.dickman_rho(u) == pytest.approx(expected, rel=1e-6)
+
+
+def test_estimate_smooth_numbers():
+    """
+    Test that the estimates are close to the exact counts for large bounds.
+    """
+    for _bound, _num in [(10**4, 10**10), (10**5, 10**9)]:
+        assert rust_primes.estimate_smooth_numbers(_bound, _num) == pytest.approx(
+            rust_primes.count_smooth_numbers(_bound, _num), rel=0.05
+        )
@@ -0,0 +1,116 @@
+# -*- coding: utf-8 -*-
+import os
+import tempfile
+
+import pytest
+
+import rust_primes
+
+from . import is_prime
+
+SMALL_PRIMES = rust_primes.list_primes(1000)
+
+
+@pytest.mark.parametrize(["exponent"], [[_exponent] for _exponent in range(1, 16)])
+def test_proth(exponent):
+    """
+    Test Proth's theorem against trial division for every Proth number with the given
+    exponent.
+    """
+    for _k in range(1, min(2**exponent, 200), 2):
+        assert rust_primes.proth(_k, exponent) is is_prime(_k * 2**exponent + 1)
+
+
+def test_proth_large():
+    """
+    Test Proth's theorem against the known primes of the form ``3 * 2^n + 1``.
+    """
+    assert [
+        _exponent for _exponent in range(2, 450) if rust_primes.proth(3, _exponent)
+    ] == [2, 5, 6, 8, 12, 18, 30, 36, 41, 66, 189, 201, 209, 276, 353, 408, 438]
+
+
+@pytest.mark.parametrize(["k", "exponent"], [[2, 3], [8, 3], [9, 3]])
+def test_proth_invalid(k, exponent):
+    """
+    Test that the numbers which are not Proth numbers are rejected.
+    """
+    with pytest.raises(ValueError):
+        rust_primes.proth(k, exponent)
+
+
+def test_pepin():
+    """
+    Test Pépin's test against the known Fermat primes.
+    """
+    assert [rust_primes.pepin(_index) for _index in range(13)] == [True] * 5 + [False] * 8
+
+    with pytest.raises(OverflowError):
+        rust_primes.pepin(32)
+
+
+@pytest.mark.parametrize(
+    ["base", "offset"],
+    [[2, 1], [2, -1], [3, 2], [5, -3], [6, 1], [10, -7]],
+)
+def test_kbn_candidates(base, offset):
+    """
+    Test the sieve against trial division by the sieving primes.
+    """
+    _multipliers = list(range(1, 40))
+    _expected = [
+        (_k, _n)
+        for _k in _multipliers
+        for _n in range(1, 31)
+        if (_value := _k * base**_n + offset) > 1
+        and not any(_value % _prime == 0 and _value != _prime for _prime in SMALL_PRIMES)
+    ]
+
+    assert (
+        rust_primes.kbn_candidates(_multipliers, base, 1, 30, offset, max_prime=1000)
+        == _expected
+    )
+    assert rust_primes.kbn_candidates(
+        _multipliers, base, 1, 30, offset, max_prime=1000, count_only=True
+    ) == len(_expected)
+
+
+def test_kbn_candidates_file():
+    """
+    Test the candidate file written by the sieve.
+    """
+    with tempfile.TemporaryDirectory() as _directory:
+        _path = os.path.join(_directory, "candidates.txt")
+        _candidates = rust_primes.kbn_candidates([3, 5], 2, 1, 100, -1, path=_path)
+
+        with open(_path) as _file:
+            _lines = _file.read().splitlines()
+
+    assert _lines[0] == "ABC $a*2^$b-1"
+    assert _lines[1:] == [f"{_k} {_n}" for _k, _n in _candidates]
+
+
+def test_kbn_candidates_file_rewritten():
+    """
+    Test if the candidate file is written again by a repeated call, rather than the
+    call being cached.
+    """
+    with tempfile.TemporaryDirectory() as _directory:
+        _path = os.path.join(_directory, "candidates.txt")
+        rust_primes.kbn_candidates((3, 5), 2, 1, 100, -1, path=_path)
+        os.remove(_path)
+        rust_primes.kbn_candidates((3, 5), 2, 1, 100, -1, path=_path)
+
+        assert os.path.exists(_path)
+
+
+@pytest.mark.parametrize(
+    ["multipliers", "base", "min_exponent"],
+    [[[1], 1, 1], [[1], 2, 0], [[0, 1], 2, 1]],
+)
+def test_kbn_candidates_invalid(multipliers, base, min_exponent):
+    """
+    Test that invalid parameters are rejected.
+    """
+    with pytest.raises(ValueError):
+        rust_primes.kbn_candidates(multipliers, base, min_exponent, 10, 1)
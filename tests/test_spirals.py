@@ -0,0 +1,171 @@
+# -*- coding: utf-8 -*-
+import math
+import os
+import tempfile
+
+import pytest
+
+import rust_primes
+
+
+def _ulam_numbers(side: int, start: int) -> list[list[int]]:
+    """
+    Walk the Ulam spiral outwards from the centre, turning anticlockwise from the right.
+    """
+    _numbers = [[0] * side for _ in range(side)]
+    _row = _column = (side - 1) // 2
+    _directions = [(0, 1), (-1, 0), (0, -1), (1, 0)]
+
+    _num, _length, _direction = start, 1, 0
+    while _num < start + (2 * (side // 2) + 1) ** 2:
+        for _ in range(2):
+            for _ in range(_length):
+                if 0 <= _row < side and 0 <= _column < side:
+                    _numbers[_row][_column] = _num
+                _num += 1
+                _row += _directions[_direction][0]
+                _column += _directions[_direction][1]
+            _direction = (_direction + 1) % 4
+        _length += 1
+
+    return _numbers
+
+
+@pytest.mark.parametrize(["side"], [[1], [2], [5], [8], [31]])
+@pytest.mark.parametrize(["start"], [[1], [41], [1000]])
+def test_ulam_spiral(side, start):
+    """
+    Test that the primes are lit at their places along the walk of an Ulam spiral.
+    """
+    _primes = set(rust_primes.list_primes(start + side * side * 2))
+    _image = rust_primes.prime_spiral(side, start=start)
+
+    assert _image.shape == (side, side)
+    assert _image.tolist() == [
+        [255 if _num in _primes else 0 for _num in _row]
+        for _row in _ulam_numbers(side, start)
+    ]
+
+
+def test_ulam_spiral_gaps():
+    """
+    Test the grey levels of the primes when coloured by the gaps to the next primes.
+    """
+    _numbers = _ulam_numbers(31, 100)
+    _primes = rust_primes.list_primes(100 + 31 * 31 + 100)
+    _gaps = {_prime: _next - _prime for _prime, _next in zip(_primes, _primes[1:])}
+    _widest = max(_gap for _prime, _gap in _gaps.items() if 100 <= _prime < 100 + 31 * 31)
+
+    _image = rust_primes.prime_spiral(31, start=100, colour_gaps=True)
+
+    assert _image.tolist() == [
+        [64 + 191 * _gaps[_num] // _widest if _num in _gaps else 0 for _num in _row]
+        for _row in _numbers
+    ]
+
+
+def test_ulam_spiral_polynomials():
+    """
+    Test that the values of Euler's polynomial stand out from the rest of the primes.
+    """
+    _numbers = _ulam_numbers(21, 41)
+    _primes = set(rust_primes.list_primes(41 + 21 * 21))
+    _values = {_k * _k + _k + 41 for _k in range(21)}
+
+    _image = rust_primes.prime_spiral(21, start=41, polynomials=[(1, 1, 41)])
+
+    for _row, _numbers_row in zip(_image.tolist(), _numbers):
+        for _level, _num in zip(_row, _numbers_row):
+            if _num in _values:
+                assert _level == (255 if _num in _primes else 48)
+            else:
+                assert _level == (127 if _num in _primes else 0)
+
+
+def test_ulam_spiral_large_start():
+    """
+    Test that a spiral far from the origin only sieves and walks the numbers laid out,
+    including the values of a polynomial that reaches the window only after many steps.
+    """
+    _start = 10**15
+    _numbers = _ulam_numbers(31, _start)
+    _primes = set(
+        rust_primes.list_primes_in_progression(1, 2, _start, _start + 31 * 31)
+    )
+
+    _image = rust_primes.prime_spiral(31, start=_start, polynomials=[(0, 2, 1)])
+
+    for _row, _numbers_row in zip(_image.tolist(), _numbers):
+        for _level, _num in zip(_row, _numbers_row):
+            if _num % 2 == 1:
+                assert _level == (255 if _num in _primes else 48)
+            else:
+                assert _level == 0
+
+
+def test_sacks_spiral():
+    """
+    Test that every prime of a Sacks spiral is lit at its polar coordinates, i.e. at the
+    square root of the prime itself.
+    """
+    _side = 101
+    _centre = (_side - 1) / 2
+    _primes = rust_primes.list_primes(int(_centre**2))
+
+    # Rounds half away from zero, as Rust does.
+    def _round(value: float) -> float:
+        return math.copysign(math.floor(abs(value) + 0.5), value)
+
+    _expected = [[0] * _side for _ in range(_side)]
+    for _prime in _primes:
+        _radius = math.sqrt(_prime)
+        _angle = 2 * math.pi * math.modf(_radius)[0]
+        _row = _round(_centre - _radius * math.sin(_angle))
+        _column = _round(_centre + _radius * math.cos(_angle))
+        if 0 <= _row < _side and 0 <= _column < _side:
+            _expected[int(_row)][int(_column)] = 255
+
+    _image = rust_primes.prime_spiral(_side, kind=rust_primes.SpiralKind.SACKS)
+
+    assert _image.tolist() == _expected
+
+
+@pytest.mark.parametrize(["extension"], [["pgm"], ["png"]])
+def test_prime_spiral_file(extension):
+    """
+    Test the image files written by :func:`prime_spiral`.
+    """
+    with tempfile.TemporaryDirectory() as _directory:
+        _path = os.path.join(_directory, f"spiral.{extension}")
+        _image = rust_primes.prime_spiral(33, start=41, path=_path)
+
+        with open(_path, "rb") as _file:
+            _contents = _file.read()
+
+    if extension == "pgm":
+        assert _contents == b"P5\n33 33\n255\n" + _image.tobytes()
+    else:
+        assert _contents.startswith(b"\x89PNG\r\n\x1a\n")
+        assert _contents[16:24] == (33).to_bytes(4, "big") * 2
+
+
+def test_prime_spiral_file_rewritten():
+    """
+    Test if the image file is written again by a repeated call, rather than the call
+    being cached.
+    """
+    with tempfile.TemporaryDirectory() as _directory:
+        _path = os.path.join(_directory, "spiral.pgm")
+        rust_primes.prime_spiral(33, start=41, path=_path)
+        os.remove(_path)
+        rust_primes.prime_spiral(33, start=41, path=_path)
+
+        assert os.path.exists(_path)
+
+
+def test_prime_spiral_invalid_path():
+    """
+    Test that an unknown image format is rejected.
+    """
+    with pytest.raises(ValueError):
+        rust_primes.prime_spiral(5, path="spiral.jpg")
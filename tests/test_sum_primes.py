@@ -0,0 +1,62 @@
+# -*- coding: utf-8 -*-
+import pytest
+
+import rust_primes
+
+
+@pytest.mark.parametrize(
+    ["num", "count"],
+    [
+        (0, 0),
+        (2, 1),
+        (10, 4),
+        (10**6, 78_498),
+        (10**9, 50_847_534),
+        (10**11, 4_118_054_813),
+    ],
+)
+def test_prime_pi(num, count):
+    """
+    Test if the sub-linear prime counts are correct.
+    """
+    assert rust_primes.prime_pi(num) == count
+
+
+@pytest.mark.parametrize(["power"], ([0], [1], [2], [3]))
+@pytest.mark.parametrize(["modulus"], ([None], [1], [97], [10**9 + 7], [2**64 - 1]))
+def test_sum_primes(power, modulus):
+    """
+    Test if the sums of prime powers match the sums over :func:`list_primes`.
+    """
+    _primes = rust_primes.list_primes(10_000)
+
+    for num in range(0, 10_001, 137):
+        _expected = sum(_prime**power for _prime in _primes if _prime <= num)
+        if modulus is not None:
+            _expected %= modulus
+
+        assert (
+            rust_primes.sum_primes(num, power=power, modulus=modulus) == _expected
+        )
+
+
+def test_sum_primes_large():
+    """
+    Test the sum of primes against known values.
+    """
+    assert rust_primes.sum_primes(10**9) == 24_739_512_092_254_535
+    assert rust_primes.sum_primes(10**11) == 201_467_077_743_744_681_014
+
+
+def test_sum_primes_errors():
+    """
+    Test the errors raised by :func:`sum_primes`.
+    """
+    with pytest.raises(ValueError):
+        rust_primes.sum_primes(100, power=4)
+
+    with pytest.raises(ValueError):
+        rust_primes.sum_primes(100, modulus=0)
+
+    with pytest.raises(OverflowError):
+        rust_primes.sum_primes(10**14, power=3)
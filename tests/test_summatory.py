@@ -0,0 +1,46 @@
+# -*- coding: utf-8 -*-
+import pytest
+
+import rust_primes
+from rust_primes import ArithmeticFunction
+
+
+@pytest.mark.parametrize(
+    ["function", "num", "value"],
+    [
+        (ArithmeticFunction.TOTIENT, 0, 0),
+        (ArithmeticFunction.TOTIENT, 1, 1),
+        (ArithmeticFunction.TOTIENT, 10**3, 304_192),
+        (ArithmeticFunction.TOTIENT, 10**6, 303_963_552_392),
+        (ArithmeticFunction.TOTIENT, 10**10, 30_396_355_092_886_216_366),
+        (ArithmeticFunction.MOBIUS, 10**6, 212),
+        (ArithmeticFunction.MOBIUS, 10**10, -33_722),
+        (ArithmeticFunction.LIOUVILLE, 10**6, -530),
+        (ArithmeticFunction.DIVISOR_COUNT, 10**3, 7_069),
+        (ArithmeticFunction.DIVISOR_SUM, 10**3, 823_081),
+    ],
+)
+def test_summatory(function, num, value):
+    """
+    Test if the sub-linear sums are correct against known values.
+    """
+    assert rust_primes.summatory(function, num) == value
+
+
+@pytest.mark.parametrize(
+    ["function", "power"],
+    [
+        (ArithmeticFunction.DIVISOR_COUNT, 0),
+        (ArithmeticFunction.DIVISOR_SUM, 1),
+    ],
+)
+def test_summatory_matches_sieve(function, power):
+    """
+    Test if the sub-linear sums match the prefix sums of the sieved values.
+    """
+    _sieve = rust_primes.divisor_sigma_sieve(3_000, power=power)
+
+    assert all(
+        rust_primes.summatory(function, num) == int(_sieve[: num + 1].sum())
+        for num in range(0, 3_001, 11)
+    )
@@ -46,3 +46,48 @@ def test_timed_execution(num, count, method):
         _single_execution,
         rel=0.4,
     )
+
+
+def test_unhashable_parameters():
+    """
+    Test that calls with unhashable parameters fall back to uncached calls, while
+    hashable ones remain cached.
+    """
+    _calls = []
+
+    def _func(pattern):
+        _calls.append(pattern)
+        return sum(pattern)
+
+    _timed = rust_primes.decorators.TimedFunction(_func)
+
+    assert _timed([0, 2]) == 2
+    assert _timed([0, 2]) == 2
+    assert len(_calls) == 2
+
+    assert _timed((0, 2, 6)) == 8
+    assert _timed((0, 2, 6)) == 8
+    assert len(_calls) == 3
+
+
+def test_side_effects():
+    """
+    Test that calls given any of the ``side_effects`` parameters are never cached,
+    while the other calls remain cached.
+    """
+    _calls = []
+
+    def _func(num, path=None):
+        _calls.append(path)
+        return num
+
+    _timed = rust_primes.decorators.TimedFunction(_func, side_effects=("path",))
+
+    assert _timed(1, path="primes.txt") == 1
+    assert _timed(1, path="primes.txt") == 1
+    assert len(_calls) == 2
+
+    assert _timed(1) == 1
+    assert _timed(1, path=None) == 1
+    assert _timed(1, path=None) == 1
+    assert len(_calls) == 4
@@ -0,0 +1,51 @@
+# -*- coding: utf-8 -*-
+import math
+
+import pytest
+
+import rust_primes
+
+
+def _naive_totient(num: int) -> int:
+    return sum(1 for k in range(1, num + 1) if math.gcd(k, num) == 1)
+
+
+@pytest.mark.parametrize(
+    ["num", "phi"],
+    [
+        (0, 0),
+        (1, 1),
+        (2, 1),
+        (36, 12),
+        (97, 96),
+        (1_000_000, 400_000),
+        (999_999_999_989, 999_999_999_988),
+    ],
+)
+def test_totient(num, phi):
+    """
+    Test if the totients are correct.
+    """
+    assert rust_primes.totient(num) == phi
+
+
+@pytest.mark.parametrize(
+    ["method"],
+    (
+        [rust_primes.SieveMethod.ATKIN],
+        [rust_primes.SieveMethod.ERATOSTHENES],
+        [rust_primes.SieveMethod.ERATOSTHENES_ATOMIC],
+        [rust_primes.SieveMethod.ERATOSTHENES_THREADED],
+    ),
+)
+def test_totient_sieve(method):
+    """
+    Test if the totient sieve matches the naive definition.
+    """
+    _sieve = rust_primes.totient_sieve(1_000, method=method)
+
+    assert len(_sieve) == 1_001
+    assert _sieve[0] == 0
+    assert all(
+        _sieve[num] == _naive_totient(num) for num in range(1, 1_001)
+    ), "Totient sieve does not match the naive totients."
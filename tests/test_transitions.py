@@ -0,0 +1,26 @@
+# -*- coding: utf-8 -*-
+import pytest
+
+import rust_primes
+
+
+@pytest.mark.parametrize(["modulus"], ([1], [2], [3], [4], [10], [30]))
+@pytest.mark.parametrize(["num"], ([0], [2], [3], [100], [10**6], [3 * 2**20 + 17]))
+def test_prime_residue_transitions(num, modulus):
+    """
+    Test if the transitions match the consecutive pairs of :func:`list_primes`.
+
+    ``3 * 2**20 + 17`` spans multiple segments, so the stitching at segment
+    boundaries is also tested.
+    """
+    _primes = rust_primes.list_primes(num)
+    _transitions = rust_primes.prime_residue_transitions(num, modulus)
+
+    assert _transitions.shape == (modulus, modulus)
+    assert int(_transitions.sum()) == max(len(_primes) - 1, 0)
+
+    _expected = [[0] * modulus for _ in range(modulus)]
+    for _prime, _next_prime in zip(_primes[:-1], _primes[1:]):
+        _expected[_prime % modulus][_next_prime % modulus] += 1
+
+    assert _transitions.tolist() == _expected